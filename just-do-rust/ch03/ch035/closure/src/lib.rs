@@ -0,0 +1,5 @@
+// main.rs의 클로저 예제를 넓힌 모듈들
+// - memo: 클로저를 감싸서 한 번 계산한 결과를 저장해 두고 다시 쓴다 (memoization)
+// - safe_index: get_val처럼 범위를 벗어난 인덱스를 어떻게 처리할지 정책으로 골라 쓴다
+pub mod memo;
+pub mod safe_index;
@@ -1,3 +1,6 @@
+use closure::memo::{Lazy, Memo, RecMemo};
+use closure::safe_index::{Policy, SafeIndex};
+
 /*
 클로저의 선언은 다음과 같은 형태를 띈다.
 let <변수명> = | <파라미터> | <표현식> ;
@@ -46,6 +49,37 @@ fn main() {
         .filter(|x| x % 3 == 0 || x % 5 == 0)
         .collect();
     assert_eq!(4667, v.len());
+
+    // 클로저의 결과를 저장해 두고 다시 쓰기 (src/memo.rs)
+    // 같은 인자로 다시 부르면 클로저를 실행하지 않는다.
+    let mut slow_add = Memo::new(|x: i32| {
+        println!("computing {} + {}", x, num);
+        x + num
+    });
+    println!("{}", slow_add.get(5)); // computing 5 + 100, 105
+    println!("{}", slow_add.get(5)); // 105 (저장된 값)
+    println!("{:?}", slow_add.stats());
+
+    // 자기 자신을 부르는 클로저: recur로 더 작은 문제를 푼다.
+    let mut fib = RecMemo::new(|recur: &mut dyn FnMut(u64) -> u64, n: u64| {
+        if n < 2 {
+            n
+        } else {
+            recur(n - 1) + recur(n - 2)
+        }
+    });
+    println!("fib(90) = {}", fib.get(90));
+
+    // 처음 쓸 때 한 번만 계산한다.
+    let total = Lazy::new(|| v.iter().sum::<i32>());
+    println!("{}", *total); // 23341668
+
+    // get_val의 "벗어나면 첫 원소, 비었으면 0"을 정책으로 골라 쓰기 (src/safe_index.rs)
+    let v = vec![1, 2, 3];
+    assert_eq!(v.get_with(3, &Policy::FirstOr(0)), Ok(&get_val(&v, 3)));
+    println!("{:?}", v.get_with(-1, &Policy::Wrap)); // Ok(3)
+    println!("{:?}", v.get_with(5, &Policy::Strict)); // Err(OutOfBounds { index: 5, len: 3 })
+    println!("{:?}", v.get_range_with(-2..5, &Policy::Clamp)); // Ok([1, 1, 1, 2, 3, 3, 3])
 }
 
 fn get_val(v: &Vec<i32>, idx: usize) -> i32 {
@@ -65,3 +99,25 @@ fn get_val(v: &Vec<i32>, idx: usize) -> i32 {
     return *val;
      */
 }
+
+#[test]
+fn get_val_matches_first_or_policy() {
+    let inputs: [Vec<i32>; 5] = [
+        vec![1, 2, 3],
+        vec![],
+        vec![7],
+        vec![-5, 0, 5, 10],
+        vec![0, 0],
+    ];
+    for v in &inputs {
+        for idx in 0..8 {
+            assert_eq!(
+                v.get_with(idx as isize, &Policy::FirstOr(0)),
+                Ok(&get_val(v, idx)),
+                "{:?}[{}]",
+                v,
+                idx
+            );
+        }
+    }
+}
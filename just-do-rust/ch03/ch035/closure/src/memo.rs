@@ -0,0 +1,567 @@
+// main.rs의 add_num처럼 클로저는 함수와 달리 바깥 값(num)을 붙잡아(capture) 들고 다닌다.
+// 여기서는 클로저를 감싸서, 같은 인자로 다시 부르면 계산하지 않고 저장해 둔 결과를 돌려주는 것(memoization)을 만든다.
+//
+//   let mut square = Memo::new(|x: u64| x * x);   // 아직 계산하지 않는다
+//   square.get(12);   // 클로저 실행 → 144를 저장
+//   square.get(12);   // 저장된 144. 클로저는 실행하지 않는다
+//
+// - Memo: Fn(K) -> V 클로저의 결과를 HashMap에 저장한다. with_capacity로 만들면 개수가 넘칠 때
+//         가장 오래 쓰지 않은 것부터 버린다(LRU). 몇 번 저장된 값을 썼는지(hit), 계산했는지(miss) 센다.
+// - RecMemo: 클로저가 자기 자신을 다시 부르는(재귀) 경우. 클로저는 첫 인자로 받은 recur를 불러서
+//            더 작은 문제의 답을 얻는데, 그것도 저장된 값을 쓴다. (피보나치, 분할 수)
+// - Lazy: 값 하나를 처음 쓸 때 한 번만 계산한다. 쓰지 않으면 계산하지 않는다.
+//
+// 저장된 값을 돌려줄 때 복사해야 하므로 V는 Clone이어야 한다.
+
+use std::cell::{Cell, OnceCell};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Deref;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    // 저장된 값을 돌려준 횟수
+    pub hits: u64,
+    // 저장된 값이 없어서 클로저를 실행한 횟수
+    pub misses: u64,
+    // 개수 제한 때문에 버린 횟수
+    pub evictions: u64,
+}
+
+impl Stats {
+    // 0.0 ~ 1.0. 아직 한 번도 부르지 않았으면 0.0
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+// Memo와 RecMemo가 함께 쓰는 저장소
+struct Cache<K, V> {
+    // 값과 마지막으로 쓴 시각(tick)
+    map: HashMap<K, (V, u64)>,
+    // tick 순서로 본 키. 가장 앞이 가장 오래 쓰지 않은 것
+    order: BTreeMap<u64, K>,
+    tick: u64,
+    capacity: Option<usize>,
+    stats: Stats,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Cache<K, V> {
+    fn new(capacity: Option<usize>) -> Cache<K, V> {
+        Cache {
+            map: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+            capacity,
+            stats: Stats::default(),
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    // 있으면 hit로 세고 가장 최근에 쓴 것으로 옮긴다. 없으면 miss로 센다.
+    fn lookup(&mut self, k: &K) -> Option<V> {
+        let tick = self.next_tick();
+        match self.map.get_mut(k) {
+            Some((v, used)) => {
+                let key = self.order.remove(used).unwrap();
+                self.order.insert(tick, key);
+                *used = tick;
+                self.stats.hits += 1;
+                Some(v.clone())
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn insert(&mut self, k: K, v: V) {
+        if self.capacity == Some(0) {
+            return;
+        }
+        if let Some((_, used)) = self.map.remove(&k) {
+            self.order.remove(&used);
+        }
+        if self.capacity.is_some_and(|cap| self.map.len() >= cap) {
+            let (_, oldest) = self.order.pop_first().unwrap();
+            self.map.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+        let tick = self.next_tick();
+        self.order.insert(tick, k.clone());
+        self.map.insert(k, (v, tick));
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+        self.order.clear();
+    }
+}
+
+pub struct Memo<F, K, V> {
+    f: F,
+    cache: Cache<K, V>,
+}
+
+impl<F, K, V> Memo<F, K, V>
+where
+    F: Fn(K) -> V,
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    // 개수 제한 없이 모두 저장한다.
+    pub fn new(f: F) -> Memo<F, K, V> {
+        Memo {
+            f,
+            cache: Cache::new(None),
+        }
+    }
+
+    // 최대 capacity개까지 저장하고, 넘치면 가장 오래 쓰지 않은 것을 버린다. 0이면 아무것도 저장하지 않는다.
+    pub fn with_capacity(f: F, capacity: usize) -> Memo<F, K, V> {
+        Memo {
+            f,
+            cache: Cache::new(Some(capacity)),
+        }
+    }
+
+    pub fn get(&mut self, k: K) -> V {
+        if let Some(v) = self.cache.lookup(&k) {
+            return v;
+        }
+        let v = (self.f)(k.clone());
+        self.cache.insert(k, v.clone());
+        v
+    }
+
+    // 저장되어 있는지만 본다. 통계와 LRU 순서는 바뀌지 않는다.
+    pub fn contains(&self, k: &K) -> bool {
+        self.cache.map.contains_key(k)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.map.is_empty()
+    }
+
+    pub fn capacity(&self) -> Option<usize> {
+        self.cache.capacity
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.cache.stats
+    }
+
+    // 저장된 값만 지운다. 통계는 남는다.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<F, K, V> fmt::Debug for Memo<F, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Memo")
+            .field("len", &self.cache.map.len())
+            .field("capacity", &self.cache.capacity)
+            .field("stats", &self.cache.stats)
+            .finish()
+    }
+}
+
+// 자기 자신을 부르는 클로저를 위한 Memo. 클로저는 (recur, k)를 받아서, 더 작은 문제는 recur(작은 k)로 푼다.
+//
+//   let mut fib = RecMemo::new(|recur: &mut dyn FnMut(u64) -> u64, n: u64| {
+//       if n < 2 { n } else { recur(n - 1) + recur(n - 2) }
+//   });
+//   fib.get(90);   // 클로저는 n = 0..=90에 대해 한 번씩, 91번만 실행된다
+//
+// 재귀 깊이만큼 스택을 쓰므로 아주 깊은 재귀(수십만 단계)에는 맞지 않는다.
+pub struct RecMemo<F, K, V> {
+    f: F,
+    cache: Cache<K, V>,
+}
+
+fn recurse<F, K, V>(f: &F, cache: &mut Cache<K, V>, k: K) -> V
+where
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    if let Some(v) = cache.lookup(&k) {
+        return v;
+    }
+    let v = f(&mut |k| recurse(f, cache, k), k.clone());
+    cache.insert(k, v.clone());
+    v
+}
+
+impl<F, K, V> RecMemo<F, K, V>
+where
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    pub fn new(f: F) -> RecMemo<F, K, V> {
+        RecMemo {
+            f,
+            cache: Cache::new(None),
+        }
+    }
+
+    // 저장 개수를 제한하면 버려진 작은 문제는 다시 계산한다. 답은 같고 실행 횟수만 늘어난다.
+    pub fn with_capacity(f: F, capacity: usize) -> RecMemo<F, K, V> {
+        RecMemo {
+            f,
+            cache: Cache::new(Some(capacity)),
+        }
+    }
+
+    pub fn get(&mut self, k: K) -> V {
+        recurse(&self.f, &mut self.cache, k)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.map.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.cache.stats
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<F, K, V> fmt::Debug for RecMemo<F, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecMemo")
+            .field("len", &self.cache.map.len())
+            .field("capacity", &self.cache.capacity)
+            .field("stats", &self.cache.stats)
+            .finish()
+    }
+}
+
+// 처음 쓸 때 한 번만 계산하는 값. *lazy 또는 lazy.get()으로 쓴다.
+//
+//   let config = Lazy::new(|| read_config());   // 아직 읽지 않는다
+//   if verbose { println!("{}", *config); }    // 여기서 처음 읽는다. verbose가 아니면 끝까지 읽지 않는다
+pub struct Lazy<T, F = fn() -> T> {
+    value: OnceCell<T>,
+    // 계산하면서 꺼내 쓰므로 계산한 뒤에는 None
+    init: Cell<Option<F>>,
+}
+
+impl<T, F: FnOnce() -> T> Lazy<T, F> {
+    pub const fn new(init: F) -> Lazy<T, F> {
+        Lazy {
+            value: OnceCell::new(),
+            init: Cell::new(Some(init)),
+        }
+    }
+
+    // 계산하는 중에 패닉이 나면 init이 없어지므로, 그 뒤에 다시 쓰면 패닉이 난다.
+    pub fn get(&self) -> &T {
+        self.value.get_or_init(|| match self.init.take() {
+            Some(init) => init(),
+            None => panic!("Lazy instance has previously been poisoned"),
+        })
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.value.get().is_some()
+    }
+
+    // 아직 계산하지 않았으면 계산해서 꺼낸다.
+    pub fn into_value(self) -> T {
+        self.get();
+        self.value.into_inner().unwrap()
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for Lazy<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for Lazy<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value.get() {
+            Some(v) => f.debug_tuple("Lazy").field(v).finish(),
+            None => f.write_str("Lazy(<uninit>)"),
+        }
+    }
+}
+
+#[test]
+fn memo_runs_closure_once_per_key() {
+    let calls = Cell::new(0);
+    let mut square = Memo::new(|x: u64| {
+        calls.set(calls.get() + 1);
+        x * x
+    });
+    assert_eq!(calls.get(), 0);
+    assert_eq!(square.get(12), 144);
+    assert_eq!(square.get(12), 144);
+    assert_eq!(square.get(3), 9);
+    assert_eq!(square.get(12), 144);
+    assert_eq!(calls.get(), 2);
+    assert_eq!(
+        square.stats(),
+        Stats {
+            hits: 2,
+            misses: 2,
+            evictions: 0
+        }
+    );
+    assert_eq!(square.stats().hit_rate(), 0.5);
+    assert_eq!(square.len(), 2);
+    assert!(square.contains(&3) && !square.contains(&4));
+
+    square.clear();
+    assert!(square.is_empty());
+    assert_eq!(square.get(3), 9);
+    assert_eq!(calls.get(), 3);
+    assert_eq!(Stats::default().hit_rate(), 0.0);
+}
+
+#[test]
+fn memo_captures_outside_values() {
+    // main.rs의 add_num처럼 바깥 값을 붙잡은 클로저, 키가 String인 경우
+    let num = 100;
+    let calls = Cell::new(0);
+    let mut add_len = Memo::new(|s: String| {
+        calls.set(calls.get() + 1);
+        s.len() + num
+    });
+    for s in ["a", "bb", "a", "ccc", "bb", "a"] {
+        add_len.get(s.to_string());
+    }
+    assert_eq!(add_len.get("ccc".to_string()), 103);
+    assert_eq!(calls.get(), 3);
+    assert_eq!(add_len.stats().hits, 4);
+}
+
+#[test]
+fn lru_evicts_least_recently_used() {
+    let calls = Cell::new(0);
+    let mut m = Memo::with_capacity(
+        |c: char| {
+            calls.set(calls.get() + 1);
+            c.to_ascii_uppercase()
+        },
+        2,
+    );
+    assert_eq!(m.capacity(), Some(2));
+    m.get('a');
+    m.get('b');
+    m.get('a'); // a가 가장 최근, b가 가장 오래됨
+    m.get('c'); // b를 버린다
+    assert!(m.contains(&'a') && m.contains(&'c') && !m.contains(&'b'));
+    assert_eq!(m.stats().evictions, 1);
+    assert_eq!(calls.get(), 3);
+
+    m.get('b'); // 다시 계산, a를 버린다
+    assert_eq!(calls.get(), 4);
+    assert!(!m.contains(&'a'));
+    assert_eq!(m.len(), 2);
+    assert_eq!(
+        m.stats(),
+        Stats {
+            hits: 1,
+            misses: 4,
+            evictions: 2
+        }
+    );
+
+    // 0이면 저장하지 않는다
+    let calls = Cell::new(0);
+    let mut none = Memo::with_capacity(
+        |x: i32| {
+            calls.set(calls.get() + 1);
+            x
+        },
+        0,
+    );
+    none.get(1);
+    none.get(1);
+    assert_eq!((calls.get(), none.len(), none.stats().evictions), (2, 0, 0));
+}
+
+#[test]
+fn lru_matches_model() {
+    // 작은 모델(Vec, 앞이 가장 오래된 것)과 같은 것을 버리는지 확인
+    // 용량 3에 키 5개로 만들 수 있는 길이 6의 호출 순서를 모두 확인한다. (5^6 = 15625가지)
+    let (cap, keys, len) = (3, 5u32, 6);
+    for code in 0..keys.pow(len) {
+        let mut m = Memo::with_capacity(|x: u32| x * 10, cap);
+        let mut model: Vec<u32> = Vec::new();
+        let mut rest = code;
+        for _ in 0..len {
+            let k = rest % keys;
+            rest /= keys;
+            let hit = model.contains(&k);
+            let before = m.stats().hits;
+            assert_eq!(m.get(k), k * 10);
+            assert_eq!(m.stats().hits - before, hit as u64);
+            model.retain(|&x| x != k);
+            if model.len() == cap {
+                model.remove(0);
+            }
+            model.push(k);
+            let mut cached: Vec<u32> = (0..keys).filter(|k| m.contains(k)).collect();
+            let mut expected = model.clone();
+            cached.sort();
+            expected.sort();
+            assert_eq!(cached, expected, "code {}", code);
+        }
+    }
+}
+
+#[test]
+fn recursive_fibonacci() {
+    let calls = Cell::new(0);
+    let mut fib = RecMemo::new(|recur: &mut dyn FnMut(u64) -> u64, n: u64| {
+        calls.set(calls.get() + 1);
+        if n < 2 {
+            n
+        } else {
+            recur(n - 1) + recur(n - 2)
+        }
+    });
+    assert_eq!(fib.get(90), 2_880_067_194_370_816_120);
+    // 0..=90에 대해 한 번씩
+    assert_eq!(calls.get(), 91);
+    assert_eq!(fib.len(), 91);
+    assert_eq!(fib.get(50), 12_586_269_025);
+    assert_eq!(calls.get(), 91);
+    assert_eq!(fib.stats().misses, 91);
+
+    // 저장 개수를 줄여도 답은 같다. 피보나치는 바로 앞의 두 값만 있으면 되므로 실행 횟수도 같다.
+    let calls = Cell::new(0);
+    let mut small = RecMemo::with_capacity(
+        |recur: &mut dyn FnMut(u64) -> u64, n: u64| {
+            calls.set(calls.get() + 1);
+            if n < 2 {
+                n
+            } else {
+                recur(n - 1) + recur(n - 2)
+            }
+        },
+        3,
+    );
+    assert_eq!(small.get(90), 2_880_067_194_370_816_120);
+    assert_eq!(calls.get(), 91);
+    assert_eq!(small.len(), 3);
+}
+
+// p(n, k): n을 k 이하의 자연수들의 합으로 나타내는 방법의 수
+#[cfg(test)]
+fn partitions(recur: &mut dyn FnMut((u32, u32)) -> u64, (n, k): (u32, u32)) -> u64 {
+    match (n, k) {
+        (0, _) => 1,
+        (_, 0) => 0,
+        _ if k > n => recur((n, n)),
+        _ => recur((n - k, k)) + recur((n, k - 1)),
+    }
+}
+
+#[test]
+fn recursive_partition_count() {
+    let calls = Cell::new(0);
+    let mut p = RecMemo::new(|recur: &mut dyn FnMut((u32, u32)) -> u64, nk| {
+        calls.set(calls.get() + 1);
+        partitions(recur, nk)
+    });
+    assert_eq!(p.get((5, 5)), 7);
+    assert_eq!(p.get((100, 100)), 190_569_292);
+    // 서로 다른 (n, k)마다 한 번씩만 실행되었다
+    assert_eq!(calls.get(), p.len());
+    assert!(p.stats().hits > 0);
+
+    // 저장하지 않으면 같은 (n, k)를 여러 번 계산한다
+    let fast_calls = Cell::new(0);
+    let mut fast = RecMemo::new(|recur: &mut dyn FnMut((u32, u32)) -> u64, nk| {
+        fast_calls.set(fast_calls.get() + 1);
+        partitions(recur, nk)
+    });
+    let slow_calls = Cell::new(0);
+    let mut slow = RecMemo::with_capacity(
+        |recur: &mut dyn FnMut((u32, u32)) -> u64, nk| {
+            slow_calls.set(slow_calls.get() + 1);
+            partitions(recur, nk)
+        },
+        0,
+    );
+    assert_eq!(fast.get((20, 20)), 627);
+    assert_eq!(slow.get((20, 20)), 627);
+    assert!(slow_calls.get() > 5 * fast_calls.get());
+    assert_eq!(slow.stats().hits, 0);
+}
+
+#[test]
+fn lazy_computes_on_first_access_only() {
+    let calls = Cell::new(0);
+    let lazy = Lazy::new(|| {
+        calls.set(calls.get() + 1);
+        vec![1, 2, 3]
+    });
+    assert!(!lazy.is_initialized());
+    assert_eq!(format!("{:?}", lazy), "Lazy(<uninit>)");
+    assert_eq!(calls.get(), 0);
+
+    assert_eq!(lazy.len(), 3); // Deref
+    assert_eq!(lazy.get(), &[1, 2, 3]);
+    assert_eq!(*lazy, [1, 2, 3]);
+    assert_eq!(calls.get(), 1);
+    assert!(lazy.is_initialized());
+    assert_eq!(format!("{:?}", lazy), "Lazy([1, 2, 3])");
+    assert_eq!(lazy.into_value(), [1, 2, 3]);
+
+    // 쓰지 않으면 계산하지 않는다
+    let unused_calls = Cell::new(0);
+    {
+        let _unused = Lazy::new(|| {
+            unused_calls.set(unused_calls.get() + 1);
+            0u32
+        });
+    }
+    assert_eq!(unused_calls.get(), 0);
+
+    // 함수 포인터 기본 타입 (F = fn() -> T)
+    fn answer() -> u32 {
+        42
+    }
+    let fp: Lazy<u32> = Lazy::new(answer);
+    assert_eq!(fp.into_value(), 42);
+}
+
+#[test]
+#[should_panic(expected = "poisoned")]
+fn lazy_poisoned_after_panicking_init() {
+    let lazy = Lazy::new(|| -> u32 { panic!("boom") });
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| *lazy));
+    let _ = *lazy;
+}
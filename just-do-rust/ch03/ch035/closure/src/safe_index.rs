@@ -0,0 +1,279 @@
+// get_val(v, idx)은 idx가 범위를 벗어나면 v[0]을, v가 비어 있으면 0을 돌려준다. (main.rs의 클로저 예제)
+// 이렇게 "벗어나면 어떻게 할지"가 unwrap_or_else 안의 클로저에 숨어 있으면, 부르는 쪽에서는
+// get_val(&v, 3)이 1을 돌려준 것이 진짜 v[3]인지 대신 준 값인지 알 수 없다.
+//
+// 여기서는 벗어났을 때의 처리(Policy)를 부르는 쪽이 직접 고르게 한다.
+//
+//   let v = [10, 20, 30];
+//   v.get_with(5, &Policy::Clamp)      // Ok(&30)  마지막 원소로 붙인다
+//   v.get_with(-1, &Policy::Wrap)      // Ok(&30)  파이썬의 v[-1]처럼 돌아간다
+//   v.get_with(5, &Policy::Default(0)) // Ok(&0)   정해 둔 값
+//   v.get_with(5, &Policy::FirstOr(0)) // Ok(&10)  get_val과 같다: 첫 원소, 비었으면 정해 둔 값
+//   v.get_with(5, &Policy::Strict)     // Err(OutOfBounds { index: 5, len: 3 })
+//
+// 인덱스는 음수도 받을 수 있게 isize이다. 음수는 Wrap에서만 뒤에서부터 센다는 뜻이고, 나머지에서는 범위 밖이다.
+// get_range_with는 범위 안의 인덱스 하나하나에 같은 정책을 적용한다. (신호/이미지 처리의 가장자리 채우기와 같다)
+//
+//   [1, 2, 3].get_range_with(-2..5, &Policy::Clamp)   // [1, 1, 1, 2, 3, 3, 3]
+//   [1, 2, 3].get_range_with(-2..5, &Policy::Wrap)    // [2, 3, 1, 2, 3, 1, 2]
+
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Policy<T> {
+    // 범위 밖이면 오류
+    Strict,
+    // 0보다 작으면 첫 원소, len 이상이면 마지막 원소
+    Clamp,
+    // len으로 나눈 나머지 자리 (음수도 뒤에서부터 돈다)
+    Wrap,
+    // 범위 밖이면 이 값
+    Default(T),
+    // 범위 밖이면 첫 원소. 비어 있으면 오류
+    First,
+    // 범위 밖이면 첫 원소, 비어 있으면 이 값 (get_val)
+    FirstOr(T),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexError {
+    OutOfBounds { index: isize, len: usize },
+    // 빈 슬라이스라 Clamp, Wrap, First로 고를 원소가 없다
+    Empty,
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexError::OutOfBounds { index, len } => {
+                write!(f, "index {} out of bounds (len {})", index, len)
+            }
+            IndexError::Empty => write!(f, "slice is empty"),
+        }
+    }
+}
+
+impl Error for IndexError {}
+
+// 인덱스가 가리키게 된 곳
+enum Resolved {
+    At(usize),
+    // 정책에 들어 있는 값 (Default, FirstOr)
+    Fallback,
+}
+
+fn resolve<T>(len: usize, index: isize, policy: &Policy<T>) -> Result<Resolved, IndexError> {
+    if index >= 0 && (index as usize) < len {
+        return Ok(Resolved::At(index as usize));
+    }
+    let out_of_bounds = IndexError::OutOfBounds { index, len };
+    match policy {
+        Policy::Strict => Err(out_of_bounds),
+        Policy::Default(_) => Ok(Resolved::Fallback),
+        _ if len == 0 => match policy {
+            Policy::FirstOr(_) => Ok(Resolved::Fallback),
+            _ => Err(IndexError::Empty),
+        },
+        Policy::Clamp if index < 0 => Ok(Resolved::At(0)),
+        Policy::Clamp => Ok(Resolved::At(len - 1)),
+        // len <= isize::MAX이므로 isize로 바꿔도 된다.
+        Policy::Wrap => Ok(Resolved::At(index.rem_euclid(len as isize) as usize)),
+        Policy::First | Policy::FirstOr(_) => Ok(Resolved::At(0)),
+    }
+}
+
+fn fallback<T>(policy: &Policy<T>) -> &T {
+    match policy {
+        Policy::Default(v) | Policy::FirstOr(v) => v,
+        _ => unreachable!("only Default and FirstOr carry a value"),
+    }
+}
+
+pub trait SafeIndex<T> {
+    // index 자리의 원소. 범위 밖이면 policy대로 한다.
+    fn get_with<'a>(&'a self, index: isize, policy: &'a Policy<T>) -> Result<&'a T, IndexError>;
+
+    // range의 인덱스마다 get_with를 한 결과. 하나라도 오류면 오류이고, start >= end이면 빈 Vec
+    fn get_range_with<'a>(
+        &'a self,
+        range: Range<isize>,
+        policy: &'a Policy<T>,
+    ) -> Result<Vec<&'a T>, IndexError>;
+}
+
+impl<T> SafeIndex<T> for [T] {
+    fn get_with<'a>(&'a self, index: isize, policy: &'a Policy<T>) -> Result<&'a T, IndexError> {
+        match resolve(self.len(), index, policy)? {
+            Resolved::At(i) => Ok(&self[i]),
+            Resolved::Fallback => Ok(fallback(policy)),
+        }
+    }
+
+    fn get_range_with<'a>(
+        &'a self,
+        range: Range<isize>,
+        policy: &'a Policy<T>,
+    ) -> Result<Vec<&'a T>, IndexError> {
+        range.map(|i| self.get_with(i, policy)).collect()
+    }
+}
+
+#[test]
+fn first_or_default_matches_get_val_table() {
+    // main.rs의 get_val(v, idx)이 돌려주는 값: 범위 밖이면 v[0], 비었으면 0
+    let cases: [(&[i32], [i32; 5]); 5] = [
+        (&[1, 2, 3], [1, 2, 3, 1, 1]),
+        (&[], [0, 0, 0, 0, 0]),
+        (&[7], [7, 7, 7, 7, 7]),
+        (&[-5, 0, 5, 10], [-5, 0, 5, 10, -5]),
+        (&[0, 0], [0, 0, 0, 0, 0]),
+    ];
+    for (v, expected) in cases {
+        for (idx, want) in expected.iter().enumerate() {
+            assert_eq!(
+                v.get_with(idx as isize, &Policy::FirstOr(0)),
+                Ok(want),
+                "{:?}[{}]",
+                v,
+                idx
+            );
+        }
+    }
+}
+
+#[test]
+fn each_policy_out_of_bounds() {
+    let v = [10, 20, 30];
+    let cases: [(isize, [Result<i32, IndexError>; 6]); 5] = [
+        (1, [Ok(20), Ok(20), Ok(20), Ok(20), Ok(20), Ok(20)]),
+        (
+            3,
+            [
+                Err(IndexError::OutOfBounds { index: 3, len: 3 }),
+                Ok(30),
+                Ok(10),
+                Ok(-1),
+                Ok(10),
+                Ok(10),
+            ],
+        ),
+        (
+            7,
+            [
+                Err(IndexError::OutOfBounds { index: 7, len: 3 }),
+                Ok(30),
+                Ok(20),
+                Ok(-1),
+                Ok(10),
+                Ok(10),
+            ],
+        ),
+        (
+            -1,
+            [
+                Err(IndexError::OutOfBounds { index: -1, len: 3 }),
+                Ok(10),
+                Ok(30),
+                Ok(-1),
+                Ok(10),
+                Ok(10),
+            ],
+        ),
+        (
+            -5,
+            [
+                Err(IndexError::OutOfBounds { index: -5, len: 3 }),
+                Ok(10),
+                Ok(20),
+                Ok(-1),
+                Ok(10),
+                Ok(10),
+            ],
+        ),
+    ];
+    let policies = [
+        Policy::Strict,
+        Policy::Clamp,
+        Policy::Wrap,
+        Policy::Default(-1),
+        Policy::First,
+        Policy::FirstOr(-1),
+    ];
+    for (index, expected) in cases {
+        for (policy, want) in policies.iter().zip(expected) {
+            assert_eq!(
+                v.get_with(index, policy).copied(),
+                want,
+                "{:?} at {}",
+                policy,
+                index
+            );
+        }
+    }
+    assert_eq!(
+        v.get_with(isize::MIN, &Policy::Wrap),
+        Ok(&v[isize::MIN.rem_euclid(3) as usize])
+    );
+    assert_eq!(v.get_with(isize::MAX, &Policy::Clamp), Ok(&30));
+}
+
+#[test]
+fn empty_slice() {
+    let v: [String; 0] = [];
+    assert_eq!(v.get_with(0, &Policy::Clamp), Err(IndexError::Empty));
+    assert_eq!(v.get_with(-1, &Policy::Wrap), Err(IndexError::Empty));
+    assert_eq!(v.get_with(0, &Policy::First), Err(IndexError::Empty));
+    assert_eq!(
+        v.get_with(0, &Policy::Strict),
+        Err(IndexError::OutOfBounds { index: 0, len: 0 })
+    );
+    let none = Policy::Default("none".to_string());
+    assert_eq!(v.get_with(4, &none).map(String::as_str), Ok("none"));
+    let none = Policy::FirstOr("none".to_string());
+    assert_eq!(v.get_with(4, &none).map(String::as_str), Ok("none"));
+    assert_eq!(v.get_range_with(0..0, &Policy::Strict), Ok(vec![]));
+    assert_eq!(
+        v.get_range_with(0..2, &Policy::Wrap),
+        Err(IndexError::Empty)
+    );
+    assert_eq!(IndexError::Empty.to_string(), "slice is empty");
+}
+
+#[test]
+fn ranges_apply_policy_per_index() {
+    let v = [1, 2, 3];
+    let get = |range: Range<isize>, policy: Policy<i32>| {
+        v.get_range_with(range, &policy)
+            .map(|r| r.into_iter().copied().collect::<Vec<_>>())
+    };
+    assert_eq!(get(-2..5, Policy::Clamp), Ok(vec![1, 1, 1, 2, 3, 3, 3]));
+    assert_eq!(get(-2..5, Policy::Wrap), Ok(vec![2, 3, 1, 2, 3, 1, 2]));
+    assert_eq!(
+        get(-2..5, Policy::Default(0)),
+        Ok(vec![0, 0, 1, 2, 3, 0, 0])
+    );
+    assert_eq!(get(1..5, Policy::First), Ok(vec![2, 3, 1, 1]));
+    assert_eq!(get(0..3, Policy::Strict), Ok(vec![1, 2, 3]));
+    assert_eq!(
+        get(1..4, Policy::Strict),
+        Err(IndexError::OutOfBounds { index: 3, len: 3 })
+    );
+    // 비어 있는 범위
+    assert_eq!(get(2..2, Policy::Strict), Ok(vec![]));
+    #[allow(clippy::reversed_empty_ranges)]
+    let backwards = 3..1;
+    assert_eq!(get(backwards, Policy::Wrap), Ok(vec![]));
+
+    // 범위 안쪽은 슬라이스의 부분과 같다
+    let words = ["a", "b", "c", "d"];
+    assert_eq!(
+        words.get_range_with(1..3, &Policy::Strict).unwrap(),
+        words[1..3].iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        IndexError::OutOfBounds { index: -1, len: 4 }.to_string(),
+        "index -1 out of bounds (len 4)"
+    );
+}
@@ -0,0 +1,500 @@
+// main.rs의 test6, test7은 이중 for 루프와 break, break 'label_i 로 (i, j) 쌍을 모은다.
+// 루프가 몇 겹인지가 코드에 박혀 있어서, 3중, 4중이 되거나 "서로 다른 k개 고르기" 같은 조건이 붙으면
+// 루프를 다시 짜야 한다. 여기서는 그런 중첩 루프를 반복자 하나로 바꿔 준다.
+//
+//   product([2..=3, 2..=4])                  // 이중 for 루프: [2, 2], [2, 3], ..., [3, 4]
+//   combinations(1..=5, 2)                   // 서로 다른 2개를 고르기(순서 무시): [1, 2], [1, 3], ...
+//   permutations(1..=3, 2)                   // 서로 다른 2개를 순서대로 늘어놓기: [1, 2], [1, 3], [2, 1], ...
+//   permutations_with_repetition(0..2, 3)    // 같은 것을 또 골라도 되는 경우: [0, 0, 0], [0, 0, 1], ...
+//   next_permutation(&mut v)                 // v를 사전 순으로 다음 순열로 바꾼다 (C++의 std::next_permutation)
+//
+// 반복자들은 값을 하나 꺼낼 때마다 다음 조합을 하나 만든다(lazy). 그래서 find, try_fold, take_while 처럼
+// 중간에 멈추는 메서드를 쓰면 나머지는 만들지 않는다. break 'label 로 빠져나가던 탐색이 한 줄이 된다.
+//
+//   let hit = combinations(1..=9, 3).find(|c| c.iter().sum::<i32>() == 15);
+//
+// 결과는 원소를 복사(clone)한 Vec<T>이고, 입력으로 준 순서를 기준으로 사전 순(lexicographic)으로 나온다.
+// 입력이 오름차순이면 결과 Vec들도 오름차순이다.
+// 입력 모음(pool)은 처음에 한 번 모두 읽어 둔다. 따라서 끝이 없는 반복자를 넣으면 안 된다.
+
+use std::iter::FusedIterator;
+
+// nCk. usize를 넘으면 None
+pub fn binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut r: u128 = 1;
+    for i in 0..k {
+        // r * (n - i)는 항상 (i + 1)로 나누어 떨어진다. r <= usize::MAX이므로 u128 곱셈은 넘치지 않는다.
+        r = r * (n - i) as u128 / (i + 1) as u128;
+        if r > usize::MAX as u128 {
+            return None;
+        }
+    }
+    Some(r as usize)
+}
+
+// nPk = n * (n - 1) * ... * (n - k + 1). usize를 넘으면 None
+pub fn falling_factorial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    (n - k + 1..=n).try_fold(1usize, |acc, x| acc.checked_mul(x))
+}
+
+// 남은 개수. None이면 usize로 셀 수 없을 만큼 많이 남은 것
+fn hint(remaining: Option<usize>) -> (usize, Option<usize>) {
+    match remaining {
+        Some(n) => (n, Some(n)),
+        None => (usize::MAX, None),
+    }
+}
+
+fn take_one(remaining: &mut Option<usize>) {
+    if let Some(n) = remaining {
+        *n -= 1;
+    }
+}
+
+// slice를 사전 순으로 바로 다음 순열로 바꾼다. 이미 마지막 순열(내림차순)이면
+// 첫 순열(오름차순)로 되돌리고 false를 돌려준다. 같은 값이 있으면 중복된 순열은 건너뛴다.
+//
+//   let mut v = [1, 2, 3];
+//   while next_permutation(&mut v) { ... }   // [1, 3, 2], [2, 1, 3], ..., [3, 2, 1]
+pub fn next_permutation<T: Ord>(v: &mut [T]) -> bool {
+    // 뒤에서부터 v[i] < v[i + 1]인 첫 자리를 찾는다. 그 뒤는 내림차순이다.
+    let Some(i) = (1..v.len()).rev().find(|&i| v[i - 1] < v[i]).map(|i| i - 1) else {
+        v.reverse();
+        return false;
+    };
+    // 내림차순 꼬리에서 v[i]보다 큰 것 중 가장 작은 값(= 가장 뒤에 있는 것)과 바꾸고, 꼬리를 오름차순으로 뒤집는다.
+    let j = (i + 1..v.len()).rev().find(|&j| v[i] < v[j]).unwrap();
+    v.swap(i, j);
+    v[i + 1..].reverse();
+    true
+}
+
+// 여러 모음의 곱집합(cartesian product). 모음 수만큼 for 루프를 겹친 것과 같은 순서로 나온다.
+// 마지막 모음이 가장 안쪽 루프다. 모음이 하나도 없으면 빈 Vec 하나를 돌려준다.
+//
+//   product([2..=3, 2..=4])  ==  for i in 2..=3 { for j in 2..=4 { [i, j] } }
+pub fn product<P, T>(pools: impl IntoIterator<Item = P>) -> Product<T>
+where
+    P: IntoIterator<Item = T>,
+    T: Clone,
+{
+    Product::new(pools.into_iter().map(|p| p.into_iter().collect()).collect())
+}
+
+// pool에서 k개를 골라 늘어놓는데, 같은 것을 여러 번 골라도 되는 경우. pool.len()^k 개
+//
+//   permutations_with_repetition(['H', 'T'], 2)   // HH, HT, TH, TT
+pub fn permutations_with_repetition<T: Clone>(
+    pool: impl IntoIterator<Item = T>,
+    k: usize,
+) -> Product<T> {
+    let pool: Vec<T> = pool.into_iter().collect();
+    Product::new(vec![pool; k])
+}
+
+#[derive(Debug, Clone)]
+pub struct Product<T> {
+    pools: Vec<Vec<T>>,
+    // 각 모음에서 지금 가리키는 자리. 자동차 주행 거리계처럼 마지막 자리부터 올라간다.
+    idx: Vec<usize>,
+    remaining: Option<usize>,
+}
+
+impl<T: Clone> Product<T> {
+    fn new(pools: Vec<Vec<T>>) -> Self {
+        let remaining = pools
+            .iter()
+            .try_fold(1usize, |acc, p| acc.checked_mul(p.len()));
+        Product {
+            idx: vec![0; pools.len()],
+            pools,
+            remaining,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for Product<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let item = self
+            .idx
+            .iter()
+            .zip(&self.pools)
+            .map(|(&i, p)| p[i].clone())
+            .collect();
+        take_one(&mut self.remaining);
+        for (i, p) in self.idx.iter_mut().zip(&self.pools).rev() {
+            *i += 1;
+            if *i < p.len() {
+                break;
+            }
+            *i = 0;
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        hint(self.remaining)
+    }
+}
+
+impl<T: Clone> FusedIterator for Product<T> {}
+
+// pool에서 서로 다른 자리의 원소 k개를 고른다. 순서는 무시하므로 pool에서의 순서를 유지한 Vec만 나온다. nCk 개
+//
+//   combinations(1..=4, 2)   // [1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]
+pub fn combinations<T: Clone>(pool: impl IntoIterator<Item = T>, k: usize) -> Combinations<T> {
+    let pool: Vec<T> = pool.into_iter().collect();
+    Combinations {
+        remaining: binomial(pool.len(), k),
+        idx: (0..k).collect(),
+        pool,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Combinations<T> {
+    pool: Vec<T>,
+    // 고른 자리들. 항상 오름차순이다.
+    idx: Vec<usize>,
+    remaining: Option<usize>,
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let item = self.idx.iter().map(|&i| self.pool[i].clone()).collect();
+        take_one(&mut self.remaining);
+        // 아직 끝까지 밀리지 않은 가장 오른쪽 자리를 하나 올리고, 그 뒤 자리들은 바로 다음 칸들로 붙인다.
+        let (n, k) = (self.pool.len(), self.idx.len());
+        if let Some(i) = (0..k).rev().find(|&i| self.idx[i] != i + n - k) {
+            self.idx[i] += 1;
+            for j in i + 1..k {
+                self.idx[j] = self.idx[j - 1] + 1;
+            }
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        hint(self.remaining)
+    }
+}
+
+impl<T: Clone> FusedIterator for Combinations<T> {}
+
+// pool에서 서로 다른 자리의 원소 k개를 골라 순서대로 늘어놓는다. nPk 개. k == pool.len()이면 모든 순열
+//
+//   permutations(1..=3, 2)   // [1, 2], [1, 3], [2, 1], [2, 3], [3, 1], [3, 2]
+pub fn permutations<T: Clone>(pool: impl IntoIterator<Item = T>, k: usize) -> Permutations<T> {
+    let pool: Vec<T> = pool.into_iter().collect();
+    Permutations {
+        remaining: falling_factorial(pool.len(), k),
+        idx: (0..pool.len()).collect(),
+        k,
+        pool,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Permutations<T> {
+    pool: Vec<T>,
+    // 0..n의 순열. 앞의 k자리가 지금 결과이고, 뒤의 n - k자리는 오름차순으로 남겨 둔다.
+    idx: Vec<usize>,
+    k: usize,
+    remaining: Option<usize>,
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let item = self.idx[..self.k]
+            .iter()
+            .map(|&i| self.pool[i].clone())
+            .collect();
+        take_one(&mut self.remaining);
+        // 뒤의 n - k자리를 내림차순으로 뒤집으면, 전체의 다음 순열은 앞 k자리가 바뀌는 첫 순열이 된다.
+        // next_permutation은 바뀐 자리 뒤를 다시 오름차순으로 만들어 주므로 불변식이 유지된다.
+        self.idx[self.k..].reverse();
+        next_permutation(&mut self.idx);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        hint(self.remaining)
+    }
+}
+
+impl<T: Clone> FusedIterator for Permutations<T> {}
+
+// 다 꺼낼 때까지 size_hint가 정확한지, 끝난 뒤에도 None인지 확인하고 꺼낸 값들을 돌려준다.
+#[cfg(test)]
+fn drain<I: Iterator>(mut it: I) -> Vec<I::Item> {
+    let mut out = Vec::new();
+    loop {
+        let (lo, hi) = it.size_hint();
+        match it.next() {
+            Some(x) => {
+                assert!(lo >= 1 && hi.is_none_or(|h| h >= 1));
+                out.push(x);
+            }
+            None => {
+                assert_eq!((lo, hi), (0, Some(0)));
+                break;
+            }
+        }
+    }
+    for _ in 0..3 {
+        assert!(it.next().is_none());
+    }
+    out
+}
+
+#[cfg(test)]
+fn factorial(n: usize) -> usize {
+    (1..=n).product()
+}
+
+#[cfg(test)]
+fn strictly_increasing<T: Ord>(v: &[T]) -> bool {
+    v.windows(2).all(|w| w[0] < w[1])
+}
+
+#[test]
+fn counting_functions() {
+    assert_eq!(binomial(5, 2), Some(10));
+    assert_eq!(binomial(5, 0), Some(1));
+    assert_eq!(binomial(5, 5), Some(1));
+    assert_eq!(binomial(3, 4), Some(0));
+    assert_eq!(binomial(0, 0), Some(1));
+    assert_eq!(binomial(52, 5), Some(2_598_960));
+    assert_eq!(binomial(66, 33), Some(7_219_428_434_016_265_740));
+    assert_eq!(binomial(100, 50), None);
+    for n in 0..20 {
+        for k in 0..=n {
+            assert_eq!(
+                binomial(n, k).unwrap() * factorial(k),
+                falling_factorial(n, k).unwrap()
+            );
+        }
+    }
+
+    assert_eq!(falling_factorial(5, 2), Some(20));
+    assert_eq!(falling_factorial(5, 0), Some(1));
+    assert_eq!(falling_factorial(10, 10), Some(3_628_800));
+    assert_eq!(falling_factorial(3, 4), Some(0));
+    assert_eq!(falling_factorial(30, 30), None);
+}
+
+#[test]
+fn product_matches_nested_loops() {
+    let mut expected = Vec::new();
+    for a in 0..3 {
+        for b in 10..12 {
+            for c in [7, 5] {
+                expected.push(vec![a, b, c]);
+            }
+        }
+    }
+    assert_eq!(
+        drain(product([vec![0, 1, 2], vec![10, 11], vec![7, 5]])),
+        expected
+    );
+
+    let pairs = drain(product([2..=3, 2..=4]));
+    assert_eq!(pairs.len(), 6);
+    assert!(strictly_increasing(&pairs));
+    assert_eq!(pairs[0], [2, 2]);
+    assert_eq!(pairs[5], [3, 4]);
+}
+
+#[test]
+fn product_edge_cases() {
+    // 모음이 없으면 빈 곱 하나 (for 루프가 0겹이면 본문이 한 번 실행되는 것과 같다)
+    assert_eq!(drain(product(Vec::<Vec<i32>>::new())), [Vec::<i32>::new()]);
+    // 하나라도 비어 있으면 아무것도 없다
+    assert!(drain(product([vec![1, 2], vec![], vec![3]])).is_empty());
+    assert_eq!(drain(product([vec!['a']])), [['a']]);
+}
+
+#[test]
+fn product_size_hint_overflow() {
+    // (2^22)^3 = 2^66 개는 usize로 셀 수 없다. 그래도 앞에서부터 하나씩 꺼낼 수는 있다.
+    let mut it = product([0..1 << 22, 0..1 << 22, 0..1 << 22]);
+    assert_eq!(it.size_hint(), (usize::MAX, None));
+    assert_eq!(it.next(), Some(vec![0, 0, 0]));
+    assert_eq!(it.next(), Some(vec![0, 0, 1]));
+}
+
+#[test]
+fn permutations_with_repetition_counts() {
+    for n in 0..5 {
+        for k in 0..5 {
+            let all = drain(permutations_with_repetition(0..n, k));
+            assert_eq!(all.len(), (n as usize).pow(k as u32), "n={} k={}", n, k);
+            assert!(strictly_increasing(&all));
+            assert!(all.iter().all(|v| v.len() == k));
+        }
+    }
+    let coins: Vec<String> = permutations_with_repetition(['H', 'T'], 2)
+        .map(|v| v.into_iter().collect())
+        .collect();
+    assert_eq!(coins, ["HH", "HT", "TH", "TT"]);
+}
+
+#[test]
+fn combinations_count_and_order() {
+    for n in 0..9 {
+        for k in 0..=n + 1 {
+            let all = drain(combinations(0..n, k));
+            assert_eq!(Some(all.len()), binomial(n, k), "n={} k={}", n, k);
+            // 결과끼리 사전 순으로 오름차순이고, 각 결과 안도 오름차순 (같은 자리를 두 번 고르지 않음)
+            assert!(strictly_increasing(&all));
+            assert!(all.iter().all(|c| c.len() == k && strictly_increasing(c)));
+        }
+    }
+    assert_eq!(
+        combinations(1..=4, 2).collect::<Vec<_>>(),
+        [[1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]]
+    );
+    // 원소 값이 아니라 자리를 고르므로 같은 값이 있으면 같은 결과가 여러 번 나온다
+    assert_eq!(combinations("aab".chars(), 2).count(), 3);
+    assert_eq!(drain(combinations(0..3, 0)), [Vec::<i32>::new()]);
+}
+
+#[test]
+fn permutations_count_and_order() {
+    for n in 0..7 {
+        for k in 0..=n + 1 {
+            let all = drain(permutations(0..n, k));
+            assert_eq!(Some(all.len()), falling_factorial(n, k), "n={} k={}", n, k);
+            assert!(strictly_increasing(&all));
+            for p in &all {
+                let mut sorted = p.clone();
+                sorted.sort();
+                sorted.dedup();
+                assert_eq!(sorted.len(), k);
+            }
+        }
+    }
+    assert_eq!(
+        permutations(1..=3, 2).collect::<Vec<_>>(),
+        [[1, 2], [1, 3], [2, 1], [2, 3], [3, 1], [3, 2]]
+    );
+    // pool의 순서를 기준으로 한 사전 순
+    assert_eq!(
+        permutations(['c', 'a'], 2).collect::<Vec<_>>(),
+        [['c', 'a'], ['a', 'c']]
+    );
+}
+
+#[test]
+fn permutations_match_combinations_times_orderings() {
+    // 각 k-조합을 모든 순서로 늘어놓은 것을 모으면 k-순열 전체와 같다
+    let mut from_combinations: Vec<Vec<u8>> = combinations(0..6u8, 3)
+        .flat_map(|c| permutations(c, 3))
+        .collect();
+    from_combinations.sort();
+    assert_eq!(
+        from_combinations,
+        permutations(0..6u8, 3).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn next_permutation_enumerates_all() {
+    let mut v = [1, 2, 3, 4];
+    let mut seen = vec![v];
+    while next_permutation(&mut v) {
+        seen.push(v);
+    }
+    assert_eq!(seen.len(), factorial(4));
+    assert!(strictly_increasing(&seen));
+    // 마지막 다음에는 처음으로 돌아온다
+    assert_eq!(v, [1, 2, 3, 4]);
+    let from_iter: Vec<Vec<i32>> = permutations(1..=4, 4).collect();
+    assert_eq!(
+        seen.iter().map(|a| a.to_vec()).collect::<Vec<_>>(),
+        from_iter
+    );
+}
+
+#[test]
+fn next_permutation_with_duplicates() {
+    // 같은 값이 있으면 서로 다른 순열만 나온다: 4! / (2! * 2!) = 6
+    let mut v = ['a', 'a', 'b', 'b'];
+    let mut seen = vec![v.iter().collect::<String>()];
+    while next_permutation(&mut v) {
+        seen.push(v.iter().collect());
+    }
+    assert_eq!(seen, ["aabb", "abab", "abba", "baab", "baba", "bbaa"]);
+
+    let mut empty: [i32; 0] = [];
+    assert!(!next_permutation(&mut empty));
+    let mut one = [7];
+    assert!(!next_permutation(&mut one));
+    let mut last = [3, 2, 1];
+    assert!(!next_permutation(&mut last));
+    assert_eq!(last, [1, 2, 3]);
+}
+
+#[test]
+fn early_termination_replaces_labeled_break() {
+    // main.rs의 test6: 바깥 루프는 i >= 4에서, 안쪽 루프는 j >= 5에서 break
+    let v: Vec<_> = product([2..=10, 2..=10])
+        .filter(|p| p[0] < 4 && p[1] < 5)
+        .map(|p| (p[0], p[1]))
+        .collect();
+    assert_eq!(v, [(2, 2), (2, 3), (2, 4), (3, 2), (3, 3), (3, 4)]);
+
+    // main.rs의 test7: j >= 5가 되면 break 'label_i 로 두 루프를 한꺼번에 빠져나간다
+    let v: Vec<_> = product([2..=10, 2..=10])
+        .take_while(|p| p[1] < 5)
+        .map(|p| (p[0], p[1]))
+        .collect();
+    assert_eq!(v, [(2, 2), (2, 3), (2, 4)]);
+
+    // try_fold: 찾자마자 그때까지 모은 값을 들고 빠져나온다
+    let stopped = product([2..=10, 2..=10]).try_fold(Vec::new(), |mut acc, p| {
+        if p[1] >= 5 {
+            return Err(acc);
+        }
+        acc.push((p[0], p[1]));
+        Ok(acc)
+    });
+    assert_eq!(stopped, Err(vec![(2, 2), (2, 3), (2, 4)]));
+
+    // find는 찾은 뒤의 조합을 만들지 않는다. C(100, 5) = 75,287,520개(약 7,500만 개)의 조합 중 앞쪽 몇 개만 만든다.
+    let mut it = combinations(1..=100, 5);
+    assert_eq!(it.size_hint().0, 75_287_520);
+    let hit = it.find(|c| c.iter().sum::<i32>() == 20);
+    assert_eq!(hit, Some(vec![1, 2, 3, 4, 10]));
+    assert_eq!(it.next(), Some(vec![1, 2, 3, 4, 11]));
+
+    // 한 자리 수 세 개로 합 15를 만드는 첫 조합
+    let hit = combinations(1..=9, 3).find(|c| c.iter().sum::<i32>() == 15);
+    assert_eq!(hit, Some(vec![1, 5, 9]));
+
+    // 자리마다 다른 숫자를 넣는 퍼즐: 각 자리 숫자의 곱이 504이고 9로 시작하는 가장 작은 세 자리 수
+    let hit = permutations(0..10, 3).find(|d| d[0] == 9 && d[0] * d[1] * d[2] == 504);
+    assert_eq!(hit, Some(vec![9, 7, 8]));
+}
@@ -0,0 +1,2 @@
+// 중첩 for 루프로 하던 조합 탐색(곱집합, 조합, 순열)을 반복자로 만든 모듈
+pub mod combinatorics;
@@ -1,3 +1,5 @@
+use for_statement::combinatorics::{combinations, next_permutation, product};
+
 fn main() {
     /*
     // 기본 사용법
@@ -35,6 +37,26 @@ fn main() {
     for val in &v {
         println!("{}", val);
     }
+
+    println!("=====");
+
+    // 중첩 for 루프 대신 반복자로 (src/combinatorics.rs)
+    // 이중 for 루프 + break 'label_i 로 찾던 것을 find 한 줄로 찾는다.
+    let hit = product([1..=9, 1..=9]).find(|p| p[0] * p[1] == 56);
+    println!("{:?}", hit); // Some([7, 8])
+
+    // 1~9 중 서로 다른 세 수의 합이 15가 되는 경우
+    let triples: Vec<_> = combinations(1..=9, 3)
+        .filter(|c| c.iter().sum::<i32>() == 15)
+        .collect();
+    println!("{} {:?}", triples.len(), triples); // 8 [[1, 5, 9], [1, 6, 8], ...]
+
+    let mut word = ['a', 'b', 'c'];
+    print!("{}", word.iter().collect::<String>());
+    while next_permutation(&mut word) {
+        print!(" {}", word.iter().collect::<String>());
+    }
+    println!(); // abc acb bac bca cab cba
 }
 
 //이중 for 루프에서의 break;
@@ -0,0 +1,613 @@
+// main.rs 2번 예제의 match age { 0..=10 => "baby", 11..=20 => "teen", ... }는 범위 arm들을 위에서부터 차례로 본다.
+// 범위가 겹치거나 빠진 값이 있어도 컴파일러는 알려 주지 않고(겹치면 앞의 arm이 이긴다),
+// 범위가 많아지면 매번 처음부터 비교한다.
+//
+// Buckets<K, V>는 겹치지 않는 범위(구간, bucket)마다 값 V를 붙여 둔 것이다.
+// - 만들 때 범위가 겹치는지 검사한다. contiguous로 만들면 범위 사이에 빈틈이 있는지도 검사한다.
+// - 범위를 시작값 순서로 정렬해 두고 이진 탐색으로 찾으므로 범위가 n개일 때 O(log n)이다.
+// - 값 여러 개가 각 범위에 몇 개씩 들어가는지(histogram) 세고, 잘게 나눈 범위의 개수를
+//   더 크게 나눈 범위로 옮겨 담을(rebin) 수 있다. (일별 → 월별, 월별 → 분기별)
+//
+//   let ages = Buckets::contiguous([(0..=10, "baby"), (11..=20, "teen"), (21..=60, "adult"), (61..=u32::MAX, "old")])?;
+//   ages.get(&33)  // Some(&"adult")
+//
+// 배열 안의 범위들은 타입이 같아야 하므로 0..=10과 61..을 섞어 쓸 수 없다. 정수라면 61..=u32::MAX처럼
+// 최댓값까지로 적으면 끝이 없는 범위가 된다.
+//
+// 범위는 안에서 모두 "시작 포함, 끝 미포함"(start..end)으로 바꿔서 다룬다. 그래야 실수(f64)처럼
+// "바로 다음 값"이 없는 타입에서도 빈틈과 겹침을 정확히 말할 수 있다. 정수는 a..=b도 쓸 수 있다.
+// K는 Ord여야 하므로 f64는 Total(f64)로 감싸서 쓴다. 날짜는 20240315 같은 정수로 나타내면 된다.
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+// f32, f64에 total_cmp로 전체 순서(Ord)를 준 것. -0.0 < 0.0이고, NaN은 양수 NaN이면 +∞보다 뒤에 온다.
+#[derive(Debug, Clone, Copy)]
+pub struct Total<F>(pub F);
+
+macro_rules! total_float {
+    ($($t:ty),*) => {$(
+        impl PartialEq for Total<$t> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl Eq for Total<$t> {}
+
+        impl PartialOrd for Total<$t> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Total<$t> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+    )*};
+}
+
+total_float!(f32, f64);
+
+// 범위 하나를 (시작, 끝)으로 바꾼다. 시작은 포함, 끝은 미포함이고 None이면 그쪽으로 끝이 없다.
+pub trait BucketRange<K> {
+    fn into_bounds(self) -> (Option<K>, Option<K>);
+}
+
+impl<K> BucketRange<K> for Range<K> {
+    fn into_bounds(self) -> (Option<K>, Option<K>) {
+        (Some(self.start), Some(self.end))
+    }
+}
+
+impl<K> BucketRange<K> for RangeFrom<K> {
+    fn into_bounds(self) -> (Option<K>, Option<K>) {
+        (Some(self.start), None)
+    }
+}
+
+impl<K> BucketRange<K> for RangeTo<K> {
+    fn into_bounds(self) -> (Option<K>, Option<K>) {
+        (None, Some(self.end))
+    }
+}
+
+impl<K> BucketRange<K> for RangeFull {
+    fn into_bounds(self) -> (Option<K>, Option<K>) {
+        (None, None)
+    }
+}
+
+// 정수는 a..=b를 a..b+1로 바꾼다. b가 그 타입의 최댓값이면 끝이 없는 것과 같다.
+macro_rules! inclusive_int {
+    ($($t:ty),*) => {$(
+        impl BucketRange<$t> for RangeInclusive<$t> {
+            fn into_bounds(self) -> (Option<$t>, Option<$t>) {
+                let (start, end) = self.into_inner();
+                (Some(start), end.checked_add(1))
+            }
+        }
+
+        impl BucketRange<$t> for RangeToInclusive<$t> {
+            fn into_bounds(self) -> (Option<$t>, Option<$t>) {
+                (None, self.end.checked_add(1))
+            }
+        }
+    )*};
+}
+
+inclusive_int!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket<K, V> {
+    // 포함. None이면 -∞
+    pub start: Option<K>,
+    // 미포함. None이면 +∞
+    pub end: Option<K>,
+    pub value: V,
+}
+
+impl<K: Ord, V> Bucket<K, V> {
+    pub fn contains(&self, k: &K) -> bool {
+        self.start.as_ref().is_none_or(|s| s <= k) && self.end.as_ref().is_none_or(|e| k < e)
+    }
+
+    // other가 이 범위 안에 모두 들어가는지
+    fn covers<W>(&self, other: &Bucket<K, W>) -> bool {
+        let start_ok = match (&self.start, &other.start) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(a), Some(b)) => a <= b,
+        };
+        let end_ok = match (&self.end, &other.end) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(a), Some(b)) => b <= a,
+        };
+        start_ok && end_ok
+    }
+}
+
+// 만들 때의 오류. 번호는 넘겨준 범위들 중 몇 번째인지 (0부터)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BucketError {
+    // 시작 >= 끝이라 아무 값도 들어갈 수 없는 범위
+    Empty { index: usize },
+    Overlap { first: usize, second: usize },
+    // contiguous에서만: first의 끝과 second의 시작 사이에 어느 범위에도 들지 않는 값이 있다.
+    Gap { first: usize, second: usize },
+    // rebin에서만: 원래 범위 하나(index번째)가 새 범위 여러 개에 걸쳐 있어서 어느 쪽으로 셀지 정할 수 없다.
+    Straddle { index: usize },
+}
+
+impl fmt::Display for BucketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BucketError::Empty { index } => write!(f, "bucket {} is empty", index),
+            BucketError::Overlap { first, second } => {
+                write!(f, "buckets {} and {} overlap", first, second)
+            }
+            BucketError::Gap { first, second } => {
+                write!(f, "gap between buckets {} and {}", first, second)
+            }
+            BucketError::Straddle { index } => {
+                write!(f, "bucket {} spans several target buckets", index)
+            }
+        }
+    }
+}
+
+impl Error for BucketError {}
+
+// 범위마다 들어간 값의 개수. counts[i]는 Buckets::iter()의 i번째 범위의 개수
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    pub counts: Vec<usize>,
+    // 어느 범위에도 들지 않은 값의 개수
+    pub outside: usize,
+}
+
+impl Histogram {
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum::<usize>() + self.outside
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Buckets<K, V> {
+    // 시작값 순서로 정렬되어 있고 서로 겹치지 않는다.
+    buckets: Vec<Bucket<K, V>>,
+}
+
+impl<K: Ord, V> Buckets<K, V> {
+    // 범위가 비었거나 서로 겹치면 오류. 범위 사이의 빈틈은 허용한다.
+    pub fn new<R, I>(ranges: I) -> Result<Buckets<K, V>, BucketError>
+    where
+        R: BucketRange<K>,
+        I: IntoIterator<Item = (R, V)>,
+    {
+        Buckets::build(ranges, false)
+    }
+
+    // new와 같고, 범위 사이에 빈틈이 있어도 오류다. (맨 앞과 맨 뒤 바깥은 상관없다.)
+    pub fn contiguous<R, I>(ranges: I) -> Result<Buckets<K, V>, BucketError>
+    where
+        R: BucketRange<K>,
+        I: IntoIterator<Item = (R, V)>,
+    {
+        Buckets::build(ranges, true)
+    }
+
+    fn build<R, I>(ranges: I, deny_gaps: bool) -> Result<Buckets<K, V>, BucketError>
+    where
+        R: BucketRange<K>,
+        I: IntoIterator<Item = (R, V)>,
+    {
+        let mut numbered = Vec::new();
+        for (index, (range, value)) in ranges.into_iter().enumerate() {
+            let (start, end) = range.into_bounds();
+            if let (Some(s), Some(e)) = (&start, &end)
+                && s >= e
+            {
+                return Err(BucketError::Empty { index });
+            }
+            numbered.push((index, Bucket { start, end, value }));
+        }
+        // Option의 순서에서 None(-∞)이 Some보다 앞이다.
+        numbered.sort_by(|(_, a), (_, b)| a.start.cmp(&b.start));
+
+        for pair in numbered.windows(2) {
+            let ((i, a), (j, b)) = (&pair[0], &pair[1]);
+            let (first, second) = (*i.min(j), *i.max(j));
+            // 정렬되어 있으므로 a의 끝과 b의 시작만 비교하면 된다.
+            let order = match (&a.end, &b.start) {
+                (None, _) | (_, None) => Ordering::Greater,
+                (Some(end), Some(start)) => end.cmp(start),
+            };
+            match order {
+                Ordering::Greater => return Err(BucketError::Overlap { first, second }),
+                Ordering::Less if deny_gaps => return Err(BucketError::Gap { first, second }),
+                _ => {}
+            }
+        }
+        Ok(Buckets {
+            buckets: numbered.into_iter().map(|(_, b)| b).collect(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.buckets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    // 시작값 순서로
+    pub fn iter(&self) -> std::slice::Iter<'_, Bucket<K, V>> {
+        self.buckets.iter()
+    }
+
+    // k가 들어가는 범위의 번호 (iter()의 순서). 이진 탐색이라 O(log n)
+    pub fn index_of(&self, k: &K) -> Option<usize> {
+        // k 이하에서 시작하는 범위들 중 마지막 것만 k를 담을 수 있다.
+        let after = self
+            .buckets
+            .partition_point(|b| b.start.as_ref().is_none_or(|s| s <= k));
+        let i = after.checked_sub(1)?;
+        self.buckets[i].contains(k).then_some(i)
+    }
+
+    pub fn get_bucket(&self, k: &K) -> Option<&Bucket<K, V>> {
+        self.index_of(k).map(|i| &self.buckets[i])
+    }
+
+    pub fn get(&self, k: &K) -> Option<&V> {
+        self.get_bucket(k).map(|b| &b.value)
+    }
+
+    // 값마다 index_of를 하므로 값이 m개이면 O(m log n)
+    pub fn histogram<'a>(&self, values: impl IntoIterator<Item = &'a K>) -> Histogram
+    where
+        K: 'a,
+    {
+        let mut hist = Histogram {
+            counts: vec![0; self.len()],
+            outside: 0,
+        };
+        for k in values {
+            match self.index_of(k) {
+                Some(i) => hist.counts[i] += 1,
+                None => hist.outside += 1,
+            }
+        }
+        hist
+    }
+
+    // 이 범위들로 센 histogram을 target의 범위들로 옮겨 담는다. 원래 범위 하나가 target 범위 하나 안에
+    // 모두 들어가야 한다(더 크게 묶기만 된다). 어느 target 범위와도 겹치지 않는 것은 outside로 간다.
+    pub fn rebin<W>(
+        &self,
+        hist: &Histogram,
+        target: &Buckets<K, W>,
+    ) -> Result<Histogram, BucketError> {
+        assert_eq!(
+            hist.counts.len(),
+            self.len(),
+            "histogram does not belong to these buckets"
+        );
+        let mut out = Histogram {
+            counts: vec![0; target.len()],
+            outside: hist.outside,
+        };
+        for (index, (bucket, &count)) in self.buckets.iter().zip(&hist.counts).enumerate() {
+            match target.place(bucket) {
+                Placement::Inside(t) => out.counts[t] += count,
+                Placement::Outside => out.outside += count,
+                Placement::Straddle => return Err(BucketError::Straddle { index }),
+            }
+        }
+        Ok(out)
+    }
+
+    // bucket이 이 범위들 중 어디에 들어가는지
+    fn place<W>(&self, bucket: &Bucket<K, W>) -> Placement {
+        // bucket과 겹칠 수 있는 범위는 bucket의 끝보다 앞에서 시작하는 것들 중 마지막 것부터다.
+        let before_end = match &bucket.end {
+            None => self.len(),
+            Some(e) => self
+                .buckets
+                .partition_point(|b| b.start.as_ref().is_none_or(|s| s < e)),
+        };
+        let Some(last) = before_end.checked_sub(1) else {
+            return Placement::Outside;
+        };
+        let candidate = &self.buckets[last];
+        if candidate.covers(bucket) {
+            return Placement::Inside(last);
+        }
+        // candidate가 bucket의 시작보다 앞에서 끝나면 겹치는 범위가 하나도 없다.
+        let ends_before = match (&candidate.end, &bucket.start) {
+            (Some(e), Some(s)) => e <= s,
+            _ => false,
+        };
+        if ends_before {
+            Placement::Outside
+        } else {
+            Placement::Straddle
+        }
+    }
+}
+
+enum Placement {
+    Inside(usize),
+    Outside,
+    Straddle,
+}
+
+// main.rs 2번 예제의 match
+#[cfg(test)]
+fn age_match(age: u32) -> &'static str {
+    match age {
+        0..=10 => "baby",
+        11..=20 => "teen",
+        21..=60 => "adult",
+        _ => "old",
+    }
+}
+
+#[cfg(test)]
+fn ages() -> Buckets<u32, &'static str> {
+    Buckets::contiguous([
+        (0..=10, "baby"),
+        (11..=20, "teen"),
+        (21..=60, "adult"),
+        (61..=u32::MAX, "old"),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn age_buckets_match_match_expression() {
+    let ages = ages();
+    for age in (0..=200).chain([u32::MAX - 1, u32::MAX]) {
+        assert_eq!(ages.get(&age), Some(&age_match(age)), "age {}", age);
+    }
+    assert_eq!(ages.get_bucket(&33).unwrap().start, Some(21));
+    assert_eq!(ages.get_bucket(&33).unwrap().end, Some(61));
+    // ..=u32::MAX는 끝이 없는 것과 같다
+    assert_eq!(ages.iter().last().unwrap().end, None);
+
+    // 넘겨주는 순서와 상관없이 정렬된다
+    let shuffled = Buckets::contiguous([
+        (61..=u32::MAX, "old"),
+        (11..=20, "teen"),
+        (0..=10, "baby"),
+        (21..=60, "adult"),
+    ])
+    .unwrap();
+    assert_eq!(shuffled, ages);
+}
+
+#[test]
+fn construction_reports_overlaps_and_gaps() {
+    assert_eq!(
+        Buckets::new([(0..=10, 'a'), (10..=20, 'b')]),
+        Err(BucketError::Overlap {
+            first: 0,
+            second: 1
+        })
+    );
+    assert_eq!(
+        Buckets::new([(20..30, 'c'), (0..10, 'a'), (5..6, 'b')]),
+        Err(BucketError::Overlap {
+            first: 1,
+            second: 2
+        })
+    );
+    // 끝이 없는 범위끼리
+    assert_eq!(
+        Buckets::new([(..5, 'a'), (..3, 'b')]).unwrap_err(),
+        BucketError::Overlap {
+            first: 0,
+            second: 1
+        }
+    );
+    assert_eq!(
+        Buckets::new([(10..=i32::MAX, 'a'), (20..=30, 'b')]).unwrap_err(),
+        BucketError::Overlap {
+            first: 0,
+            second: 1
+        }
+    );
+    assert_eq!(
+        Buckets::new([(0..10, 'a'), (5..5, 'b')]).unwrap_err(),
+        BucketError::Empty { index: 1 }
+    );
+    assert_eq!(
+        Buckets::new([(RangeInclusive::new(3, 2), 'a')]).unwrap_err(),
+        BucketError::Empty { index: 0 }
+    );
+
+    // 빈틈은 new에서는 괜찮고 contiguous에서는 오류
+    let gappy = [(0..10, 'a'), (11..20, 'b')];
+    let b = Buckets::new(gappy.clone()).unwrap();
+    assert_eq!(b.get(&10), None);
+    assert_eq!(b.get(&11), Some(&'b'));
+    assert_eq!(
+        Buckets::contiguous(gappy).unwrap_err(),
+        BucketError::Gap {
+            first: 0,
+            second: 1
+        }
+    );
+    // 맞닿은 범위는 빈틈이 아니다
+    assert!(Buckets::contiguous([(0..10, 'a'), (10..20, 'b'), (20..i32::MAX, 'c')]).is_ok());
+    assert!(Buckets::contiguous([(0..=9, 'a'), (10..=19, 'b')]).is_ok());
+    assert_eq!(
+        Buckets::<i32, ()>::new(Vec::<(Range<i32>, ())>::new()).map(|b| b.len()),
+        Ok(0)
+    );
+    assert_eq!(
+        BucketError::Gap {
+            first: 0,
+            second: 1
+        }
+        .to_string(),
+        "gap between buckets 0 and 1"
+    );
+}
+
+#[test]
+fn lookup_matches_linear_scan() {
+    // 끊는 점 7개 중 몇 개를 골라 그 사이사이를 범위로 만들고, 몇 개는 빼서 빈틈을 만든다.
+    // 고르는 방법과 빼는 방법을 모두 확인한다.
+    let points: [i64; 7] = [-100, -3, 0, 1, 2, 50, 99];
+    for cut_mask in 0..1u32 << points.len() {
+        let cuts: Vec<i64> = (0..points.len())
+            .filter(|i| cut_mask >> i & 1 == 1)
+            .map(|i| points[i])
+            .collect();
+        let windows = cuts.len().saturating_sub(1);
+        for keep_mask in 0..1u32 << windows {
+            let ranges: Vec<(Range<i64>, usize)> = cuts
+                .windows(2)
+                .enumerate()
+                .filter(|(i, _)| keep_mask >> i & 1 == 1)
+                .map(|(i, w)| (w[0]..w[1], i))
+                .collect();
+            let buckets = Buckets::new(ranges.clone()).unwrap();
+            for k in -110..110 {
+                let expected = ranges.iter().find(|(r, _)| r.contains(&k)).map(|(_, v)| v);
+                assert_eq!(buckets.get(&k), expected, "k = {} in {:?}", k, ranges);
+            }
+        }
+    }
+}
+
+#[test]
+fn floats_with_total_order() {
+    let inf = f64::INFINITY;
+    let temps = Buckets::contiguous([
+        (Total(-inf)..Total(0.0), "freezing"),
+        (Total(0.0)..Total(15.0), "cold"),
+        (Total(15.0)..Total(25.0), "mild"),
+        (Total(25.0)..Total(inf), "hot"),
+    ])
+    .unwrap();
+    assert_eq!(temps.get(&Total(-3.5)), Some(&"freezing"));
+    assert_eq!(temps.get(&Total(0.0)), Some(&"cold"));
+    assert_eq!(temps.get(&Total(14.999)), Some(&"cold"));
+    assert_eq!(temps.get(&Total(15.0)), Some(&"mild"));
+    assert_eq!(temps.get(&Total(1e300)), Some(&"hot"));
+    // total_cmp에서 -0.0은 0.0보다 작다
+    assert_eq!(temps.get(&Total(-0.0)), Some(&"freezing"));
+    // +∞ 자신과 NaN은 어느 범위에도 들지 않는다
+    assert_eq!(temps.get(&Total(inf)), None);
+    assert_eq!(temps.get(&Total(f64::NAN)), None);
+
+    let readings: Vec<Total<f64>> = [-1.0, 3.0, 20.0, 21.5, 30.0, f64::NAN]
+        .into_iter()
+        .map(Total)
+        .collect();
+    assert_eq!(
+        temps.histogram(&readings),
+        Histogram {
+            counts: vec![1, 1, 2, 1],
+            outside: 1
+        }
+    );
+    assert!(Buckets::new([(Total(1.0f32)..Total(0.5), ())]).is_err());
+}
+
+#[test]
+fn dates_as_integers_histogram_and_rebin() {
+    // 날짜를 yyyymmdd 정수로. 2024년 월별 범위와 분기별 범위
+    let months = Buckets::contiguous((1..=12).map(|m| {
+        let start = 2024 * 10000 + m * 100 + 1;
+        (start..start + 100, m)
+    }))
+    .unwrap();
+    let quarters = Buckets::contiguous([
+        (20240101..20240401, "Q1"),
+        (20240401..20240701, "Q2"),
+        (20240701..20241001, "Q3"),
+        (20241001..20250101, "Q4"),
+    ])
+    .unwrap();
+    assert_eq!(months.get(&20240229), Some(&2));
+    assert_eq!(quarters.get(&20241231), Some(&"Q4"));
+
+    let dates = [
+        20240105, 20240131, 20240229, 20240301, 20240510, 20240815, 20240930, 20241225, 20231231,
+        20250101,
+    ];
+    let monthly = months.histogram(&dates);
+    assert_eq!(monthly.counts, [2, 1, 1, 0, 1, 0, 0, 1, 1, 0, 0, 1]);
+    assert_eq!(monthly.outside, 2);
+
+    let quarterly = months.rebin(&monthly, &quarters).unwrap();
+    assert_eq!(quarterly, quarters.histogram(&dates));
+    assert_eq!(quarterly.counts, [4, 1, 2, 1]);
+    assert_eq!(quarterly.total(), dates.len());
+
+    // 분기별을 월별로 쪼갤 수는 없다
+    assert_eq!(
+        quarters.rebin(&quarterly, &months),
+        Err(BucketError::Straddle { index: 0 })
+    );
+}
+
+#[test]
+fn rebin_outside_and_straddle() {
+    let fine = Buckets::new([
+        (0..=9, ()),
+        (10..=19, ()),
+        (30..=39, ()),
+        (40..=i32::MAX, ()),
+    ])
+    .unwrap();
+    let coarse = Buckets::new([(0..=19, 'a'), (35..=99, 'b')]).unwrap();
+    let hist = Histogram {
+        counts: vec![1, 2, 3, 4],
+        outside: 5,
+    };
+    // 30..40은 35에서 나뉘므로 옮겨 담을 수 없다
+    assert_eq!(
+        fine.rebin(&hist, &coarse),
+        Err(BucketError::Straddle { index: 2 })
+    );
+
+    let coarse = Buckets::new([(0..=19, 'a'), (30..=99, 'b')]).unwrap();
+    // 40..은 100 너머까지 이어지므로 역시 안 된다
+    assert_eq!(
+        fine.rebin(&hist, &coarse),
+        Err(BucketError::Straddle { index: 3 })
+    );
+
+    let coarse = Buckets::new([(0..=19, 'a'), (30..=i32::MAX, 'b')]).unwrap();
+    let out = fine.rebin(&hist, &coarse).unwrap();
+    assert_eq!(out.counts, [3, 7]);
+    assert_eq!(out.outside, 5);
+
+    // 어느 범위와도 겹치지 않으면 outside로
+    let only_high = Buckets::new([(25..=29, 'x'), (50..=i32::MAX, 'y')]).unwrap();
+    let out = Buckets::new([(0..10, ()), (10..20, ()), (60..70, ())])
+        .unwrap()
+        .rebin(
+            &Histogram {
+                counts: vec![1, 2, 3],
+                outside: 0,
+            },
+            &only_high,
+        )
+        .unwrap();
+    assert_eq!(out.counts, [0, 3]);
+    assert_eq!(out.outside, 3);
+}
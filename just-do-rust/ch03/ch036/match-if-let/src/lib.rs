@@ -0,0 +1,5 @@
+// main.rs의 match 예제들을 넓힌 모듈들
+// - rules: match로 하던 FizzBuzz, 나이대 나누기를 설정 글로 적은 규칙으로 한다
+// - buckets: 나이대 match의 범위 arm들을 겹침 검사와 이진 탐색이 되는 범위 분류기로 만든다
+pub mod buckets;
+pub mod rules;
@@ -1,3 +1,6 @@
+use match_if_let::buckets::Buckets;
+use match_if_let::rules::RuleSet;
+
 fn main() {
     // 1 변수 값에 따른 matching
     let x = 1;
@@ -61,4 +64,43 @@ fn main() {
     } else {
         println!("not a digit");
     }
+
+    // 7 match의 arm들을 설정 글로 적은 규칙으로 (src/rules.rs)
+    // 7의 배수에 bazz를 더하고 싶으면 match를 고치지 않고 규칙 한 줄을 더하면 된다.
+    let fizzbuzz: RuleSet =
+        "mode: concat\ndivisible:3 => fizz\ndivisible:5 => buzz\ndivisible:7 => bazz"
+            .parse()
+            .unwrap();
+    fizzbuzz
+        .write_range(100..=105, &mut std::io::stdout().lock())
+        .unwrap(); // 100 이면 buzz, 105 이면 fizzbuzzbazz
+
+    let ages: RuleSet =
+        "range:0..=10 => baby\nrange:11..=20 => teen\nrange:21..=60 => adult\nelse => old"
+            .parse()
+            .unwrap();
+    println!("{:?}", ages.eval(33)); // Some("adult")
+
+    match "divisible:0 => never".parse::<RuleSet>() {
+        Ok(_) => println!("ok"),
+        Err(e) => println!("{}", e), // line 1: divisor must not be zero
+    }
+
+    // 8 2번 예제의 범위 arm들을 범위 분류기로 (src/buckets.rs)
+    // 범위가 겹치거나 빈틈이 있으면 만들 때 알려 준다.
+    let ages = Buckets::contiguous([
+        (0..=10, "baby"),
+        (11..=20, "teen"),
+        (21..=60, "adult"),
+        (61..=u32::MAX, "old"),
+    ])
+    .unwrap();
+    println!("{:?}", ages.get(&33)); // Some("adult")
+    let hist = ages.histogram(&[3, 15, 33, 47, 70, 12]);
+    for (bucket, count) in ages.iter().zip(&hist.counts) {
+        println!("{:>5}: {}", bucket.value, count);
+    }
+    if let Err(e) = Buckets::contiguous([(0..=10, "baby"), (12..=20, "teen")]) {
+        println!("{}", e); // gap between buckets 0 and 1
+    }
 }
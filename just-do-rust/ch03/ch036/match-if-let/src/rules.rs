@@ -0,0 +1,555 @@
+// main.rs의 4번 예제는 match (n % 3, n % 5)로 FizzBuzz를, 2번 예제는 match age { 0..=10 => "baby", ... }로
+// 나이대를 나눈다. 규칙이 코드에 박혀 있어서 "7의 배수는 Bazz" 같은 규칙 하나를 더하려면 match를 고쳐야 한다.
+// 여기서는 (조건, 이름) 규칙들을 글(설정)로 적어 두고 읽어서 쓴다.
+//
+//   # FizzBuzz
+//   mode: concat
+//   divisible:3 => fizz
+//   divisible:5 => buzz
+//
+//   # 나이대
+//   mode: first
+//   range:0..=10 => baby
+//   range:11..=20 => teen
+//   range:21..=60 => adult
+//   else => old
+//
+// - 조건: divisible:N (N의 배수), equals:N, range:A..=B / A..B / A.. / ..=B / ..B
+// - mode: first면 위에서부터 처음 맞는 규칙 하나만 쓴다 (match의 arm과 같다). 생략하면 first
+//         concat이면 맞는 규칙의 이름을 모두 순서대로 이어 붙인다 (3과 5의 배수 → "fizzbuzz")
+// - else => 이름: 맞는 규칙이 하나도 없을 때 쓴다 (match의 _ arm). 없으면 결과가 None
+// - #으로 시작하는 줄과 빈 줄은 건너뛴다.
+//
+// 설정은 &str에서 parse로 읽는다. 파일이라면 fs::read_to_string(path)?.parse::<RuleSet>()처럼 쓴다.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Predicate {
+    Divisible(i64),
+    Equals(i64),
+    // 양 끝을 포함하는 범위. None이면 그쪽으로 끝이 없다.
+    Range {
+        start: Option<i64>,
+        end: Option<i64>,
+    },
+}
+
+impl Predicate {
+    pub fn matches(&self, n: i64) -> bool {
+        match *self {
+            // i64::MIN % -1은 넘침으로 패닉이므로 wrapping_rem을 쓴다. (나머지는 0)
+            Predicate::Divisible(d) => n.wrapping_rem(d) == 0,
+            Predicate::Equals(x) => n == x,
+            Predicate::Range { start, end } => {
+                start.is_none_or(|s| s <= n) && end.is_none_or(|e| n <= e)
+            }
+        }
+    }
+}
+
+// 설정 파일에 적는 모양 그대로 쓴다. ..B는 ..=B-1로 바뀌어 있다.
+impl fmt::Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Predicate::Divisible(d) => write!(f, "divisible:{}", d),
+            Predicate::Equals(x) => write!(f, "equals:{}", x),
+            Predicate::Range { start, end } => {
+                write!(f, "range:")?;
+                if let Some(s) = start {
+                    write!(f, "{}", s)?;
+                }
+                match end {
+                    Some(e) => write!(f, "..={}", e),
+                    None => write!(f, ".."),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub predicate: Predicate,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    // 처음 맞는 규칙 하나
+    #[default]
+    First,
+    // 맞는 규칙 모두를 이어 붙임
+    Concat,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RuleSet {
+    mode: Mode,
+    rules: Vec<Rule>,
+    fallback: Option<String>,
+}
+
+// 설정의 형식이 잘못된 줄. line은 1부터 센다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRulesError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseRulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ParseRulesError {}
+
+impl RuleSet {
+    pub fn new(mode: Mode) -> RuleSet {
+        RuleSet {
+            mode,
+            ..RuleSet::default()
+        }
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    // 규칙을 맨 뒤에 더한다. first 모드에서는 앞의 규칙이 먼저다.
+    pub fn push(&mut self, predicate: Predicate, label: impl Into<String>) {
+        self.rules.push(Rule {
+            predicate,
+            label: label.into(),
+        });
+    }
+
+    // 맞는 규칙이 없을 때 쓸 이름 (else => ...). 바꾸기 전의 값을 돌려준다.
+    pub fn set_fallback(&mut self, label: Option<String>) -> Option<String> {
+        std::mem::replace(&mut self.fallback, label)
+    }
+
+    // n에 붙는 이름. 맞는 규칙도 else도 없으면 None
+    pub fn eval(&self, n: i64) -> Option<String> {
+        let mut labels = self
+            .rules
+            .iter()
+            .filter(|r| r.predicate.matches(n))
+            .map(|r| r.label.as_str());
+        let label = match self.mode {
+            Mode::First => labels.next().map(str::to_string),
+            // 이름은 비어 있을 수 없으므로, 이어 붙인 것이 비었다면 맞는 규칙이 없었던 것이다.
+            Mode::Concat => Some(labels.collect::<String>()).filter(|s| !s.is_empty()),
+        };
+        label.or_else(|| self.fallback.clone())
+    }
+
+    // 범위의 수마다 (수, 이름)을 하나씩 만들어 주는 반복자. 꺼낼 때 계산하므로 끝이 없는 범위(1..)도 된다.
+    pub fn eval_iter<I>(&self, numbers: I) -> Eval<'_, I::IntoIter>
+    where
+        I: IntoIterator<Item = i64>,
+    {
+        Eval {
+            rules: self,
+            numbers: numbers.into_iter(),
+        }
+    }
+
+    // 수마다 한 줄씩 바로바로 쓴다. 이름이 없는 수는 수 자체를 쓴다. (FizzBuzz의 출력 모양)
+    pub fn write_range<I, W>(&self, numbers: I, out: &mut W) -> io::Result<()>
+    where
+        I: IntoIterator<Item = i64>,
+        W: Write,
+    {
+        self.write_range_with(numbers, out, |n| n.to_string())
+    }
+
+    // write_range와 같지만, 이름이 없는 수에 쓸 글을 unlabelled로 만든다.
+    // main.rs 4번 예제처럼 나머지를 찍으려면 |n| format!("{} {} ", n % 3, n % 5)를 넘긴다.
+    pub fn write_range_with<I, W, F>(
+        &self,
+        numbers: I,
+        out: &mut W,
+        mut unlabelled: F,
+    ) -> io::Result<()>
+    where
+        I: IntoIterator<Item = i64>,
+        W: Write,
+        F: FnMut(i64) -> String,
+    {
+        for (n, label) in self.eval_iter(numbers) {
+            match label {
+                Some(label) => writeln!(out, "{}", label)?,
+                None => writeln!(out, "{}", unlabelled(n))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Eval<'a, I> {
+    rules: &'a RuleSet,
+    numbers: I,
+}
+
+impl<I: Iterator<Item = i64>> Iterator for Eval<'_, I> {
+    type Item = (i64, Option<String>);
+
+    fn next(&mut self) -> Option<(i64, Option<String>)> {
+        let n = self.numbers.next()?;
+        Some((n, self.rules.eval(n)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.numbers.size_hint()
+    }
+}
+
+fn parse_number(s: &str) -> Result<i64, String> {
+    s.trim()
+        .parse()
+        .map_err(|_| format!("invalid number {:?}", s.trim()))
+}
+
+fn parse_range(s: &str) -> Result<Predicate, String> {
+    let Some((a, b)) = s.split_once("..") else {
+        return Err(format!("invalid range {:?}", s));
+    };
+    let start = match a.trim() {
+        "" => None,
+        a => Some(parse_number(a)?),
+    };
+    let end = if let Some(b) = b.strip_prefix('=') {
+        Some(parse_number(b)?)
+    } else if b.trim().is_empty() {
+        None
+    } else {
+        // A..B는 B를 빼므로 A..=B-1로 바꿔 둔다. i64::MIN을 빼는 범위는 비어 있다.
+        let b = parse_number(b)?;
+        Some(b.checked_sub(1).ok_or(format!("empty range {:?}", s))?)
+    };
+    if let (Some(a), Some(b)) = (start, end)
+        && a > b
+    {
+        return Err(format!("empty range {:?}", s));
+    }
+    Ok(Predicate::Range { start, end })
+}
+
+impl FromStr for Predicate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Predicate, String> {
+        let (kind, arg) = s
+            .split_once(':')
+            .ok_or_else(|| format!("unknown predicate {:?}", s))?;
+        match kind.trim() {
+            "divisible" => match parse_number(arg)? {
+                0 => Err("divisor must not be zero".to_string()),
+                d => Ok(Predicate::Divisible(d)),
+            },
+            "equals" => Ok(Predicate::Equals(parse_number(arg)?)),
+            "range" => parse_range(arg.trim()),
+            _ => Err(format!("unknown predicate {:?}", s)),
+        }
+    }
+}
+
+// 한 줄에 하나: "mode: first|concat", "<조건> => <이름>", "else => <이름>"
+impl FromStr for RuleSet {
+    type Err = ParseRulesError;
+
+    fn from_str(text: &str) -> Result<RuleSet, ParseRulesError> {
+        let mut set = RuleSet::default();
+        let mut seen_mode = false;
+        for (i, raw) in text.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parse_err = |message: String| ParseRulesError {
+                line: i + 1,
+                message,
+            };
+            let Some((lhs, label)) = line.split_once("=>") else {
+                let mode = line
+                    .strip_prefix("mode:")
+                    .ok_or_else(|| {
+                        parse_err(format!("expected '<predicate> => <label>', got {:?}", line))
+                    })?
+                    .trim();
+                if seen_mode {
+                    return Err(parse_err("duplicate mode".to_string()));
+                }
+                set.mode = match mode {
+                    "first" => Mode::First,
+                    "concat" => Mode::Concat,
+                    _ => return Err(parse_err(format!("unknown mode {:?}", mode))),
+                };
+                seen_mode = true;
+                continue;
+            };
+            let (lhs, label) = (lhs.trim(), label.trim());
+            if label.is_empty() {
+                return Err(parse_err("missing label".to_string()));
+            }
+            if lhs == "else" {
+                if set.fallback.is_some() {
+                    return Err(parse_err("duplicate else".to_string()));
+                }
+                set.fallback = Some(label.to_string());
+            } else {
+                set.push(lhs.parse().map_err(parse_err)?, label);
+            }
+        }
+        Ok(set)
+    }
+}
+
+// 다시 읽을 수 있는 설정 글로 쓴다.
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            Mode::First => writeln!(f, "mode: first")?,
+            Mode::Concat => writeln!(f, "mode: concat")?,
+        }
+        for rule in &self.rules {
+            writeln!(f, "{} => {}", rule.predicate, rule.label)?;
+        }
+        if let Some(label) = &self.fallback {
+            writeln!(f, "else => {}", label)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+const FIZZBUZZ: &str = "\
+# 3의 배수는 fizz, 5의 배수는 buzz, 둘 다면 fizzbuzz
+mode: concat
+divisible:3 => fizz
+divisible:5 => buzz
+";
+
+#[cfg(test)]
+const AGES: &str = "\
+mode: first
+range:0..=10 => baby
+range:11..=20 => teen
+range:21..=60 => adult
+else => old
+";
+
+// main.rs 4번 예제의 match가 println!으로 찍는 글 (줄바꿈은 빼고). 어느 쪽도 아니면 나머지 두 개를 찍는다.
+#[cfg(test)]
+fn fizzbuzz_match(n: i64) -> String {
+    match (n % 3, n % 5) {
+        (0, 0) => "fizzbuzz".to_string(),
+        (0, _) => "fizz".to_string(),
+        (_, 0) => "buzz".to_string(),
+        (_, _) => format!("{} {} ", n % 3, n % 5),
+    }
+}
+
+// 규칙에 맞지 않는 수에 main.rs의 마지막 arm처럼 나머지를 쓴다.
+#[cfg(test)]
+fn remainders(n: i64) -> String {
+    format!("{} {} ", n % 3, n % 5)
+}
+
+// main.rs 2번 예제의 match
+#[cfg(test)]
+fn age_match(age: u32) -> &'static str {
+    match age {
+        0..=10 => "baby",
+        11..=20 => "teen",
+        21..=60 => "adult",
+        _ => "old",
+    }
+}
+
+#[test]
+fn fizzbuzz_from_config_matches_match_expression() {
+    let rules: RuleSet = FIZZBUZZ.parse().unwrap();
+    assert_eq!(rules.mode(), Mode::Concat);
+    assert_eq!(rules.rules().len(), 2);
+    for n in -45..=300 {
+        let printed = rules.eval(n).unwrap_or_else(|| remainders(n));
+        assert_eq!(printed, fizzbuzz_match(n), "n = {}", n);
+    }
+
+    // first 모드라면 15의 배수 규칙을 맨 앞에 두어야 한다 (match의 (0, 0) arm을 맨 앞에 두는 것과 같다)
+    let first: RuleSet = "divisible:15 => fizzbuzz\ndivisible:3 => fizz\ndivisible:5 => buzz"
+        .parse()
+        .unwrap();
+    assert_eq!(first.mode(), Mode::First);
+    for n in -45..=300 {
+        let printed = first.eval(n).unwrap_or_else(|| remainders(n));
+        assert_eq!(printed, fizzbuzz_match(n), "n = {}", n);
+    }
+}
+
+#[test]
+fn fizzbuzz_output_matches_main_rs() {
+    // main.rs의 n = 4
+    assert_eq!(fizzbuzz_match(4), "1 4 ");
+
+    let rules: RuleSet = FIZZBUZZ.parse().unwrap();
+    let mut out = Vec::new();
+    rules
+        .write_range_with(1..=15, &mut out, remainders)
+        .unwrap();
+    let expected = "1 1 \n2 2 \nfizz\n1 4 \nbuzz\nfizz\n1 2 \n2 3 \nfizz\nbuzz\n\
+                    2 1 \nfizz\n1 3 \n2 4 \nfizzbuzz\n";
+    assert_eq!(String::from_utf8(out).unwrap(), expected);
+
+    // 음수도 main.rs의 match가 println!으로 찍는 것과 같다.
+    let mut out = Vec::new();
+    rules
+        .write_range_with(-20..=20, &mut out, remainders)
+        .unwrap();
+    let printed: String = (-20..=20).map(|n| fizzbuzz_match(n) + "\n").collect();
+    assert_eq!(String::from_utf8(out).unwrap(), printed);
+}
+
+#[test]
+fn age_groups_from_config_match_match_expression() {
+    let rules: RuleSet = AGES.parse().unwrap();
+    for age in 0..=150u32 {
+        assert_eq!(rules.eval(age as i64).as_deref(), Some(age_match(age)));
+    }
+    // 2번 예제의 33살
+    assert_eq!(rules.eval(33).as_deref(), Some("adult"));
+}
+
+#[test]
+fn first_and_concat_modes() {
+    let mut rules = RuleSet::new(Mode::First);
+    rules.push(Predicate::Divisible(2), "even");
+    rules.push(Predicate::Equals(4), "four");
+    rules.push(Predicate::Divisible(7), "seven");
+    assert_eq!(rules.eval(4).as_deref(), Some("even"));
+    assert_eq!(rules.eval(14).as_deref(), Some("even"));
+    assert_eq!(rules.eval(21).as_deref(), Some("seven"));
+    assert_eq!(rules.eval(9), None);
+
+    rules.mode = Mode::Concat;
+    assert_eq!(rules.eval(4).as_deref(), Some("evenfour"));
+    assert_eq!(rules.eval(14).as_deref(), Some("evenseven"));
+    assert_eq!(rules.eval(9), None);
+
+    assert_eq!(rules.set_fallback(Some("-".to_string())), None);
+    assert_eq!(rules.eval(9).as_deref(), Some("-"));
+    assert_eq!(rules.eval(28).as_deref(), Some("evenseven"));
+}
+
+#[test]
+fn range_forms() {
+    let cases = [
+        ("range:1..=3", [false, true, true, true, false]),
+        ("range:1..3", [false, true, true, false, false]),
+        ("range:2..", [false, false, true, true, true]),
+        ("range:..=1", [true, true, false, false, false]),
+        ("range:..1", [true, false, false, false, false]),
+        ("range: 3 ..= 3 ", [false, false, false, true, false]),
+        ("range:..", [true, true, true, true, true]),
+    ];
+    for (text, expected) in cases {
+        let p: Predicate = text.parse().unwrap();
+        let got: Vec<bool> = (0..5).map(|n| p.matches(n)).collect();
+        assert_eq!(got, expected, "{}", text);
+    }
+    let p: Predicate = "range:-10..-5".parse().unwrap();
+    assert!(p.matches(-10) && p.matches(-6) && !p.matches(-5));
+    assert!(Predicate::Divisible(-3).matches(9));
+    assert!(Predicate::Divisible(4).matches(i64::MIN));
+    assert!(Predicate::Divisible(-1).matches(i64::MIN));
+}
+
+#[test]
+fn parse_errors_carry_line_numbers() {
+    let table = [
+        ("fizz", 1, "expected '<predicate> => <label>', got \"fizz\""),
+        ("# c\n\ndivisible:3 =>", 3, "missing label"),
+        ("divisible:0 => x", 1, "divisor must not be zero"),
+        ("divisible:x => x", 1, "invalid number \"x\""),
+        ("mode: first\nodd:1 => x", 2, "unknown predicate \"odd:1\""),
+        ("prime => x", 1, "unknown predicate \"prime\""),
+        ("range:5..=1 => x", 1, "empty range \"5..=1\""),
+        ("range:3..3 => x", 1, "empty range \"3..3\""),
+        ("range:3 => x", 1, "invalid range \"3\""),
+        ("range:1..=z => x", 1, "invalid number \"z\""),
+        ("mode: all", 1, "unknown mode \"all\""),
+        ("mode: first\nmode: concat", 2, "duplicate mode"),
+        ("else => a\nequals:1 => b\nelse => c", 3, "duplicate else"),
+    ];
+    for (text, line, message) in table {
+        let expected = ParseRulesError {
+            line,
+            message: message.to_string(),
+        };
+        assert_eq!(text.parse::<RuleSet>(), Err(expected), "{:?}", text);
+    }
+    let err = "\n\nbad".parse::<RuleSet>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 3: expected '<predicate> => <label>', got \"bad\""
+    );
+}
+
+#[test]
+fn display_round_trips() {
+    for text in [
+        FIZZBUZZ,
+        AGES,
+        "range:..=-1 => negative\nrange:1..100 => small",
+    ] {
+        let rules: RuleSet = text.parse().unwrap();
+        let again: RuleSet = rules.to_string().parse().unwrap();
+        assert_eq!(again, rules);
+    }
+    let rules: RuleSet = "range:1..100 => small".parse().unwrap();
+    assert_eq!(rules.to_string(), "mode: first\nrange:1..=99 => small\n");
+}
+
+#[test]
+fn streaming_output() {
+    let rules: RuleSet = FIZZBUZZ.parse().unwrap();
+    let mut out = Vec::new();
+    rules.write_range(1..=15, &mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert_eq!(
+        text.lines().collect::<Vec<_>>(),
+        [
+            "1", "2", "fizz", "4", "buzz", "fizz", "7", "8", "fizz", "buzz", "11", "fizz", "13",
+            "14", "fizzbuzz"
+        ]
+    );
+
+    // 끝이 없는 범위도 필요한 만큼만 계산한다
+    let mut it = rules.eval_iter(1..);
+    assert_eq!(it.size_hint(), (usize::MAX, None));
+    let labelled: Vec<_> = it
+        .by_ref()
+        .filter_map(|(n, l)| l.map(|l| (n, l)))
+        .take(3)
+        .collect();
+    assert_eq!(
+        labelled,
+        [
+            (3, "fizz".to_string()),
+            (5, "buzz".to_string()),
+            (6, "fizz".to_string())
+        ]
+    );
+    assert_eq!(it.next(), Some((7, None)));
+    assert_eq!(rules.eval_iter(0..10).size_hint(), (10, Some(10)));
+}
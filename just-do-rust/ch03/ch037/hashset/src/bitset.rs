@@ -0,0 +1,502 @@
+// 0, 1, 2, ...처럼 작은 정수만 담는 집합이라면, HashSet 대신 비트 배열로 만들 수 있다.
+// i가 들어 있으면 i번째 비트를 1로 둔다. u64 하나(워드)에 64개의 값을 담는다.
+//
+//   {1, 3, 64} -> words[0] = 0b1010, words[1] = 0b1
+//
+// 합집합, 교집합 같은 연산이 워드 단위의 |, &로 64개씩 한 번에 처리되어 빠르고, 메모리도 적게 쓴다.
+// 대신 큰 값(예: 1_000_000) 하나만 넣어도 그 값까지의 워드를 모두 만들어야 한다.
+
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitXor, Sub};
+
+const BITS: usize = u64::BITS as usize;
+
+#[derive(Clone, Default)]
+pub struct BitSet {
+    // 뒤쪽에 0인 워드가 남아 있을 수 있다. 비교할 때는 무시한다.
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> BitSet {
+        BitSet::default()
+    }
+
+    // 0 ~ n-1 값을 넣을 공간을 미리 만든다.
+    pub fn with_capacity(n: usize) -> BitSet {
+        BitSet {
+            words: vec![0; n.div_ceil(BITS)],
+        }
+    }
+
+    // 새로 넣었으면 true, 이미 있었으면 false (HashSet::insert와 같다)
+    pub fn insert(&mut self, i: usize) -> bool {
+        let (w, bit) = (i / BITS, 1u64 << (i % BITS));
+        if w >= self.words.len() {
+            self.words.resize(w + 1, 0);
+        }
+        let was = self.words[w] & bit != 0;
+        self.words[w] |= bit;
+        !was
+    }
+
+    pub fn remove(&mut self, i: usize) -> bool {
+        match self.words.get_mut(i / BITS) {
+            Some(word) => {
+                let bit = 1u64 << (i % BITS);
+                let was = *word & bit != 0;
+                *word &= !bit;
+                was
+            }
+            None => false,
+        }
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        self.words
+            .get(i / BITS)
+            .is_some_and(|w| w & (1u64 << (i % BITS)) != 0)
+    }
+
+    // 1인 비트의 개수. count_ones()는 CPU 명령 하나(popcnt)로 계산된다.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    // 작은 값부터 차례로
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            words: &self.words,
+            index: 0,
+            current: self.words.first().copied().unwrap_or(0),
+        }
+    }
+
+    pub fn min(&self) -> Option<usize> {
+        self.iter().next()
+    }
+
+    pub fn max(&self) -> Option<usize> {
+        let (i, w) = self
+            .words
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, w)| **w != 0)?;
+        Some(i * BITS + (BITS - 1 - w.leading_zeros() as usize))
+    }
+
+    // 워드마다 f(내 워드, 상대 워드)를 계산한 새 집합. 길이가 짧은 쪽은 0으로 채워서 계산한다.
+    fn zip_words(&self, other: &BitSet, f: impl Fn(u64, u64) -> u64) -> BitSet {
+        let n = self.words.len().max(other.words.len());
+        let get = |v: &[u64], i: usize| v.get(i).copied().unwrap_or(0);
+        BitSet {
+            words: (0..n)
+                .map(|i| f(get(&self.words, i), get(&other.words, i)))
+                .collect(),
+        }
+    }
+
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        self.zip_words(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        self.zip_words(other, |a, b| a & b)
+    }
+
+    pub fn difference(&self, other: &BitSet) -> BitSet {
+        self.zip_words(other, |a, b| a & !b)
+    }
+
+    pub fn symmetric_difference(&self, other: &BitSet) -> BitSet {
+        self.zip_words(other, |a, b| a ^ b)
+    }
+
+    pub fn union_with(&mut self, other: &BitSet) {
+        if self.words.len() < other.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+
+    pub fn intersect_with(&mut self, other: &BitSet) {
+        for (i, a) in self.words.iter_mut().enumerate() {
+            *a &= other.words.get(i).copied().unwrap_or(0);
+        }
+    }
+
+    pub fn is_subset(&self, other: &BitSet) -> bool {
+        self.words
+            .iter()
+            .enumerate()
+            .all(|(i, a)| a & !other.words.get(i).copied().unwrap_or(0) == 0)
+    }
+
+    pub fn is_superset(&self, other: &BitSet) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &BitSet) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+
+    // 자카드 유사도 |A ∩ B| / |A ∪ B|. 0(겹치는 것 없음) ~ 1(같음). 둘 다 비어 있으면 1로 본다.
+    pub fn jaccard(&self, other: &BitSet) -> f64 {
+        let union = self.union(other).len();
+        if union == 0 {
+            return 1.0;
+        }
+        self.intersection(other).len() as f64 / union as f64
+    }
+
+    // 이 집합의 모든 부분집합(2^n개). 빈 집합부터 시작해서 이진수로 세는 순서다.
+    // 원소가 64개 이상이면 부분집합이 너무 많으므로 패닉이다.
+    pub fn subsets(&self) -> Subsets {
+        let elements: Vec<usize> = self.iter().collect();
+        assert!(elements.len() < BITS, "too many elements for subsets");
+        Subsets {
+            end: 1u64 << elements.len(),
+            elements,
+            mask: 0,
+            size: None,
+        }
+    }
+
+    // 원소가 정확히 k개인 부분집합들 (조합 nCk)
+    pub fn subsets_of_size(&self, k: usize) -> Subsets {
+        let mut s = self.subsets();
+        if k > s.elements.len() {
+            s.mask = s.end;
+        } else {
+            // k개의 1로 시작하는 가장 작은 마스크
+            s.mask = (1u64 << k) - 1;
+            s.size = Some(k);
+        }
+        s
+    }
+
+    pub fn power_set(&self) -> Vec<BitSet> {
+        self.subsets().collect()
+    }
+}
+
+pub struct Iter<'a> {
+    words: &'a [u64],
+    index: usize,
+    // 아직 꺼내지 않은 현재 워드의 비트들
+    current: u64,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.index += 1;
+            self.current = *self.words.get(self.index)?;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // 가장 낮은 1 비트를 지운다.
+        self.current &= self.current - 1;
+        Some(self.index * BITS + bit)
+    }
+}
+
+impl<'a> IntoIterator for &'a BitSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+pub struct Subsets {
+    elements: Vec<usize>,
+    // mask의 j번째 비트가 1이면 elements[j]를 포함한다.
+    mask: u64,
+    end: u64,
+    // Some(k)면 1 비트가 k개인 마스크만 차례로 만든다.
+    size: Option<usize>,
+}
+
+impl Iterator for Subsets {
+    type Item = BitSet;
+
+    fn next(&mut self) -> Option<BitSet> {
+        if self.mask >= self.end {
+            return None;
+        }
+        let m = self.mask;
+        let set = (0..self.elements.len())
+            .filter(|j| m & (1 << j) != 0)
+            .map(|j| self.elements[j])
+            .collect();
+        self.mask = match self.size {
+            None => m + 1,
+            // k = 0이면 빈 집합 하나뿐
+            Some(0) => self.end,
+            // Gosper's hack: 1 비트 개수가 같은 다음으로 큰 수
+            Some(_) => {
+                let c = m & m.wrapping_neg();
+                let r = m + c;
+                (((r ^ m) >> 2) / c) | r
+            }
+        };
+        Some(set)
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> BitSet {
+        let mut set = BitSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<usize> for BitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for i in iter {
+            self.insert(i);
+        }
+    }
+}
+
+// 뒤쪽의 0 워드는 무시하고 비교한다. {1}을 remove(100) 해도 {1}과 같아야 하기 때문이다.
+impl PartialEq for BitSet {
+    fn eq(&self, other: &BitSet) -> bool {
+        let n = self.words.len().max(other.words.len());
+        (0..n).all(|i| {
+            self.words.get(i).copied().unwrap_or(0) == other.words.get(i).copied().unwrap_or(0)
+        })
+    }
+}
+
+impl Eq for BitSet {}
+
+// HashSet처럼 {1, 2, 3} 형태로 출력한다. 순서는 항상 작은 값부터다.
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+// HashSet처럼 &a | &b, &a & &b, &a - &b, &a ^ &b로 쓸 수 있게 한다.
+impl BitOr<&BitSet> for &BitSet {
+    type Output = BitSet;
+
+    fn bitor(self, rhs: &BitSet) -> BitSet {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd<&BitSet> for &BitSet {
+    type Output = BitSet;
+
+    fn bitand(self, rhs: &BitSet) -> BitSet {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub<&BitSet> for &BitSet {
+    type Output = BitSet;
+
+    fn sub(self, rhs: &BitSet) -> BitSet {
+        self.difference(rhs)
+    }
+}
+
+impl BitXor<&BitSet> for &BitSet {
+    type Output = BitSet;
+
+    fn bitxor(self, rhs: &BitSet) -> BitSet {
+        self.symmetric_difference(rhs)
+    }
+}
+
+#[cfg(test)]
+use std::collections::HashSet;
+
+// 워드 경계(63, 64, 127, 128)를 넘나드는 값 8개. 이것들로 만들 수 있는 집합 256개를 모두 확인한다.
+#[cfg(test)]
+const EDGES: [usize; 8] = [0, 1, 63, 64, 65, 127, 128, 199];
+
+// mask의 i번째 비트가 켜져 있으면 EDGES[i]를 넣는다.
+#[cfg(test)]
+fn edge_pair(mask: usize) -> (BitSet, HashSet<usize>) {
+    let values: Vec<usize> = (0..EDGES.len())
+        .filter(|i| mask >> i & 1 == 1)
+        .map(|i| EDGES[i])
+        .collect();
+    (
+        values.iter().copied().collect(),
+        values.into_iter().collect(),
+    )
+}
+
+#[cfg(test)]
+fn sorted(set: &HashSet<usize>) -> Vec<usize> {
+    let mut v: Vec<usize> = set.iter().copied().collect();
+    v.sort();
+    v
+}
+
+#[test]
+fn basic_operations() {
+    let mut s = BitSet::new();
+    assert!(s.insert(3));
+    assert!(!s.insert(3));
+    assert!(s.insert(64));
+    assert!(s.insert(0));
+    assert_eq!(s.iter().collect::<Vec<_>>(), [0, 3, 64]);
+    assert_eq!((s.min(), s.max()), (Some(0), Some(64)));
+    assert_eq!(s.len(), 3);
+    assert!(s.contains(64) && !s.contains(65) && !s.contains(10_000));
+    assert!(s.remove(64));
+    assert!(!s.remove(64));
+    assert!(!s.remove(10_000));
+    assert_eq!(s, [0, 3].into_iter().collect());
+    assert_eq!(format!("{:?}", s), "{0, 3}");
+    s.clear();
+    assert!(s.is_empty());
+    assert_eq!((s.min(), s.max()), (None, None));
+    assert_eq!(BitSet::with_capacity(100), BitSet::new());
+}
+
+#[test]
+fn main_rs_example() {
+    let a: BitSet = [1, 2, 3, 4, 5, 6].into_iter().collect();
+    let b: BitSet = [3, 4, 5, 6, 7, 8].into_iter().collect();
+    assert_eq!(
+        (&a | &b).iter().collect::<Vec<_>>(),
+        [1, 2, 3, 4, 5, 6, 7, 8]
+    );
+    assert_eq!((&a & &b).iter().collect::<Vec<_>>(), [3, 4, 5, 6]);
+    assert_eq!((&a - &b).iter().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!((&a ^ &b).iter().collect::<Vec<_>>(), [1, 2, 7, 8]);
+    assert_eq!(a.jaccard(&b), 0.5);
+    assert_eq!(BitSet::new().jaccard(&BitSet::new()), 1.0);
+}
+
+#[test]
+fn subsets_and_power_set() {
+    let s: BitSet = [2, 5, 70].into_iter().collect();
+    let power = s.power_set();
+    assert_eq!(power.len(), 8);
+    assert_eq!(power[0], BitSet::new());
+    assert_eq!(power[7], s);
+    assert!(power.iter().all(|p| p.is_subset(&s)));
+    // 모두 서로 다르다.
+    for i in 0..power.len() {
+        for j in i + 1..power.len() {
+            assert_ne!(power[i], power[j]);
+        }
+    }
+
+    let pairs: Vec<Vec<usize>> = s.subsets_of_size(2).map(|p| p.iter().collect()).collect();
+    assert_eq!(pairs, [vec![2, 5], vec![2, 70], vec![5, 70]]);
+    assert_eq!(s.subsets_of_size(0).collect::<Vec<_>>(), [BitSet::new()]);
+    assert_eq!(
+        s.subsets_of_size(3).collect::<Vec<_>>(),
+        std::slice::from_ref(&s)
+    );
+    assert_eq!(s.subsets_of_size(4).count(), 0);
+    assert_eq!(BitSet::new().power_set(), [BitSet::new()]);
+
+    // nCk의 합은 2^n
+    let ten: BitSet = (0..10).collect();
+    let total: usize = (0..=10).map(|k| ten.subsets_of_size(k).count()).sum();
+    assert_eq!(total, 1024);
+    assert_eq!(ten.subsets_of_size(3).count(), 120);
+    assert!(ten.subsets_of_size(3).all(|p| p.len() == 3));
+}
+
+#[test]
+fn prop_matches_hashset() {
+    for mask in 0..1 << EDGES.len() {
+        let (a, ha) = edge_pair(mask);
+        assert_eq!(a.iter().collect::<Vec<_>>(), sorted(&ha));
+        assert_eq!(a.len(), ha.len());
+        for i in 0..210 {
+            assert_eq!(a.contains(i), ha.contains(&i));
+        }
+    }
+    for (mask_a, mask_b) in
+        (0..1 << EDGES.len()).flat_map(|a| (0..1 << EDGES.len()).map(move |b| (a, b)))
+    {
+        let (a, ha) = edge_pair(mask_a);
+        let (b, hb) = edge_pair(mask_b);
+
+        let check = |bits: BitSet, hash: HashSet<usize>| {
+            assert_eq!(bits.iter().collect::<Vec<_>>(), sorted(&hash));
+        };
+        check(a.union(&b), ha.union(&hb).copied().collect());
+        check(a.intersection(&b), ha.intersection(&hb).copied().collect());
+        check(a.difference(&b), ha.difference(&hb).copied().collect());
+        check(
+            a.symmetric_difference(&b),
+            ha.symmetric_difference(&hb).copied().collect(),
+        );
+
+        let mut u = a.clone();
+        u.union_with(&b);
+        assert_eq!(u, a.union(&b));
+        let mut i = a.clone();
+        i.intersect_with(&b);
+        assert_eq!(i, a.intersection(&b));
+
+        assert_eq!(a.is_subset(&b), ha.is_subset(&hb));
+        assert_eq!(a.is_superset(&b), ha.is_superset(&hb));
+        assert_eq!(a.is_disjoint(&b), ha.is_disjoint(&hb));
+        assert_eq!(a.max(), ha.iter().copied().max());
+
+        let union = ha.union(&hb).count();
+        let expected = if union == 0 {
+            1.0
+        } else {
+            ha.intersection(&hb).count() as f64 / union as f64
+        };
+        assert_eq!(a.jaccard(&b), expected);
+    }
+}
+
+#[test]
+fn prop_insert_remove_matches_hashset() {
+    // 값 3개에 대한 넣기/빼기 6가지로 만들 수 있는 길이 5의 순서 전부 (6^5 = 7776가지)
+    let ops: [(bool, usize); 6] = [
+        (true, 0),
+        (true, 64),
+        (true, 299),
+        (false, 0),
+        (false, 64),
+        (false, 299),
+    ];
+    for code in 0..6usize.pow(5) {
+        let mut bits = BitSet::new();
+        let mut hash = HashSet::new();
+        let mut rest = code;
+        for _ in 0..5 {
+            let (insert, v) = ops[rest % 6];
+            rest /= 6;
+            if insert {
+                assert_eq!(bits.insert(v), hash.insert(v));
+            } else {
+                assert_eq!(bits.remove(v), hash.remove(&v));
+            }
+            assert_eq!(bits.len(), hash.len());
+            assert_eq!(bits.iter().collect::<Vec<_>>(), sorted(&hash));
+        }
+    }
+}
@@ -0,0 +1,5 @@
+// main.rs의 HashSet 집합 연산을 넓힌 모듈들
+// - multiset: 같은 값을 여러 번 담을 수 있는 집합 (개수를 센다)
+// - bitset: 작은 정수들을 비트로 담는 집합
+pub mod bitset;
+pub mod multiset;
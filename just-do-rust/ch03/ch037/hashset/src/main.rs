@@ -1,3 +1,5 @@
+use hashset::bitset::BitSet;
+use hashset::multiset::MultiSet;
 use std::collections::HashSet;
 
 fn main() {
@@ -63,4 +65,18 @@ fn main() {
     println!("{:?}", i);
     println!("{:?}", d);
     println!("{:?}", s);
+
+    // 5. 멀티셋과 비트셋
+    // 같은 값을 여러 번 담는 MultiSet은 src/multiset.rs, 작은 정수를 비트로 담는 BitSet은 src/bitset.rs에 있다.
+    let a: MultiSet<&str> = ["사과", "사과", "배"].into_iter().collect();
+    let b: MultiSet<&str> = ["사과", "귤"].into_iter().collect();
+    println!("{:?}", a.sum(&b).most_common(3));
+    println!("{}", a.intersection(&b).count(&"사과"));
+
+    let a: BitSet = [1, 2, 3, 4, 5, 6].into_iter().collect();
+    let b: BitSet = [3, 4, 5, 6, 7, 8].into_iter().collect();
+    println!("{:?} {:?} {:?} {:?}", &a | &b, &a & &b, &a - &b, &a ^ &b);
+    println!("jaccard: {}", a.jaccard(&b));
+    let small: BitSet = [1, 2, 3].into_iter().collect();
+    println!("{:?}", small.power_set());
 }
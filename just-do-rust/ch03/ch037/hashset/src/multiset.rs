@@ -0,0 +1,268 @@
+// HashSet은 같은 값을 한 번만 담는다. MultiSet(멀티셋, bag)은 같은 값을 여러 번 담을 수 있고, 몇 번 들어 있는지를 센다.
+// 내부적으로는 HashMap<값, 개수>다. 개수가 0이 된 값은 맵에서 지운다.
+//
+// 집합 연산은 개수(multiplicity)를 기준으로 한다.
+//   {a, a, b} ∪ {a, c}   = {a, a, b, c}      개수의 최댓값
+//   {a, a, b} ∩ {a, c}   = {a}               개수의 최솟값
+//   {a, a, b} + {a, c}   = {a, a, a, b, c}   개수의 합
+//   {a, a, b} - {a, c}   = {a, b}            개수의 차 (0 아래로는 내려가지 않는다)
+
+use std::collections::HashMap;
+use std::collections::hash_map;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiSet<T: Eq + Hash> {
+    counts: HashMap<T, usize>,
+    // 개수를 모두 더한 값. len()을 매번 계산하지 않으려고 따로 들고 있다.
+    len: usize,
+}
+
+impl<T: Eq + Hash> Default for MultiSet<T> {
+    fn default() -> Self {
+        MultiSet {
+            counts: HashMap::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<T: Eq + Hash> MultiSet<T> {
+    pub fn new() -> MultiSet<T> {
+        MultiSet::default()
+    }
+
+    // 값을 하나 넣고, 넣은 뒤의 개수를 돌려준다.
+    pub fn insert(&mut self, value: T) -> usize {
+        self.insert_n(value, 1)
+    }
+
+    pub fn insert_n(&mut self, value: T, n: usize) -> usize {
+        if n == 0 {
+            return self.count(&value);
+        }
+        self.len += n;
+        let c = self.counts.entry(value).or_insert(0);
+        *c += n;
+        *c
+    }
+
+    // 값을 하나 뺀다. 들어 있지 않았으면 false
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.remove_n(value, 1) == 1
+    }
+
+    // 최대 n개를 빼고, 실제로 뺀 개수를 돌려준다.
+    pub fn remove_n(&mut self, value: &T, n: usize) -> usize {
+        let Some(c) = self.counts.get_mut(value) else {
+            return 0;
+        };
+        let removed = n.min(*c);
+        *c -= removed;
+        if *c == 0 {
+            self.counts.remove(value);
+        }
+        self.len -= removed;
+        removed
+    }
+
+    // 값을 모두 빼고, 몇 개였는지 돌려준다.
+    pub fn remove_all(&mut self, value: &T) -> usize {
+        let removed = self.counts.remove(value).unwrap_or(0);
+        self.len -= removed;
+        removed
+    }
+
+    pub fn count(&self, value: &T) -> usize {
+        self.counts.get(value).copied().unwrap_or(0)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.counts.contains_key(value)
+    }
+
+    // 중복을 포함한 전체 개수
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // 서로 다른 값의 개수
+    pub fn distinct_len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // (값, 개수) 쌍. HashSet처럼 순서는 보장되지 않는다.
+    pub fn iter(&self) -> hash_map::Iter<'_, T, usize> {
+        self.counts.iter()
+    }
+
+    // 값을 개수만큼 반복해서 돌려준다.
+    pub fn iter_all(&self) -> impl Iterator<Item = &T> {
+        self.counts
+            .iter()
+            .flat_map(|(v, &n)| std::iter::repeat_n(v, n))
+    }
+
+    // 모든 값에 대해 self의 개수가 other의 개수 이하인가
+    pub fn is_subset(&self, other: &MultiSet<T>) -> bool {
+        self.len <= other.len && self.counts.iter().all(|(v, &n)| n <= other.count(v))
+    }
+
+    // 두 멀티셋에 나오는 값마다 f(내 개수, 상대 개수)로 새 개수를 정한다.
+    fn combine(&self, other: &MultiSet<T>, f: impl Fn(usize, usize) -> usize) -> MultiSet<T>
+    where
+        T: Clone,
+    {
+        let mut out = MultiSet::new();
+        for v in self.counts.keys().chain(other.counts.keys()) {
+            if out.contains(v) {
+                continue;
+            }
+            out.insert_n(v.clone(), f(self.count(v), other.count(v)));
+        }
+        out
+    }
+
+    pub fn union(&self, other: &MultiSet<T>) -> MultiSet<T>
+    where
+        T: Clone,
+    {
+        self.combine(other, usize::max)
+    }
+
+    pub fn intersection(&self, other: &MultiSet<T>) -> MultiSet<T>
+    where
+        T: Clone,
+    {
+        self.combine(other, usize::min)
+    }
+
+    pub fn sum(&self, other: &MultiSet<T>) -> MultiSet<T>
+    where
+        T: Clone,
+    {
+        self.combine(other, |a, b| a + b)
+    }
+
+    pub fn difference(&self, other: &MultiSet<T>) -> MultiSet<T>
+    where
+        T: Clone,
+    {
+        self.combine(other, usize::saturating_sub)
+    }
+
+    // 개수가 많은 순서로 n개. 개수가 같으면 값의 순서
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)>
+    where
+        T: Ord,
+    {
+        let mut all: Vec<(&T, usize)> = self.counts.iter().map(|(v, &c)| (v, c)).collect();
+        all.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        all.truncate(n);
+        all
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for MultiSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = MultiSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for MultiSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for v in iter {
+            self.insert(v);
+        }
+    }
+}
+
+// 0, 1, 2를 각각 0 ~ 2개씩 넣은 멀티셋 27개 전부
+#[cfg(test)]
+fn all_small_multisets() -> Vec<MultiSet<u8>> {
+    let mut out = Vec::new();
+    for code in 0..27 {
+        let mut m = MultiSet::new();
+        let mut rest = code;
+        for v in 0..3u8 {
+            for _ in 0..rest % 3 {
+                m.insert(v);
+            }
+            rest /= 3;
+        }
+        out.push(m);
+    }
+    out
+}
+
+#[test]
+fn counts_and_removal() {
+    let mut m: MultiSet<&str> = ["a", "b", "a", "c", "a"].into_iter().collect();
+    assert_eq!(m.count(&"a"), 3);
+    assert_eq!(m.len(), 5);
+    assert_eq!(m.distinct_len(), 3);
+
+    assert!(m.remove(&"a"));
+    assert_eq!(m.count(&"a"), 2);
+    assert!(!m.remove(&"z"));
+    assert_eq!(m.remove_n(&"a", 5), 2);
+    assert!(!m.contains(&"a"));
+    assert_eq!(m.insert_n("b", 3), 4);
+    assert_eq!(m.remove_all(&"b"), 4);
+    assert_eq!(m.len(), 1);
+    assert_eq!(m.insert_n("q", 0), 0);
+    assert!(!m.contains(&"q"));
+    assert_eq!(m.iter_all().count(), m.len());
+}
+
+#[test]
+fn multiplicity_semantics() {
+    let a: MultiSet<char> = "aab".chars().collect();
+    let b: MultiSet<char> = "ac".chars().collect();
+    let to_sorted = |m: MultiSet<char>| {
+        let mut v: Vec<char> = m.iter_all().copied().collect();
+        v.sort();
+        v.into_iter().collect::<String>()
+    };
+    assert_eq!(to_sorted(a.union(&b)), "aabc");
+    assert_eq!(to_sorted(a.intersection(&b)), "a");
+    assert_eq!(to_sorted(a.sum(&b)), "aaabc");
+    assert_eq!(to_sorted(a.difference(&b)), "ab");
+    assert_eq!(to_sorted(b.difference(&a)), "c");
+}
+
+#[test]
+fn most_common() {
+    let m: MultiSet<&str> = "사과 배 사과 귤 배 사과 감".split(' ').collect();
+    assert_eq!(m.most_common(2), vec![(&"사과", 3), (&"배", 2)]);
+    assert_eq!(m.most_common(10).len(), 4);
+}
+
+#[test]
+fn prop_operations_match_counts() {
+    let all = all_small_multisets();
+    for (a, b) in all.iter().flat_map(|a| all.iter().map(move |b| (a, b))) {
+        let (u, i, s, d) = (a.union(b), a.intersection(b), a.sum(b), a.difference(b));
+        for v in 0..4u8 {
+            let (x, y) = (a.count(&v), b.count(&v));
+            assert_eq!(u.count(&v), x.max(y));
+            assert_eq!(i.count(&v), x.min(y));
+            assert_eq!(s.count(&v), x + y);
+            assert_eq!(d.count(&v), x.saturating_sub(y));
+        }
+        assert_eq!(s.len(), a.len() + b.len());
+        // |A ∪ B| + |A ∩ B| = |A| + |B|
+        assert_eq!(u.len() + i.len(), a.len() + b.len());
+        assert!(i.is_subset(a) && i.is_subset(b));
+        assert!(a.is_subset(&u) && d.is_subset(a));
+        assert_eq!(&d.sum(&i), a);
+        // 개수가 0인 값은 남지 않는다.
+        assert!(d.iter().all(|(_, &n)| n > 0));
+    }
+}
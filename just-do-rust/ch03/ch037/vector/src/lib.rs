@@ -0,0 +1,2 @@
+// 앞뒤 양쪽에서 넣고 뺄 수 있는 원형 버퍼(RingBuffer)와 덱(Deque)을 직접 구현한 모듈
+pub mod ring;
@@ -1,3 +1,5 @@
+use vector::ring::{Deque, Overflow, RingBuffer};
+
 fn main() {
     //1. 벡터의 생성/선언 방법
     // 벡터를 선언/생성할 때 두 가지 방법이 있다.
@@ -202,4 +204,28 @@ fn main() {
     let mut v = vec![5, 3, 1, 2, 4];
     v.sort();
     assert_eq!(v, vec![1, 2, 3, 4, 5]);
+
+    // 원형 버퍼와 덱 (src/ring.rs)
+    // Vec은 앞쪽에 넣고 빼는 것이 느리다. 원형 버퍼는 양쪽 끝 모두 O(1)이다.
+    let mut recent = RingBuffer::new(3, Overflow::Overwrite);
+    for i in 1..=5 {
+        if let Ok(Some(old)) = recent.push_back(i) {
+            println!("{} 밀려남", old);
+        }
+    }
+    println!("최근 3개: {:?}", recent); // [3, 4, 5]
+
+    let mut queue = RingBuffer::new(2, Overflow::Reject);
+    queue.push_back("a").unwrap();
+    queue.push_back("b").unwrap();
+    println!("가득 차서 거절: {:?}", queue.push_back("c")); // Err("c")
+
+    let mut d: Deque<i32> = (1..=5).collect();
+    d.push_front(0);
+    d.rotate_left(2);
+    println!("{:?} {:?}", d, d.as_slices());
+    let drained: Vec<i32> = d.drain(1..3).collect();
+    println!("drain {:?} -> 남은 것 {:?}", drained, d);
+    d.make_contiguous().sort();
+    println!("정렬 {:?}, d[0] = {}", d, d[0]);
 }
@@ -0,0 +1,852 @@
+// Vec은 뒤쪽(push/pop)은 빠르지만 앞쪽에 넣거나 빼면(insert(0, x), remove(0)) 나머지 원소를 모두 옮겨야 해서 느리다.
+// 원형 버퍼는 배열의 끝과 처음이 이어져 있다고 보고, 시작 위치(head)만 옮겨서 앞쪽 연산도 O(1)로 만든다.
+//
+//   용량 8, head = 6, len = 4인 경우
+//   index:  0  1  2  3  4  5  6  7
+//   buf:   [c][d][ ][ ][ ][ ][a][b]     논리적 순서는 a, b, c, d
+//
+// - RingBuffer<T>: 용량이 고정되어 있다. 가득 찼을 때 넣으면 반대쪽 원소를 밀어내거나(Overwrite) 거절한다(Reject).
+// - Deque<T>: 가득 차면 용량을 두 배로 늘린다. 늘리는 비용을 여러 번의 push가 나눠 내므로 평균 O(1)이다.
+//
+// 둘 다 내부적으로 Ring<T>를 쓴다. 비어 있는 칸이 있어야 하므로 저장 공간은 MaybeUninit<T>(초기화되지 않았을 수도 있는 T)의 배열이다.
+// 초기화된 칸을 읽는 부분에만 unsafe가 필요하고, 그 칸이 정말 초기화되어 있다는 것은 head와 len으로 보장한다.
+// 크기가 0인 타입(ZST, 예: ())도 그대로 동작한다. 이 경우 배열은 메모리를 차지하지 않는다.
+
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
+
+pub type Iter<'a, T> = std::iter::Chain<std::slice::Iter<'a, T>, std::slice::Iter<'a, T>>;
+pub type IterMut<'a, T> = std::iter::Chain<std::slice::IterMut<'a, T>, std::slice::IterMut<'a, T>>;
+
+// ------------------------------------------------------------
+// 공통 부분
+
+struct Ring<T> {
+    // buf[head], buf[head + 1], ... (끝을 넘으면 0으로 돌아간다) 순서로 len개가 초기화되어 있다.
+    buf: Box<[MaybeUninit<T>]>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> Ring<T> {
+    fn with_capacity(cap: usize) -> Ring<T> {
+        Ring {
+            buf: (0..cap).map(|_| MaybeUninit::uninit()).collect(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    fn cap(&self) -> usize {
+        self.buf.len()
+    }
+
+    // 논리적 위치 i(0 ~ len-1)의 실제 배열 인덱스
+    fn phys(&self, i: usize) -> usize {
+        let p = self.head + i;
+        if p >= self.cap() { p - self.cap() } else { p }
+    }
+
+    // len < cap일 때만 부른다.
+    fn push_back(&mut self, value: T) {
+        debug_assert!(self.len < self.cap());
+        let p = self.phys(self.len);
+        self.buf[p].write(value);
+        self.len += 1;
+    }
+
+    fn push_front(&mut self, value: T) {
+        debug_assert!(self.len < self.cap());
+        self.head = if self.head == 0 {
+            self.cap() - 1
+        } else {
+            self.head - 1
+        };
+        self.buf[self.head].write(value);
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: len > 0이므로 buf[head]는 초기화되어 있다. 읽은 뒤 head를 옮기므로 다시 읽히지 않는다.
+        let value = unsafe { self.buf[self.head].assume_init_read() };
+        self.head = self.phys(1);
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let p = self.phys(self.len);
+        // SAFETY: 줄이기 전의 len 범위 안이므로 초기화되어 있고, len을 줄였으므로 다시 읽히지 않는다.
+        Some(unsafe { self.buf[p].assume_init_read() })
+    }
+
+    fn get(&self, i: usize) -> Option<&T> {
+        if i >= self.len {
+            return None;
+        }
+        // SAFETY: i < len이므로 초기화된 칸이다.
+        Some(unsafe { self.buf[self.phys(i)].assume_init_ref() })
+    }
+
+    fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i >= self.len {
+            return None;
+        }
+        let p = self.phys(i);
+        // SAFETY: i < len이므로 초기화된 칸이다.
+        Some(unsafe { self.buf[p].assume_init_mut() })
+    }
+
+    // 초기화된 칸들의 실제 배열 범위. 끝을 넘어가면 두 조각이 된다.
+    fn ranges(&self) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        let first_len = self.len.min(self.cap() - self.head);
+        (self.head..self.head + first_len, 0..self.len - first_len)
+    }
+
+    fn as_slices(&self) -> (&[T], &[T]) {
+        let (a, b) = self.ranges();
+        // SAFETY: 두 범위의 칸은 모두 초기화되어 있고, MaybeUninit<T>는 T와 메모리 배치가 같다.
+        unsafe { (assume_init(&self.buf[a]), assume_init(&self.buf[b])) }
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (a, b) = self.ranges();
+        // 두 번째 조각(b)은 항상 첫 번째 조각(a)보다 앞에 있으므로 a.start에서 나누면 겹치지 않는다.
+        let (front, back) = self.buf.split_at_mut(a.start);
+        let back = &mut back[..a.len()];
+        let front = &mut front[b];
+        // SAFETY: as_slices와 같다.
+        unsafe { (assume_init_mut(back), assume_init_mut(front)) }
+    }
+
+    // 원소들이 buf[0..len]에 차례로 오도록 배열 전체를 돌린다.
+    // 빈 칸(초기화되지 않은 MaybeUninit)도 같이 옮겨지지만, 값을 읽지는 않으므로 문제없다.
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self.buf.rotate_left(self.head);
+        self.head = 0;
+        self.as_mut_slices().0
+    }
+
+    // 용량을 new_cap으로 늘린다. 원소는 앞쪽으로 모인다.
+    fn grow(&mut self, new_cap: usize) {
+        debug_assert!(new_cap >= self.cap());
+        self.make_contiguous();
+        let mut v = Vec::from(std::mem::take(&mut self.buf));
+        v.resize_with(new_cap, MaybeUninit::uninit);
+        self.buf = v.into_boxed_slice();
+    }
+
+    fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+    }
+
+    // VecDeque와 같다. 앞의 n개를 뒤로 보낸다.
+    fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.len, "rotate_left: n > len");
+        if self.len == self.cap() {
+            // 가득 차 있으면 빈 칸이 없으므로 head만 옮기면 된다.
+            if self.len > 0 {
+                self.head = self.phys(n % self.len);
+            }
+            return;
+        }
+        // 적게 옮기는 쪽으로 한 칸씩 옮긴다. 빈 칸이 있으므로 push가 항상 성공한다.
+        if n <= self.len - n {
+            for _ in 0..n {
+                let v = self.pop_front().unwrap();
+                self.push_back(v);
+            }
+        } else {
+            for _ in 0..self.len - n {
+                let v = self.pop_back().unwrap();
+                self.push_front(v);
+            }
+        }
+    }
+
+    fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> std::vec::IntoIter<T> {
+        let (start, end) = bounds(range, self.len);
+        if start == end {
+            return Vec::new().into_iter();
+        }
+        // 범위 앞쪽과 뒤쪽 중 짧은 쪽을 잠깐 꺼내 두고, 범위를 꺼낸 뒤 다시 넣는다.
+        // 꺼낸 만큼 빈 칸이 생기므로 다시 넣을 때 push가 항상 성공한다. 용량은 그대로다.
+        if start <= self.len - end {
+            let front: Vec<T> = (0..start).map(|_| self.pop_front().unwrap()).collect();
+            let drained: Vec<T> = (start..end).map(|_| self.pop_front().unwrap()).collect();
+            for v in front.into_iter().rev() {
+                self.push_front(v);
+            }
+            drained.into_iter()
+        } else {
+            let back: Vec<T> = (end..self.len).map(|_| self.pop_back().unwrap()).collect();
+            let mut drained: Vec<T> = (start..end).map(|_| self.pop_back().unwrap()).collect();
+            drained.reverse();
+            for v in back.into_iter().rev() {
+                self.push_back(v);
+            }
+            drained.into_iter()
+        }
+    }
+}
+
+impl<T> Drop for Ring<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone> Clone for Ring<T> {
+    fn clone(&self) -> Ring<T> {
+        let mut r = Ring::with_capacity(self.cap());
+        let (a, b) = self.as_slices();
+        for v in a.iter().chain(b) {
+            r.push_back(v.clone());
+        }
+        r
+    }
+}
+
+// SAFETY: 호출하는 쪽에서 모든 칸이 초기화되어 있음을 보장해야 한다.
+unsafe fn assume_init<T>(s: &[MaybeUninit<T>]) -> &[T] {
+    unsafe { &*(s as *const [MaybeUninit<T>] as *const [T]) }
+}
+
+unsafe fn assume_init_mut<T>(s: &mut [MaybeUninit<T>]) -> &mut [T] {
+    unsafe { &mut *(s as *mut [MaybeUninit<T>] as *mut [T]) }
+}
+
+// 0..len 안의 [start, end)로 바꾼다. 범위를 벗어나면 Vec::drain처럼 패닉이다.
+fn bounds<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "drain: start {} > end {}", start, end);
+    assert!(end <= len, "drain: end {} > len {}", end, len);
+    (start, end)
+}
+
+// RingBuffer와 Deque가 똑같이 갖는 메서드들
+macro_rules! ring_methods {
+    ($name:ident) => {
+        impl<T> $name<T> {
+            pub fn len(&self) -> usize {
+                self.ring.len
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.ring.len == 0
+            }
+
+            pub fn capacity(&self) -> usize {
+                self.ring.cap()
+            }
+
+            pub fn pop_front(&mut self) -> Option<T> {
+                self.ring.pop_front()
+            }
+
+            pub fn pop_back(&mut self) -> Option<T> {
+                self.ring.pop_back()
+            }
+
+            pub fn front(&self) -> Option<&T> {
+                self.ring.get(0)
+            }
+
+            pub fn back(&self) -> Option<&T> {
+                self.ring.len.checked_sub(1).and_then(|i| self.ring.get(i))
+            }
+
+            pub fn get(&self, i: usize) -> Option<&T> {
+                self.ring.get(i)
+            }
+
+            pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+                self.ring.get_mut(i)
+            }
+
+            pub fn iter(&self) -> Iter<'_, T> {
+                let (a, b) = self.ring.as_slices();
+                a.iter().chain(b.iter())
+            }
+
+            pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+                let (a, b) = self.ring.as_mut_slices();
+                a.iter_mut().chain(b.iter_mut())
+            }
+
+            // 앞쪽 조각과 뒤쪽 조각. 원소가 배열의 끝을 넘어가지 않으면 두 번째 조각은 비어 있다.
+            pub fn as_slices(&self) -> (&[T], &[T]) {
+                self.ring.as_slices()
+            }
+
+            pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+                self.ring.as_mut_slices()
+            }
+
+            // 원소들을 한 조각으로 모아서 돌려준다. 이후 as_slices()의 두 번째 조각은 비어 있다.
+            pub fn make_contiguous(&mut self) -> &mut [T] {
+                self.ring.make_contiguous()
+            }
+
+            // 앞의 n개를 뒤로 보낸다. n > len이면 패닉
+            pub fn rotate_left(&mut self, n: usize) {
+                self.ring.rotate_left(n)
+            }
+
+            // 뒤의 n개를 앞으로 보낸다. n > len이면 패닉
+            pub fn rotate_right(&mut self, n: usize) {
+                assert!(n <= self.len(), "rotate_right: n > len");
+                self.ring.rotate_left(self.len() - n)
+            }
+
+            // range 범위의 원소들을 빼서 돌려준다. 범위를 벗어나면 패닉
+            pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> std::vec::IntoIter<T> {
+                self.ring.drain(range)
+            }
+
+            pub fn clear(&mut self) {
+                self.ring.clear()
+            }
+        }
+
+        impl<T> Index<usize> for $name<T> {
+            type Output = T;
+
+            fn index(&self, i: usize) -> &T {
+                let len = self.len();
+                self.get(i)
+                    .unwrap_or_else(|| panic!("index {} out of bounds (len {})", i, len))
+            }
+        }
+
+        impl<T> IndexMut<usize> for $name<T> {
+            fn index_mut(&mut self, i: usize) -> &mut T {
+                let len = self.len();
+                self.get_mut(i)
+                    .unwrap_or_else(|| panic!("index {} out of bounds (len {})", i, len))
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a $name<T> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T>;
+
+            fn into_iter(self) -> Iter<'a, T> {
+                self.iter()
+            }
+        }
+
+        impl<T: fmt::Debug> fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T: PartialEq> PartialEq for $name<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.len() == other.len() && self.iter().eq(other.iter())
+            }
+        }
+    };
+}
+
+// ------------------------------------------------------------
+// RingBuffer
+
+// 가득 찬 RingBuffer에 넣을 때의 동작
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    // 반대쪽 끝의 원소를 밀어낸다. (push_back이면 맨 앞, push_front면 맨 뒤)
+    Overwrite,
+    // 넣지 않고 값을 돌려준다.
+    Reject,
+}
+
+#[derive(Clone)]
+pub struct RingBuffer<T> {
+    ring: Ring<T>,
+    overflow: Overflow,
+}
+
+impl<T> RingBuffer<T> {
+    pub fn new(capacity: usize, overflow: Overflow) -> RingBuffer<T> {
+        RingBuffer {
+            ring: Ring::with_capacity(capacity),
+            overflow,
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.ring.len == self.ring.cap()
+    }
+
+    // Ok(None): 그냥 들어갔다.
+    // Ok(Some(old)): Overwrite라서 old를 밀어내고 들어갔다. (용량이 0이면 넣은 값이 바로 밀려난다.)
+    // Err(value): Reject라서 들어가지 못했다.
+    pub fn push_back(&mut self, value: T) -> Result<Option<T>, T> {
+        if !self.is_full() {
+            self.ring.push_back(value);
+            return Ok(None);
+        }
+        match self.overflow {
+            Overflow::Reject => Err(value),
+            Overflow::Overwrite => match self.ring.pop_front() {
+                Some(old) => {
+                    self.ring.push_back(value);
+                    Ok(Some(old))
+                }
+                None => Ok(Some(value)),
+            },
+        }
+    }
+
+    pub fn push_front(&mut self, value: T) -> Result<Option<T>, T> {
+        if !self.is_full() {
+            self.ring.push_front(value);
+            return Ok(None);
+        }
+        match self.overflow {
+            Overflow::Reject => Err(value),
+            Overflow::Overwrite => match self.ring.pop_back() {
+                Some(old) => {
+                    self.ring.push_front(value);
+                    Ok(Some(old))
+                }
+                None => Ok(Some(value)),
+            },
+        }
+    }
+}
+
+ring_methods!(RingBuffer);
+
+// ------------------------------------------------------------
+// Deque
+
+#[derive(Clone)]
+pub struct Deque<T> {
+    ring: Ring<T>,
+}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Deque::new()
+    }
+}
+
+impl<T> Deque<T> {
+    const MIN_CAPACITY: usize = 4;
+
+    // 처음에는 메모리를 잡지 않는다. 첫 push 때 용량이 생긴다.
+    pub fn new() -> Deque<T> {
+        Deque::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Deque<T> {
+        Deque {
+            ring: Ring::with_capacity(capacity),
+        }
+    }
+
+    // 가득 찼으면 용량을 두 배로 늘린다.
+    // 원소 n개를 넣는 동안 옮기는 횟수는 4 + 8 + ... + n/2 + n < 2n이므로 push 한 번당 평균 O(1)이다.
+    fn reserve_one(&mut self) {
+        if self.ring.len == self.ring.cap() {
+            let new_cap = (self.ring.cap() * 2).max(Self::MIN_CAPACITY);
+            self.ring.grow(new_cap);
+        }
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.reserve_one();
+        self.ring.push_back(value);
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.reserve_one();
+        self.ring.push_front(value);
+    }
+}
+
+ring_methods!(Deque);
+
+impl<T> FromIterator<T> for Deque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Deque<T> {
+        let mut d = Deque::new();
+        d.extend(iter);
+        d
+    }
+}
+
+impl<T> Extend<T> for Deque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for v in iter {
+            self.push_back(v);
+        }
+    }
+}
+
+// ------------------------------------------------------------
+// 테스트: 짧은 연산 순서를 모두 만들어 VecDeque와 똑같이 수행하고 결과를 비교한다.
+
+#[cfg(test)]
+use std::collections::VecDeque;
+
+#[cfg(test)]
+fn assert_same<T: PartialEq + fmt::Debug>(
+    a: &(impl Index<usize, Output = T> + ?Sized),
+    slices: (&[T], &[T]),
+    model: &VecDeque<T>,
+) {
+    let mine: Vec<&T> = slices.0.iter().chain(slices.1).collect();
+    let theirs: Vec<&T> = model.iter().collect();
+    assert_eq!(mine, theirs);
+    for (i, v) in model.iter().enumerate() {
+        assert_eq!(&a[i], v);
+    }
+}
+
+// 테스트에서 덱과 링 버퍼에 하는 연산. 인자가 길이보다 크면 길이에 맞게 줄여서 쓴다.
+#[cfg(test)]
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    PushBack,
+    PushFront,
+    PopFront,
+    PopBack,
+    RotateLeft(usize),
+    RotateRight(usize),
+    // i번째 원소와 앞의 세 원소를 바꾼다 (IndexMut, iter_mut)
+    Bump(usize),
+    Drain(usize, usize),
+    MakeContiguous,
+}
+
+// 시작 상태를 만드는 prefix 뒤에 ops로 만들 수 있는 길이 len의 순서를 모두 붙인다.
+#[cfg(test)]
+fn all_scripts(prefixes: &[&[Op]], ops: &[Op], len: usize) -> Vec<Vec<Op>> {
+    let mut scripts: Vec<Vec<Op>> = prefixes.iter().map(|p| p.to_vec()).collect();
+    for _ in 0..len {
+        scripts = scripts
+            .iter()
+            .flat_map(|s| {
+                ops.iter().map(move |&op| {
+                    let mut next = s.clone();
+                    next.push(op);
+                    next
+                })
+            })
+            .collect();
+    }
+    scripts
+}
+
+// 빈 상태, 앞뒤로 조금 넣은 상태, head가 한 바퀴 돌아간 상태, 용량이 4에서 8로 늘어난 상태
+#[cfg(test)]
+const PREFIXES: [&[Op]; 5] = [
+    &[],
+    &[Op::PushBack, Op::PushBack, Op::PushBack],
+    &[Op::PushFront, Op::PushFront, Op::PushFront],
+    &[
+        Op::PushBack,
+        Op::PushBack,
+        Op::PushBack,
+        Op::PushBack,
+        Op::PopFront,
+        Op::PopFront,
+        Op::PushBack,
+        Op::PushBack,
+    ],
+    &[
+        Op::PushFront,
+        Op::PushFront,
+        Op::PushFront,
+        Op::PushBack,
+        Op::PushBack,
+        Op::PushFront,
+    ],
+];
+
+#[cfg(test)]
+const DEQUE_OPS: [Op; 10] = [
+    Op::PushBack,
+    Op::PushFront,
+    Op::PopFront,
+    Op::PopBack,
+    Op::RotateLeft(1),
+    Op::RotateRight(2),
+    Op::Bump(1),
+    Op::Drain(1, 3),
+    Op::Drain(0, 9),
+    Op::MakeContiguous,
+];
+
+// make: 몇 번째 연산인지로 넣을 값을 만든다. ZST 테스트에서는 항상 ()를 만든다.
+#[cfg(test)]
+fn run_deque_ops<T: Clone + PartialEq + fmt::Debug>(
+    script: &[Op],
+    make: impl Fn(usize) -> T,
+    bump: impl Fn(&mut T),
+) {
+    let mut d: Deque<T> = Deque::new();
+    let mut m: VecDeque<T> = VecDeque::new();
+    for (step, &op) in script.iter().enumerate() {
+        let len = m.len();
+        match op {
+            Op::PushBack => {
+                d.push_back(make(step));
+                m.push_back(make(step));
+            }
+            Op::PushFront => {
+                d.push_front(make(step));
+                m.push_front(make(step));
+            }
+            Op::PopFront => assert_eq!(d.pop_front(), m.pop_front()),
+            Op::PopBack => assert_eq!(d.pop_back(), m.pop_back()),
+            Op::RotateLeft(n) => {
+                d.rotate_left(n.min(len));
+                m.rotate_left(n.min(len));
+            }
+            Op::RotateRight(n) => {
+                d.rotate_right(n.min(len));
+                m.rotate_right(n.min(len));
+            }
+            Op::Bump(i) if i < len => {
+                bump(&mut d[i]);
+                bump(&mut m[i]);
+                for v in d.iter_mut().zip(m.iter_mut()).take(3) {
+                    bump(v.0);
+                    bump(v.1);
+                }
+            }
+            Op::Bump(_) => {}
+            Op::Drain(a, b) => {
+                let (a, b) = (a.min(len), b.min(len));
+                let mine: Vec<T> = d.drain(a..b).collect();
+                let theirs: Vec<T> = m.drain(a..b).collect();
+                assert_eq!(mine, theirs);
+            }
+            Op::MakeContiguous => {
+                assert_eq!(d.make_contiguous(), m.make_contiguous());
+                assert!(d.as_slices().1.is_empty());
+            }
+        }
+        assert_eq!(d.len(), m.len());
+        assert_eq!(d.front(), m.front());
+        assert_eq!(d.back(), m.back());
+        assert!(d.capacity() >= d.len());
+        assert_same(&d, d.as_slices(), &m);
+    }
+}
+
+#[test]
+fn deque_matches_vecdeque() {
+    for script in all_scripts(&PREFIXES, &DEQUE_OPS, 4) {
+        run_deque_ops(&script, |i| i as i64, |v| *v += 1);
+        run_deque_ops(&script, |i| i.to_string(), |v| v.push('!'));
+    }
+}
+
+#[test]
+fn deque_with_zero_sized_type() {
+    for script in all_scripts(&PREFIXES, &DEQUE_OPS, 3) {
+        run_deque_ops(&script, |_| (), |_| {});
+    }
+    let mut d: Deque<()> = (0..1000).map(|_| ()).collect();
+    assert_eq!(d.len(), 1000);
+    assert_eq!(d.drain(10..20).count(), 10);
+    assert_eq!(d.iter().count(), 990);
+}
+
+#[test]
+fn deque_growth_is_geometric() {
+    let mut d = Deque::new();
+    assert_eq!(d.capacity(), 0);
+    let mut grows = 0;
+    let mut cap = 0;
+    for i in 0..10_000 {
+        d.push_front(i);
+        if d.capacity() != cap {
+            grows += 1;
+            cap = d.capacity();
+        }
+    }
+    // 4, 8, 16, ..., 16384: 13번만 늘어난다.
+    assert_eq!(grows, 13);
+    assert_eq!(d[0], 9_999);
+    assert_eq!(d[9_999], 0);
+}
+
+#[cfg(test)]
+const RING_OPS: [Op; 8] = [
+    Op::PushBack,
+    Op::PushFront,
+    Op::PopFront,
+    Op::PopBack,
+    Op::RotateLeft(1),
+    Op::RotateLeft(3),
+    Op::Drain(1, usize::MAX),
+    Op::MakeContiguous,
+];
+
+#[cfg(test)]
+fn run_ring_ops(script: &[Op], cap: usize, overflow: Overflow) {
+    let mut r: RingBuffer<u32> = RingBuffer::new(cap, overflow);
+    let mut m: VecDeque<u32> = VecDeque::new();
+    for (step, &op) in script.iter().enumerate() {
+        let step = step as u32;
+        match op {
+            Op::PushBack => {
+                let got = r.push_back(step);
+                let expected = if m.len() < cap {
+                    m.push_back(step);
+                    Ok(None)
+                } else if overflow == Overflow::Reject {
+                    Err(step)
+                } else if cap == 0 {
+                    Ok(Some(step))
+                } else {
+                    let old = m.pop_front();
+                    m.push_back(step);
+                    Ok(old)
+                };
+                assert_eq!(got, expected);
+            }
+            Op::PushFront => {
+                let got = r.push_front(step);
+                let expected = if m.len() < cap {
+                    m.push_front(step);
+                    Ok(None)
+                } else if overflow == Overflow::Reject {
+                    Err(step)
+                } else if cap == 0 {
+                    Ok(Some(step))
+                } else {
+                    let old = m.pop_back();
+                    m.push_front(step);
+                    Ok(old)
+                };
+                assert_eq!(got, expected);
+            }
+            Op::PopFront => assert_eq!(r.pop_front(), m.pop_front()),
+            Op::PopBack => assert_eq!(r.pop_back(), m.pop_back()),
+            Op::RotateLeft(n) => {
+                let n = n.min(m.len());
+                r.rotate_left(n);
+                m.rotate_left(n);
+            }
+            Op::Drain(a, _) => {
+                let a = a.min(m.len());
+                let mine: Vec<u32> = r.drain(a..).collect();
+                let theirs: Vec<u32> = m.drain(a..).collect();
+                assert_eq!(mine, theirs);
+            }
+            Op::MakeContiguous => {
+                r.make_contiguous();
+            }
+            Op::RotateRight(_) | Op::Bump(_) => unreachable!("not in RING_OPS"),
+        }
+        assert_eq!(r.capacity(), cap);
+        assert_eq!(r.is_full(), m.len() == cap);
+        assert_same(&r, r.as_slices(), &m);
+    }
+}
+
+#[test]
+fn ring_buffer_matches_model() {
+    for script in all_scripts(&PREFIXES, &RING_OPS, 4) {
+        for cap in [0, 1, 2, 5] {
+            run_ring_ops(&script, cap, Overflow::Overwrite);
+            run_ring_ops(&script, cap, Overflow::Reject);
+        }
+    }
+}
+
+#[test]
+fn ring_buffer_policies() {
+    let mut r = RingBuffer::new(3, Overflow::Overwrite);
+    for i in 1..=3 {
+        assert_eq!(r.push_back(i), Ok(None));
+    }
+    assert_eq!(r.push_back(4), Ok(Some(1)));
+    assert_eq!(r.push_front(0), Ok(Some(4)));
+    assert_eq!(format!("{:?}", r), "[0, 2, 3]");
+
+    let mut r = RingBuffer::new(2, Overflow::Reject);
+    r.push_back("a").unwrap();
+    r.push_back("b").unwrap();
+    assert_eq!(r.push_back("c"), Err("c"));
+    assert_eq!(r.push_front("c"), Err("c"));
+    assert_eq!(r.iter().copied().collect::<Vec<_>>(), ["a", "b"]);
+
+    // 가득 찬 상태의 회전은 head만 옮긴다.
+    let mut r = RingBuffer::new(4, Overflow::Reject);
+    for i in 0..4 {
+        r.push_back(i).unwrap();
+    }
+    r.rotate_left(1);
+    assert_eq!(r.as_slices(), (&[1, 2, 3][..], &[0][..]));
+    r.rotate_right(2);
+    assert_eq!(r.make_contiguous(), [3, 0, 1, 2]);
+}
+
+#[test]
+fn ring_buffer_with_zero_sized_type() {
+    let mut r = RingBuffer::new(3, Overflow::Overwrite);
+    for _ in 0..10 {
+        r.push_back(()).unwrap();
+    }
+    assert_eq!(r.len(), 3);
+    assert_eq!(r.pop_front(), Some(()));
+    assert_eq!(r.iter().count(), 2);
+}
+
+#[test]
+fn drops_every_element_exactly_once() {
+    use std::rc::Rc;
+
+    let token = Rc::new(());
+    {
+        let mut d = Deque::new();
+        for _ in 0..100 {
+            d.push_front(Rc::clone(&token));
+        }
+        d.rotate_left(37);
+        drop(d.drain(10..20));
+        d.pop_back();
+        let c = d.clone();
+        assert_eq!(Rc::strong_count(&token), 1 + 89 * 2);
+        drop(c);
+
+        let mut r = RingBuffer::new(8, Overflow::Overwrite);
+        for _ in 0..20 {
+            drop(r.push_back(Rc::clone(&token)));
+        }
+        assert_eq!(Rc::strong_count(&token), 1 + 89 + 8);
+    }
+    assert_eq!(Rc::strong_count(&token), 1);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn index_out_of_bounds_panics() {
+    let d: Deque<i32> = (0..3).collect();
+    let _ = d[3];
+}
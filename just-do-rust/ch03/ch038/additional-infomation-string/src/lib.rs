@@ -0,0 +1,2 @@
+// 바이트/문자 차이를 신경 쓰지 않고 문자열을 안전하게 자르고, 칸 수를 맞추고, 한글 자모를 다루는 모듈
+pub mod text;
@@ -1,3 +1,5 @@
+use additional_infomation_string::text::{self, Align};
+
 fn main() {
     // 1. 문자열 리터럴 (String Literal)에 대한 이해
     // 쌍 따옴표 "..."로 묶여 있는 문자열을 '문자열 리터럴'이라고 한다. Rust에서 문자열 리터럴은 &'static str타입
@@ -112,4 +114,26 @@ fn main() {
     // include_str! 매크로를 이용하면 쉽고 빠른 속도로 문자열을 읽어 낼 수 있다.
     let hello_html = include_str!("../hello.html");
     println!("{}", hello_html);
+
+    //6. 바이트가 아닌 문자, 칸 수 단위로 다루기 (src/text.rs)
+    // &s[0..1]은 바이트 위치라서 한글 중간을 자르면 패닉이 난다. substr은 문자 위치를 쓴다.
+    let s = "대한민국 만세 😀";
+    println!("{}", text::substr(s, 2, 2)); // 민국
+    // 한글, 이모지는 터미널에서 2칸을 차지한다. len(), chars().count()와 모두 다르다.
+    println!(
+        "len={} chars={} width={}",
+        s.len(),
+        s.chars().count(),
+        text::width(s)
+    );
+    println!("{}", text::truncate(s, 9, "…")); // 대한민국…
+    for (name, city) in [("홍길동", "서울"), ("Kim", "Busan")] {
+        println!(
+            "|{}|{}|",
+            text::pad(name, 8, Align::Left),
+            text::pad(city, 6, Align::Right)
+        );
+    }
+    let jamo = text::decompose_str("한글");
+    println!("{} -> {}", jamo, text::compose_str(&jamo));
 }
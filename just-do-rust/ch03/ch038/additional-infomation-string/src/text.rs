@@ -0,0 +1,369 @@
+// 문자열 예제들(ch03/ch038)에서 본 것처럼 len()은 바이트 수, chars().count()는 문자(유니코드 코드 포인트) 수다.
+// 또 &s[0..1] 같은 슬라이스는 바이트 위치를 쓰기 때문에 한글(3바이트) 중간을 자르면 패닉이 난다.
+// 이 모듈은 그런 실수 없이 문자열을 다루는 함수들을 모아 둔다.
+//
+// - substr: 바이트가 아닌 문자 위치로 자른다.
+// - width: 터미널에 찍힐 때의 칸 수. 한글, 한자, 전각 문자, 대부분의 이모지는 2칸, 결합 문자(é의 ´ 등)는 0칸이다.
+// - truncate, pad: 칸 수를 기준으로 말줄임표를 붙이거나 공백을 채운다. 표를 출력할 때 한글 때문에 줄이 어긋나지 않는다.
+// - decompose, compose: 한글 음절을 초성/중성/종성 자모로 나누고 다시 합친다.
+//
+// 칸 수는 유니코드 East Asian Width 표를 간단히 줄인 것이라, 모든 문자에 대해 터미널과 정확히 같지는 않다.
+
+// start번째 문자부터 len개 문자. 범위를 벗어난 부분은 무시하므로 패닉하지 않는다.
+pub fn substr(s: &str, start: usize, len: usize) -> &str {
+    let byte_at = |n: usize| s.char_indices().nth(n).map_or(s.len(), |(b, _)| b);
+    let begin = byte_at(start);
+    let end = begin
+        + s[begin..]
+            .char_indices()
+            .nth(len)
+            .map_or(s.len() - begin, |(b, _)| b);
+    &s[begin..end]
+}
+
+// 앞 글자에 붙어서 칸을 차지하지 않는 문자
+fn is_zero_width(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}'     // 결합 발음 구별 기호 (é = e + ´)
+        | '\u{1160}'..='\u{11FF}'   // 첫가끝 한글 자모의 중성, 종성
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{200B}'..='\u{200F}'   // 폭 없는 공백, ZWJ 등
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{FE00}'..='\u{FE0F}'   // 이체자 선택자
+        | '\u{FE20}'..='\u{FE2F}'
+        | '\u{1F3FB}'..='\u{1F3FF}' // 이모지 피부색
+    ) || c.is_control()
+}
+
+// East Asian Wide / Fullwidth 문자와 이모지
+fn is_wide(c: char) -> bool {
+    matches!(c,
+        '\u{1100}'..='\u{115F}'     // 첫가끝 한글 자모의 초성
+        | '\u{2E80}'..='\u{303E}'   // CJK 부수, 기호
+        | '\u{3041}'..='\u{33FF}'   // 가나, 한글 호환 자모(ㄱ, ㅏ), CJK 기호
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'   // 한자
+        | '\u{A000}'..='\u{A4CF}'
+        | '\u{A960}'..='\u{A97F}'
+        | '\u{AC00}'..='\u{D7A3}'   // 한글 음절
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{FE30}'..='\u{FE4F}'
+        | '\u{FF00}'..='\u{FF60}'   // 전각 문자
+        | '\u{FFE0}'..='\u{FFE6}'
+        | '\u{1F000}'..='\u{1F2FF}' // 마작패, 카드, 둘러싼 글자 (🀄, 🈚)
+        | '\u{1F300}'..='\u{1F64F}' // 이모지
+        | '\u{1F680}'..='\u{1F6FF}' // 교통, 지도 기호 (🚀)
+        | '\u{1F900}'..='\u{1FAFF}' // 보충 이모지 (🤔, 🫠)
+        | '\u{20000}'..='\u{3FFFD}'
+    )
+}
+
+// 문자 하나가 차지하는 칸 수 (0, 1, 2)
+pub fn char_width(c: char) -> usize {
+    if is_zero_width(c) {
+        0
+    } else if is_wide(c) {
+        2
+    } else {
+        1
+    }
+}
+
+pub fn width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+// 칸 수가 max_width를 넘으면 뒤를 잘라내고 ellipsis를 붙인다. 결과의 칸 수는 항상 max_width 이하다.
+// 문자 단위로 자르므로 한글이나 이모지가 깨지지 않고, 결합 문자는 앞 글자와 함께 남거나 함께 잘린다.
+// ellipsis조차 들어가지 않으면 말줄임표 없이 자르기만 한다.
+pub fn truncate(s: &str, max_width: usize, ellipsis: &str) -> String {
+    if width(s) <= max_width {
+        return s.to_string();
+    }
+    let (limit, tail) = if width(ellipsis) <= max_width {
+        (max_width - width(ellipsis), ellipsis)
+    } else {
+        (max_width, "")
+    };
+    let mut out = String::new();
+    let mut w = 0;
+    for c in s.chars() {
+        if w + char_width(c) > limit {
+            break;
+        }
+        w += char_width(c);
+        out.push(c);
+    }
+    // 이모지 조합(👨‍👩‍👧)을 자른 경우 끝에 남은 ZWJ는 뒤 글자가 없으므로 뺀다.
+    while out.ends_with('\u{200D}') {
+        out.pop();
+    }
+    out.push_str(tail);
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+// 칸 수가 width가 되도록 공백을 채운다. 이미 넓으면 그대로 돌려준다.
+// 가운데 정렬에서 남는 한 칸은 오른쪽에 붙인다.
+pub fn pad(s: &str, width: usize, align: Align) -> String {
+    let gap = width.saturating_sub(self::width(s));
+    let (left, right) = match align {
+        Align::Left => (0, gap),
+        Align::Right => (gap, 0),
+        Align::Center => (gap / 2, gap - gap / 2),
+    };
+    format!("{}{}{}", " ".repeat(left), s, " ".repeat(right))
+}
+
+// ------------------------------------------------------------
+// 한글 자모
+//
+// 한글 음절(가 ~ 힣, 11172자)은 유니코드에 다음 순서로 놓여 있다.
+//   음절 = 0xAC00 + (초성 번호 * 21 + 중성 번호) * 28 + 종성 번호
+// 종성 번호 0은 받침이 없다는 뜻이다. 자모는 화면에 보이는 호환 자모(ㄱ, ㅏ)로 돌려준다.
+
+const SYLLABLE_BASE: u32 = 0xAC00;
+const MEDIAL_COUNT: u32 = 21;
+const FINAL_COUNT: u32 = 28;
+
+const INITIALS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+const MEDIALS: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+
+// 종성 번호 1 ~ 27
+const FINALS: [char; 27] = [
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
+    'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jamo {
+    pub initial: char,
+    pub medial: char,
+    // 받침이 없으면 None
+    pub final_: Option<char>,
+}
+
+pub fn is_hangul_syllable(c: char) -> bool {
+    ('\u{AC00}'..='\u{D7A3}').contains(&c)
+}
+
+// 한글 음절이 아니면 None
+pub fn decompose(c: char) -> Option<Jamo> {
+    if !is_hangul_syllable(c) {
+        return None;
+    }
+    let index = c as u32 - SYLLABLE_BASE;
+    let f = index % FINAL_COUNT;
+    let m = index / FINAL_COUNT % MEDIAL_COUNT;
+    let i = index / FINAL_COUNT / MEDIAL_COUNT;
+    Some(Jamo {
+        initial: INITIALS[i as usize],
+        medial: MEDIALS[m as usize],
+        final_: (f > 0).then(|| FINALS[f as usize - 1]),
+    })
+}
+
+// 초성, 중성, 종성이 될 수 없는 자모가 있으면 None
+pub fn compose(jamo: Jamo) -> Option<char> {
+    let position = |table: &[char], c: char| table.iter().position(|&t| t == c).map(|p| p as u32);
+    let i = position(&INITIALS, jamo.initial)?;
+    let m = position(&MEDIALS, jamo.medial)?;
+    let f = match jamo.final_ {
+        None => 0,
+        Some(c) => position(&FINALS, c)? + 1,
+    };
+    char::from_u32(SYLLABLE_BASE + (i * MEDIAL_COUNT + m) * FINAL_COUNT + f)
+}
+
+// 한글 음절을 모두 자모로 풀어 쓴다. 한글이 아닌 문자는 그대로 둔다. "한글" → "ㅎㅏㄴㄱㅡㄹ"
+pub fn decompose_str(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        match decompose(c) {
+            Some(j) => {
+                out.push(j.initial);
+                out.push(j.medial);
+                out.extend(j.final_);
+            }
+            None => out.push(c),
+        }
+    }
+    out
+}
+
+// 풀어 쓴 자모를 음절로 모은다. "ㅎㅏㄴㄱㅡㄹ" → "한글"
+// 초성 + 중성 뒤의 자음은, 그 다음이 모음이면 다음 음절의 초성으로, 아니면 받침으로 본다.
+// 음절로 모을 수 없는 자모는 그대로 둔다. 따라서 decompose_str의 결과는 원래 문자열로 돌아온다.
+// (단, 원래 문자열에 "가ㅋ"처럼 음절 뒤에 낱자모가 있었다면 "갘"으로 합쳐진다.)
+pub fn compose_str(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let is_medial = |i: usize| chars.get(i).is_some_and(|c| MEDIALS.contains(c));
+    let mut out = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if INITIALS.contains(&chars[i]) && is_medial(i + 1) {
+            let final_ = chars
+                .get(i + 2)
+                .filter(|c| FINALS.contains(c) && !is_medial(i + 3))
+                .copied();
+            let jamo = Jamo {
+                initial: chars[i],
+                medial: chars[i + 1],
+                final_,
+            };
+            // 위에서 모두 표에 있는 자모인지 확인했으므로 실패하지 않는다.
+            out.push(compose(jamo).unwrap());
+            i += if final_.is_some() { 3 } else { 2 };
+        } else {
+            out.push(chars[i]);
+            i += 1;
+        }
+    }
+    out
+}
+
+#[test]
+fn substr_uses_char_positions() {
+    let s = "대한민국 Rust 😀!";
+    assert_eq!(substr(s, 0, 2), "대한");
+    assert_eq!(substr(s, 2, 3), "민국 ");
+    assert_eq!(substr(s, 10, 1), "😀");
+    assert_eq!(substr(s, 10, 100), "😀!");
+    assert_eq!(substr(s, 100, 1), "");
+    assert_eq!(substr(s, 3, 0), "");
+    // 결합 문자는 별개의 문자(코드 포인트)다.
+    assert_eq!(substr("e\u{301}x", 0, 1), "e");
+    assert_eq!(substr("e\u{301}x", 1, 2), "\u{301}x");
+}
+
+#[test]
+fn display_width() {
+    let table = [
+        ("hello", 5),
+        ("안녕", 4),
+        ("Rust 러스트", 11),
+        ("ㄱㅏ", 4),
+        ("日本語", 6),
+        ("ＡＢ", 4),
+        ("😀", 2),
+        ("🚀", 2),
+        ("🀄🈚", 4),
+        ("🤔🫠", 4),
+        ("👍🏽", 2),                       // 피부색 수식자는 0칸
+        ("❤\u{FE0F}", 1),                // 이체자 선택자는 0칸
+        ("e\u{301}", 1),                 // é = e + 결합 악센트
+        ("a\u{308}o\u{308}u\u{308}", 3), // äöü
+        ("\u{1112}\u{1161}\u{11AB}", 2), // 첫가끝 자모로 쓴 "한"
+        ("탭\t", 2),
+    ];
+    for (s, w) in table {
+        assert_eq!(width(s), w, "{:?}", s);
+    }
+}
+
+#[test]
+fn truncate_never_splits_characters() {
+    assert_eq!(truncate("짧음", 10, "…"), "짧음");
+    assert_eq!(truncate("안녕하세요", 7, "…"), "안녕하…");
+    assert_eq!(truncate("안녕하세요", 8, "..."), "안녕...");
+    // 2칸 문자가 들어갈 자리가 1칸뿐이면 그 문자는 통째로 뺀다.
+    assert_eq!(truncate("a안녕하세요", 6, "…"), "a안녕…");
+    assert_eq!(truncate("😀😀😀", 5, "…"), "😀😀…");
+    assert_eq!(truncate("cafe\u{301} au lait", 6, "…"), "cafe\u{301} …");
+    assert_eq!(truncate("e\u{301}e\u{301}e\u{301}", 2, "…"), "e\u{301}…");
+    assert_eq!(truncate("👨\u{200D}👩\u{200D}👧 가족", 3, ""), "👨");
+    // 말줄임표도 들어가지 않으면 자르기만 한다.
+    assert_eq!(truncate("안녕하세요", 2, "..."), "안");
+    assert_eq!(truncate("안녕", 1, "…"), "…");
+    assert_eq!(truncate("안녕", 0, "…"), "");
+    for max in 0..12 {
+        assert!(width(&truncate("한글 English 😀 mixed", max, "…")) <= max);
+    }
+}
+
+#[test]
+fn pad_by_display_width() {
+    assert_eq!(pad("이름", 6, Align::Left), "이름  ");
+    assert_eq!(pad("이름", 6, Align::Right), "  이름");
+    assert_eq!(pad("이름", 7, Align::Center), " 이름  ");
+    assert_eq!(pad("name", 6, Align::Center), " name ");
+    assert_eq!(pad("e\u{301}", 3, Align::Right), "  e\u{301}");
+    assert_eq!(pad("너무 긴 이름", 4, Align::Left), "너무 긴 이름");
+    // 한글과 영어가 섞인 표도 줄이 맞는다.
+    let rows = [("홍길동", "서울"), ("Kim", "Busan"), ("😀", "?")];
+    let lines: Vec<String> = rows
+        .iter()
+        .map(|(a, b)| format!("|{}|{}|", pad(a, 8, Align::Left), pad(b, 6, Align::Right)))
+        .collect();
+    assert!(lines.iter().all(|l| width(l) == width(&lines[0])));
+}
+
+#[test]
+fn hangul_jamo() {
+    assert_eq!(
+        decompose('한'),
+        Some(Jamo {
+            initial: 'ㅎ',
+            medial: 'ㅏ',
+            final_: Some('ㄴ')
+        })
+    );
+    assert_eq!(
+        decompose('가'),
+        Some(Jamo {
+            initial: 'ㄱ',
+            medial: 'ㅏ',
+            final_: None
+        })
+    );
+    assert_eq!(decompose('힣').unwrap().final_, Some('ㅎ'));
+    assert_eq!(decompose('닭').unwrap().final_, Some('ㄺ'));
+    assert_eq!(decompose('A'), None);
+    assert_eq!(decompose('ㄱ'), None);
+
+    // 11172자 모두 나눴다가 다시 합치면 제자리로 돌아온다.
+    for c in '\u{AC00}'..='\u{D7A3}' {
+        assert_eq!(compose(decompose(c).unwrap()), Some(c));
+    }
+    let bad = Jamo {
+        initial: 'ㄳ',
+        medial: 'ㅏ',
+        final_: None,
+    };
+    assert_eq!(compose(bad), None);
+}
+
+#[test]
+fn hangul_strings() {
+    assert_eq!(decompose_str("한글"), "ㅎㅏㄴㄱㅡㄹ");
+    assert_eq!(decompose_str("닭 e\u{301} 😀"), "ㄷㅏㄺ e\u{301} 😀");
+    let table = [
+        "한글",
+        "가나다",
+        "간아",
+        "앉아서 읽다",
+        "값이 얼마예요? 3,000원!",
+        "Rust 러스트 😀 café",
+    ];
+    for s in table {
+        assert_eq!(compose_str(&decompose_str(s)), s);
+    }
+    // 낱자모만 있는 경우는 그대로 둔다.
+    assert_eq!(compose_str("ㅋㅋㅋ ㅏ"), "ㅋㅋㅋ ㅏ");
+    assert_eq!(compose_str("ㄱㅏㄴㅏ"), "가나");
+    assert_eq!(compose_str("ㄱㅏㄴㅇㅏ"), "간아");
+}
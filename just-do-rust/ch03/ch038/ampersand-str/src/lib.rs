@@ -0,0 +1,2 @@
+// main.rs의 get_number를 발전시킨, 한국 전화번호를 읽고 여러 형식으로 출력하는 모듈
+pub mod phone;
@@ -1,3 +1,5 @@
+use ampersand_str::phone::{self, PhoneNumber};
+
 fn main() {
     // 우리가 일반적으로 선언하는 더블 따옴표로된 문자열은 &str 타입 변수로 받게 된다.
     // let s = "Hello, World";
@@ -48,6 +50,23 @@ fn main() {
     println!("{:?}", get_number(s));
     println!("{}", s); //010-9999-1234
 
+    // get_number는 숫자가 아닌 글자를 그냥 버린다. "010-99a9-1234"도 숫자 11개로 통과한다.
+    // phone 모듈(src/phone.rs)은 구조를 확인하고, 잘못된 입력은 이유를 담은 에러로 돌려준다.
+    let p: PhoneNumber = s.parse().unwrap();
+    println!(
+        "{} / {} / {} / {}",
+        p,
+        p.international(),
+        p.e164(),
+        p.masked()
+    );
+    for s in ["+82 2-323-4567", "010-99a9-1234", "070-1234-5678"] {
+        match phone::parse(s) {
+            Ok(p) => println!("{} -> {} {:?}", s, p, p.kind()),
+            Err(e) => println!("{} -> 에러: {}", s, e),
+        }
+    }
+
     // &str의 메서드들
     // byte
     let mut bytes = "bors".bytes();
@@ -0,0 +1,445 @@
+// main.rs의 get_number는 "010-9999-1234"에서 숫자가 아닌 글자를 모두 버린다.
+// 그래서 "010-99a9-1234"처럼 잘못된 입력도 조용히 숫자만 남기고 통과해 버린다.
+// 이 모듈은 &str을 읽어서 한국 전화번호 구조(PhoneNumber)로 바꾸고, 잘못된 입력은 이유를 담은 에러로 돌려준다.
+//
+//   010-9999-1234      휴대전화 (010, 옛 번호 011, 016 ~ 019)
+//   02-323-4567        서울 (02)
+//   031-234-5678       지역번호 (031 ~ 064)
+//   +82 10-9999-1234   국제 표기 (국가번호 82 뒤에서는 맨 앞의 0을 뺀다)
+//
+// 지역번호(area)는 맨 앞의 0(국내 식별번호)을 뺀 값으로 저장한다. "010"이면 "10", "02"면 "2"다.
+// 이렇게 두면 국내 표기는 앞에 0을, 국제 표기는 앞에 +82를 붙이기만 하면 된다.
+// PhoneNumber는 parse로만 만들 수 있다. 필드를 감춰 두었으므로 출력할 때 자릿수를 다시 확인하지 않아도 된다.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+pub const KOREA: u16 = 82;
+
+// 지역번호(0을 뺀 값)와 지역 이름
+const REGIONS: [(&str, &str); 16] = [
+    ("31", "경기"),
+    ("32", "인천"),
+    ("33", "강원"),
+    ("41", "충남"),
+    ("42", "대전"),
+    ("43", "충북"),
+    ("44", "세종"),
+    ("51", "부산"),
+    ("52", "울산"),
+    ("53", "대구"),
+    ("54", "경북"),
+    ("55", "경남"),
+    ("61", "전남"),
+    ("62", "광주"),
+    ("63", "전북"),
+    ("64", "제주"),
+];
+
+const LEGACY_MOBILE: [&str; 5] = ["11", "16", "17", "18", "19"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Mobile,
+    Seoul,
+    // 지역 이름
+    Regional(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhoneNumber {
+    country: u16,
+    // 맨 앞의 0을 뺀 지역번호/이동통신 식별번호
+    area: String,
+    // 가입자 번호 7 ~ 8자리. 구분자 없이 숫자만 담는다.
+    subscriber: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePhoneError {
+    // 빈 문자열이거나 공백, 구분자뿐인 경우
+    Empty,
+    // 숫자, +, 구분자(- . 공백 괄호)가 아닌 글자. pos는 글자(char) 위치
+    InvalidChar {
+        ch: char,
+        pos: usize,
+    },
+    // +82가 아닌 국가번호. 국가번호는 1 ~ 3자리라 어디까지가 국가번호인지 알 수 없으므로 값은 담지 않는다.
+    UnsupportedCountry,
+    // 국내 표기인데 0으로 시작하지 않는 경우
+    MissingTrunkPrefix,
+    // 알 수 없는 지역번호. 0을 붙인 국내 표기로 담는다.
+    UnknownAreaCode(String),
+    // 가입자 번호의 자릿수가 맞지 않는 경우
+    InvalidLength {
+        min: usize,
+        max: usize,
+        found: usize,
+    },
+    // 유선 전화의 국번이 0이나 1로 시작하는 경우 (국번은 2 ~ 9로 시작한다)
+    InvalidExchange(String),
+}
+
+impl fmt::Display for ParsePhoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePhoneError::Empty => write!(f, "empty phone number"),
+            ParsePhoneError::InvalidChar { ch, pos } => {
+                write!(f, "invalid character {:?} at {}", ch, pos)
+            }
+            ParsePhoneError::UnsupportedCountry => {
+                write!(f, "unsupported country code (only +{} is supported)", KOREA)
+            }
+            ParsePhoneError::MissingTrunkPrefix => write!(f, "national number must start with 0"),
+            ParsePhoneError::UnknownAreaCode(a) => write!(f, "unknown area code {}", a),
+            ParsePhoneError::InvalidLength { min, max, found } => {
+                write!(
+                    f,
+                    "subscriber number must have {} to {} digits, found {}",
+                    min, max, found
+                )
+            }
+            ParsePhoneError::InvalidExchange(s) => write!(f, "invalid exchange number {}", s),
+        }
+    }
+}
+
+impl Error for ParsePhoneError {}
+
+impl PhoneNumber {
+    pub fn country(&self) -> u16 {
+        self.country
+    }
+
+    pub fn area(&self) -> &str {
+        &self.area
+    }
+
+    pub fn subscriber(&self) -> &str {
+        &self.subscriber
+    }
+
+    pub fn kind(&self) -> Kind {
+        match self.area.as_str() {
+            "2" => Kind::Seoul,
+            "10" => Kind::Mobile,
+            a if LEGACY_MOBILE.contains(&a) => Kind::Mobile,
+            a => Kind::Regional(region_name(a).unwrap_or("?")),
+        }
+    }
+
+    // 가입자 번호를 국번(앞)과 번호(뒤 4자리)로 나눈다. parse에서 7자리 이상임을 확인했다.
+    fn groups(&self) -> (&str, &str) {
+        self.subscriber.split_at(self.subscriber.len() - 4)
+    }
+
+    // 010-9999-1234
+    pub fn national(&self) -> String {
+        let (exchange, line) = self.groups();
+        format!("0{}-{}-{}", self.area, exchange, line)
+    }
+
+    // +82 10-9999-1234
+    pub fn international(&self) -> String {
+        let (exchange, line) = self.groups();
+        format!("+{} {}-{}-{}", self.country, self.area, exchange, line)
+    }
+
+    // +821099991234 (구분자 없는 국제 표준 형식)
+    pub fn e164(&self) -> String {
+        format!("+{}{}{}", self.country, self.area, self.subscriber)
+    }
+
+    // 화면에 보여줄 때 국번을 가린다. 010-****-1234
+    pub fn masked(&self) -> String {
+        let (exchange, line) = self.groups();
+        format!("0{}-{}-{}", self.area, "*".repeat(exchange.len()), line)
+    }
+}
+
+// 국내 표기로 출력한다.
+impl fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.national())
+    }
+}
+
+impl FromStr for PhoneNumber {
+    type Err = ParsePhoneError;
+
+    fn from_str(s: &str) -> Result<PhoneNumber, ParsePhoneError> {
+        parse(s)
+    }
+}
+
+fn region_name(area: &str) -> Option<&'static str> {
+    REGIONS
+        .iter()
+        .find(|(a, _)| *a == area)
+        .map(|(_, name)| *name)
+}
+
+// 숫자만 골라내되, get_number와 달리 허용하지 않는 글자가 있으면 에러다.
+// +는 맨 앞에만 올 수 있다. 돌려주는 bool은 +로 시작했는지 여부
+fn digits(s: &str) -> Result<(bool, String), ParsePhoneError> {
+    let mut plus = false;
+    let mut out = String::new();
+    for (pos, ch) in s.chars().enumerate() {
+        match ch {
+            '0'..='9' => out.push(ch),
+            '-' | '.' | ' ' | '(' | ')' => {}
+            '+' if pos == 0 => plus = true,
+            _ => return Err(ParsePhoneError::InvalidChar { ch, pos }),
+        }
+    }
+    Ok((plus, out))
+}
+
+pub fn parse(s: &str) -> Result<PhoneNumber, ParsePhoneError> {
+    let s = s.trim();
+    let (plus, digits) = digits(s)?;
+    if digits.is_empty() {
+        return Err(ParsePhoneError::Empty);
+    }
+
+    // 국제 표기면 82를 떼어 낸다. "+82 (0)10..."처럼 0을 남겨 쓴 경우도 받아 준다.
+    let national = if plus {
+        let Some(rest) = digits.strip_prefix("82") else {
+            return Err(ParsePhoneError::UnsupportedCountry);
+        };
+        rest.strip_prefix('0').unwrap_or(rest)
+    } else {
+        digits
+            .strip_prefix('0')
+            .ok_or(ParsePhoneError::MissingTrunkPrefix)?
+    };
+    if national.is_empty() {
+        return Err(ParsePhoneError::Empty);
+    }
+
+    // 서울만 지역번호가 한 자리다.
+    let area_len = if national.starts_with('2') { 1 } else { 2 };
+    if national.len() < area_len {
+        return Err(ParsePhoneError::UnknownAreaCode(format!("0{}", national)));
+    }
+    let (area, subscriber) = national.split_at(area_len);
+
+    // (최소 자릿수, 최대 자릿수, 유선 전화인가)
+    let (min, max, landline) = match area {
+        "2" => (7, 8, true),
+        "10" => (8, 8, false),
+        a if LEGACY_MOBILE.contains(&a) => (7, 8, false),
+        a if region_name(a).is_some() => (7, 8, true),
+        a => return Err(ParsePhoneError::UnknownAreaCode(format!("0{}", a))),
+    };
+    if subscriber.len() < min || subscriber.len() > max {
+        return Err(ParsePhoneError::InvalidLength {
+            min,
+            max,
+            found: subscriber.len(),
+        });
+    }
+    if landline && subscriber.starts_with(['0', '1']) {
+        let exchange = &subscriber[..subscriber.len() - 4];
+        return Err(ParsePhoneError::InvalidExchange(exchange.to_string()));
+    }
+
+    Ok(PhoneNumber {
+        country: KOREA,
+        area: area.to_string(),
+        subscriber: subscriber.to_string(),
+    })
+}
+
+#[test]
+fn parses_valid_numbers() {
+    // 입력, 국내 표기, 국제 표기, E.164, 가린 표기
+    let table = [
+        (
+            "010-9999-1234",
+            "010-9999-1234",
+            "+82 10-9999-1234",
+            "+821099991234",
+            "010-****-1234",
+        ),
+        (
+            "01099991234",
+            "010-9999-1234",
+            "+82 10-9999-1234",
+            "+821099991234",
+            "010-****-1234",
+        ),
+        (
+            " 010 9999 1234 ",
+            "010-9999-1234",
+            "+82 10-9999-1234",
+            "+821099991234",
+            "010-****-1234",
+        ),
+        (
+            "+82 10-9999-1234",
+            "010-9999-1234",
+            "+82 10-9999-1234",
+            "+821099991234",
+            "010-****-1234",
+        ),
+        (
+            "+82 (0)10 9999 1234",
+            "010-9999-1234",
+            "+82 10-9999-1234",
+            "+821099991234",
+            "010-****-1234",
+        ),
+        (
+            "+821099991234",
+            "010-9999-1234",
+            "+82 10-9999-1234",
+            "+821099991234",
+            "010-****-1234",
+        ),
+        (
+            "02-323-4567",
+            "02-323-4567",
+            "+82 2-323-4567",
+            "+8223234567",
+            "02-***-4567",
+        ),
+        (
+            "(02) 3234.5678",
+            "02-3234-5678",
+            "+82 2-3234-5678",
+            "+82232345678",
+            "02-****-5678",
+        ),
+        (
+            "031-323-4567",
+            "031-323-4567",
+            "+82 31-323-4567",
+            "+82313234567",
+            "031-***-4567",
+        ),
+        (
+            "064-7200-1234",
+            "064-7200-1234",
+            "+82 64-7200-1234",
+            "+826472001234",
+            "064-****-1234",
+        ),
+        (
+            "011-234-5678",
+            "011-234-5678",
+            "+82 11-234-5678",
+            "+82112345678",
+            "011-***-5678",
+        ),
+    ];
+    for (input, national, international, e164, masked) in table {
+        let p: PhoneNumber = input
+            .parse()
+            .unwrap_or_else(|e| panic!("{:?}: {}", input, e));
+        assert_eq!(p.national(), national, "{:?}", input);
+        assert_eq!(p.international(), international, "{:?}", input);
+        assert_eq!(p.e164(), e164, "{:?}", input);
+        assert_eq!(p.masked(), masked, "{:?}", input);
+        assert_eq!(p.to_string(), national);
+        // 어떤 표기로 출력해도 다시 읽으면 같은 번호다.
+        for s in [national, international, e164] {
+            assert_eq!(s.parse::<PhoneNumber>(), Ok(p.clone()));
+        }
+    }
+}
+
+#[test]
+fn kinds() {
+    let kind = |s: &str| s.parse::<PhoneNumber>().unwrap().kind();
+    assert_eq!(kind("010-9999-1234"), Kind::Mobile);
+    assert_eq!(kind("019-234-5678"), Kind::Mobile);
+    assert_eq!(kind("02-323-4567"), Kind::Seoul);
+    assert_eq!(kind("051-323-4567"), Kind::Regional("부산"));
+    assert_eq!(kind("+82 64-323-4567"), Kind::Regional("제주"));
+}
+
+#[test]
+fn parts() {
+    // 머리말의 예시는 모두 올바른 번호다.
+    for s in [
+        "010-9999-1234",
+        "02-323-4567",
+        "031-234-5678",
+        "+82 10-9999-1234",
+    ] {
+        assert!(parse(s).is_ok(), "{:?}", s);
+    }
+    let p = parse("031-234-5678").unwrap();
+    assert_eq!(p.country(), KOREA);
+    assert_eq!(p.area(), "31");
+    assert_eq!(p.subscriber(), "2345678");
+}
+
+#[test]
+fn rejects_invalid_numbers() {
+    use ParsePhoneError::*;
+
+    let table = [
+        ("", Empty),
+        ("  - () ", Empty),
+        ("+82", Empty),
+        ("010-99a9-1234", InvalidChar { ch: 'a', pos: 6 }),
+        ("010-9999-1234+", InvalidChar { ch: '+', pos: 13 }),
+        ("010/9999/1234", InvalidChar { ch: '/', pos: 3 }),
+        ("010-９９９９-1234", InvalidChar { ch: '９', pos: 4 }),
+        ("+1 212-555-0100", UnsupportedCountry),
+        ("+44 20 7946 0958", UnsupportedCountry),
+        ("10-9999-1234", MissingTrunkPrefix),
+        ("0", Empty),
+        ("03", UnknownAreaCode("03".to_string())),
+        ("070-1234-5678", UnknownAreaCode("070".to_string())),
+        ("039-123-4567", UnknownAreaCode("039".to_string())),
+        (
+            "010-999-1234",
+            InvalidLength {
+                min: 8,
+                max: 8,
+                found: 7,
+            },
+        ),
+        (
+            "02-12-3456",
+            InvalidLength {
+                min: 7,
+                max: 8,
+                found: 6,
+            },
+        ),
+        (
+            "031-12345-67890",
+            InvalidLength {
+                min: 7,
+                max: 8,
+                found: 10,
+            },
+        ),
+        ("02-012-3456", InvalidExchange("012".to_string())),
+        ("031-1234-5678", InvalidExchange("1234".to_string())),
+    ];
+    for (input, expected) in table {
+        assert_eq!(parse(input), Err(expected), "{:?}", input);
+    }
+}
+
+#[test]
+fn error_messages() {
+    let e = parse("010-99a9-1234").unwrap_err();
+    assert_eq!(e.to_string(), "invalid character 'a' at 6");
+    let e = parse("010-999-1234").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "subscriber number must have 8 to 8 digits, found 7"
+    );
+    let e = parse("+1 212-555-0100").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "unsupported country code (only +82 is supported)"
+    );
+}
@@ -0,0 +1,2 @@
+// main.rs의 String 편집(insert, remove 등)을 큰 텍스트에서도 빠르게 할 수 있게 만든 로프(Rope) 모듈
+pub mod rope;
@@ -1,3 +1,5 @@
+use string::rope::Rope;
+
 fn main() {
     //String은 문자열을 다루는 대표적인 문자열 타입니다.
     // &str은 고정된 문자열을 다루는 타입인데 반해, String은 가변 크기 문자열을 다룬다.
@@ -108,4 +110,22 @@ fn main() {
     let mut s = String::from("hello");
     s.truncate(3);
     assert_eq!("hel", s);
+
+    // Rope (src/rope.rs)
+    // String의 insert, remove는 뒤쪽 바이트를 모두 옮긴다. 로프는 트리를 잘라 붙여서 O(log n)에 고친다.
+    // 위치는 바이트가 아닌 글자 단위라서 한글 중간을 자를 걱정이 없다.
+    let mut rope = Rope::from("안녕하세요\n로프입니다");
+    rope.insert(5, ", 세상");
+    rope.remove(0..2);
+    rope.push_str("\n끝");
+    println!("{}", rope);
+    println!(
+        "글자 {}개, {}줄, 1번 줄(0부터 셈)은 {}번 글자부터",
+        rope.len_chars(),
+        rope.len_lines(),
+        rope.line_to_char(1)
+    );
+    for (i, line) in rope.lines().enumerate() {
+        println!("{}: {}", i, line);
+    }
 }
@@ -0,0 +1,784 @@
+// main.rs의 String은 바이트를 한 덩어리로 이어서 저장한다. 그래서 insert, remove로 중간을 고치면 뒤쪽 바이트를 모두 옮겨야 한다. (O(n))
+// 큰 텍스트(편집기의 버퍼 등)를 자주 고칠 때는 로프(Rope)를 쓴다.
+// 로프는 텍스트를 작은 조각(leaf, 최대 MAX_LEAF 바이트)으로 나누어 이진 트리에 담는다.
+//
+//              Branch (글자 12개, 줄바꿈 1개)
+//              /                  \
+//     Leaf "Hello, "        Leaf "로프!\n"
+//
+// - 가지(Branch)는 아래에 있는 글자 수와 줄바꿈 수를 들고 있어서, n번째 글자나 n번째 줄을 트리 높이만큼만 내려가서 찾는다.
+// - 삽입과 삭제는 split(둘로 자르기)과 join(이어 붙이기) 두 연산으로 만든다.
+// - join은 AVL 트리처럼 양쪽 높이 차이가 1 이하가 되도록 회전하므로 높이는 O(log n)이고, 삽입/삭제도 O(log n)이다.
+//
+// 위치는 모두 바이트가 아닌 글자(char) 단위다. String은 바이트 위치를 쓰기 때문에 &s[0..1]처럼 한글 중간을 자르면 패닉이 나지만,
+// 로프는 글자 위치를 쓰므로 그런 일이 없다.
+
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+// 조각 하나의 최대 바이트 수
+const MAX_LEAF: usize = 64;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Info {
+    bytes: usize,
+    chars: usize,
+    newlines: usize,
+}
+
+impl Info {
+    fn of(s: &str) -> Info {
+        Info {
+            bytes: s.len(),
+            chars: s.chars().count(),
+            newlines: s.bytes().filter(|&b| b == b'\n').count(),
+        }
+    }
+
+    fn add(self, other: Info) -> Info {
+        Info {
+            bytes: self.bytes + other.bytes,
+            chars: self.chars + other.chars,
+            newlines: self.newlines + other.newlines,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Leaf(String),
+    Branch {
+        left: Box<Node>,
+        right: Box<Node>,
+        info: Info,
+        height: usize,
+    },
+}
+
+impl Node {
+    fn info(&self) -> Info {
+        match self {
+            Node::Leaf(s) => Info::of(s),
+            Node::Branch { info, .. } => *info,
+        }
+    }
+
+    fn height(&self) -> usize {
+        match self {
+            Node::Leaf(_) => 0,
+            Node::Branch { height, .. } => *height,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Node::Leaf(s) if s.is_empty())
+    }
+
+    // 회전 없이 두 노드를 가지로 묶는다.
+    fn branch(left: Node, right: Node) -> Node {
+        Node::Branch {
+            info: left.info().add(right.info()),
+            height: 1 + left.height().max(right.height()),
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    fn into_children(self) -> (Node, Node) {
+        match self {
+            Node::Branch { left, right, .. } => (*left, *right),
+            Node::Leaf(_) => unreachable!("leaf has no children"),
+        }
+    }
+
+    // 높이 차이가 2 이하인 두 노드를 묶는다. 차이가 2면 AVL 트리처럼 회전해서 1 이하로 만든다.
+    fn balance(left: Node, right: Node) -> Node {
+        if left.height() > right.height() + 1 {
+            let (ll, lr) = left.into_children();
+            if ll.height() >= lr.height() {
+                Node::branch(ll, Node::branch(lr, right))
+            } else {
+                let (lrl, lrr) = lr.into_children();
+                Node::branch(Node::branch(ll, lrl), Node::branch(lrr, right))
+            }
+        } else if right.height() > left.height() + 1 {
+            let (rl, rr) = right.into_children();
+            if rr.height() >= rl.height() {
+                Node::branch(Node::branch(left, rl), rr)
+            } else {
+                let (rll, rlr) = rl.into_children();
+                Node::branch(Node::branch(left, rll), Node::branch(rlr, rr))
+            }
+        } else {
+            Node::branch(left, right)
+        }
+    }
+
+    // left 뒤에 right를 이어 붙인다. 높이가 낮은 쪽을 높은 쪽의 가장자리로 내려 보내서 붙이므로 O(높이 차이)다.
+    fn join(left: Node, right: Node) -> Node {
+        if left.is_empty() {
+            return right;
+        }
+        if right.is_empty() {
+            return left;
+        }
+        match (left, right) {
+            // 작은 조각끼리는 하나로 합쳐서 조각이 지나치게 잘게 나뉘지 않게 한다.
+            (Node::Leaf(mut a), Node::Leaf(b)) if a.len() + b.len() <= MAX_LEAF => {
+                a.push_str(&b);
+                Node::Leaf(a)
+            }
+            (left, right) if left.height() > right.height() + 1 => {
+                let (ll, lr) = left.into_children();
+                Node::balance(ll, Node::join(lr, right))
+            }
+            (left, right) if right.height() > left.height() + 1 => {
+                let (rl, rr) = right.into_children();
+                Node::balance(Node::join(left, rl), rr)
+            }
+            (left, right) => Node::branch(left, right),
+        }
+    }
+
+    // [start, end) 글자를 out 뒤에 붙인다. 범위에 걸치지 않는 가지로는 내려가지 않는다.
+    fn push_range(&self, start: usize, end: usize, out: &mut String) {
+        match self {
+            Node::Leaf(s) => out.push_str(&s[char_to_byte(s, start)..char_to_byte(s, end)]),
+            Node::Branch { left, right, .. } => {
+                let left_chars = left.info().chars;
+                if start < left_chars {
+                    left.push_range(start, end.min(left_chars), out);
+                }
+                if end > left_chars {
+                    right.push_range(start.saturating_sub(left_chars), end - left_chars, out);
+                }
+            }
+        }
+    }
+
+    // 앞의 at글자와 나머지로 자른다.
+    fn split(self, at: usize) -> (Node, Node) {
+        match self {
+            Node::Leaf(mut s) => {
+                let tail = s.split_off(char_to_byte(&s, at));
+                (Node::Leaf(s), Node::Leaf(tail))
+            }
+            Node::Branch { left, right, .. } => {
+                let left_chars = left.info().chars;
+                if at <= left_chars {
+                    let (a, b) = left.split(at);
+                    (a, Node::join(b, *right))
+                } else {
+                    let (a, b) = right.split(at - left_chars);
+                    (Node::join(*left, a), b)
+                }
+            }
+        }
+    }
+
+    // 조각들로 높이가 최소인 트리를 만든다.
+    fn build(leaves: &mut [String]) -> Node {
+        match leaves.len() {
+            0 => Node::Leaf(String::new()),
+            1 => Node::Leaf(std::mem::take(&mut leaves[0])),
+            n => {
+                let (a, b) = leaves.split_at_mut(n / 2);
+                Node::branch(Node::build(a), Node::build(b))
+            }
+        }
+    }
+}
+
+// s를 글자 경계에서 MAX_LEAF 바이트 이하의 조각으로 나눈다.
+fn chunk(s: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut cur = String::new();
+    for c in s.chars() {
+        if cur.len() + c.len_utf8() > MAX_LEAF {
+            out.push(std::mem::take(&mut cur));
+        }
+        cur.push(c);
+    }
+    if !cur.is_empty() {
+        out.push(cur);
+    }
+    out
+}
+
+// 조각 안에서 글자 위치를 바이트 위치로 바꾼다. 글자 수와 같으면 끝(s.len())이다.
+fn char_to_byte(s: &str, at: usize) -> usize {
+    s.char_indices().nth(at).map_or(s.len(), |(b, _)| b)
+}
+
+// 0..len 안의 [start, end)로 바꾼다. 범위를 벗어나면 패닉
+fn bounds<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end, "range start {} > end {}", start, end);
+    assert!(end <= len, "range end {} > len {}", end, len);
+    (start, end)
+}
+
+#[derive(Clone)]
+pub struct Rope {
+    root: Node,
+}
+
+impl Default for Rope {
+    fn default() -> Self {
+        Rope::new()
+    }
+}
+
+impl Rope {
+    pub fn new() -> Rope {
+        Rope {
+            root: Node::Leaf(String::new()),
+        }
+    }
+
+    pub fn len_chars(&self) -> usize {
+        self.root.info().chars
+    }
+
+    pub fn len_bytes(&self) -> usize {
+        self.root.info().bytes
+    }
+
+    // 줄 수. 줄바꿈 수 + 1이므로 빈 로프도 1줄이다.
+    pub fn len_lines(&self) -> usize {
+        self.root.info().newlines + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len_chars() == 0
+    }
+
+    // at번째 글자 앞에 text를 넣는다. at > len_chars()면 패닉
+    pub fn insert(&mut self, at: usize, text: &str) {
+        assert!(
+            at <= self.len_chars(),
+            "insert at {} > len {}",
+            at,
+            self.len_chars()
+        );
+        if text.is_empty() {
+            return;
+        }
+        let root = std::mem::take(self).root;
+        let (a, b) = root.split(at);
+        let middle = Node::build(&mut chunk(text));
+        self.root = Node::join(Node::join(a, middle), b);
+    }
+
+    pub fn insert_char(&mut self, at: usize, c: char) {
+        self.insert(at, c.encode_utf8(&mut [0; 4]));
+    }
+
+    pub fn push_str(&mut self, text: &str) {
+        self.insert(self.len_chars(), text);
+    }
+
+    // 다른 로프를 뒤에 붙인다. 복사하지 않고 트리를 이어 붙인다.
+    pub fn append(&mut self, other: Rope) {
+        let root = std::mem::take(self).root;
+        self.root = Node::join(root, other.root);
+    }
+
+    // 글자 범위를 지운다. 범위를 벗어나면 패닉
+    pub fn remove<R: RangeBounds<usize>>(&mut self, range: R) {
+        let (start, end) = bounds(range, self.len_chars());
+        if start == end {
+            return;
+        }
+        let root = std::mem::take(self).root;
+        let (a, rest) = root.split(start);
+        let (_, c) = rest.split(end - start);
+        self.root = Node::join(a, c);
+    }
+
+    // at글자 뒤를 잘라서 새 로프로 돌려준다. String::split_off와 같다.
+    pub fn split_off(&mut self, at: usize) -> Rope {
+        assert!(
+            at <= self.len_chars(),
+            "split_off at {} > len {}",
+            at,
+            self.len_chars()
+        );
+        let root = std::mem::take(self).root;
+        let (a, b) = root.split(at);
+        self.root = a;
+        Rope { root: b }
+    }
+
+    // 글자 범위를 String으로 복사한다. 범위 앞쪽은 건너뛰고 내려가므로 O(log n + 복사하는 글자 수)다.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> String {
+        let (start, end) = bounds(range, self.len_chars());
+        let mut out = String::new();
+        if start < end {
+            self.root.push_range(start, end, &mut out);
+        }
+        out
+    }
+
+    // at번째 글자. 범위를 벗어나면 None
+    pub fn char(&self, at: usize) -> Option<char> {
+        let mut node = &self.root;
+        let mut at = at;
+        loop {
+            match node {
+                Node::Leaf(s) => return s.chars().nth(at),
+                Node::Branch { left, right, .. } => {
+                    let left_chars = left.info().chars;
+                    if at < left_chars {
+                        node = left;
+                    } else {
+                        at -= left_chars;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    // at번째 글자가 몇 번째 줄(0부터)에 있는지. at 앞에 있는 줄바꿈 수와 같다.
+    pub fn char_to_line(&self, at: usize) -> usize {
+        assert!(
+            at <= self.len_chars(),
+            "char {} > len {}",
+            at,
+            self.len_chars()
+        );
+        let mut node = &self.root;
+        let mut at = at;
+        let mut line = 0;
+        loop {
+            match node {
+                Node::Leaf(s) => {
+                    return line + s.chars().take(at).filter(|&c| c == '\n').count();
+                }
+                Node::Branch { left, right, .. } => {
+                    let info = left.info();
+                    if at < info.chars {
+                        node = left;
+                    } else {
+                        at -= info.chars;
+                        line += info.newlines;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    // line번째 줄(0부터)의 첫 글자 위치. line >= len_lines()면 패닉
+    pub fn line_to_char(&self, line: usize) -> usize {
+        assert!(
+            line < self.len_lines(),
+            "line {} >= {}",
+            line,
+            self.len_lines()
+        );
+        if line == 0 {
+            return 0;
+        }
+        // line번째 줄바꿈 바로 뒤를 찾는다.
+        let mut node = &self.root;
+        let mut nth = line;
+        let mut at = 0;
+        loop {
+            match node {
+                Node::Leaf(s) => {
+                    let (i, _) = s
+                        .chars()
+                        .enumerate()
+                        .filter(|&(_, c)| c == '\n')
+                        .nth(nth - 1)
+                        .unwrap();
+                    return at + i + 1;
+                }
+                Node::Branch { left, right, .. } => {
+                    let info = left.info();
+                    if nth <= info.newlines {
+                        node = left;
+                    } else {
+                        nth -= info.newlines;
+                        at += info.chars;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    // 트리에 담긴 조각들을 순서대로 돌려준다.
+    pub fn chunks(&self) -> Chunks<'_> {
+        Chunks {
+            stack: vec![&self.root],
+        }
+    }
+
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.chunks().flat_map(str::chars)
+    }
+
+    // str::lines와 같다. 줄 끝의 \n(과 그 앞의 \r)은 빼고, 마지막 줄이 비어 있으면 돌려주지 않는다.
+    // 한 줄이 여러 조각에 걸칠 수 있으므로 String으로 돌려준다.
+    pub fn lines(&self) -> impl Iterator<Item = String> + '_ {
+        let mut chars = self.chars().peekable();
+        std::iter::from_fn(move || {
+            chars.peek()?;
+            let mut line = String::new();
+            for c in chars.by_ref() {
+                if c == '\n' {
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                    break;
+                }
+                line.push(c);
+            }
+            Some(line)
+        })
+    }
+}
+
+pub struct Chunks<'a> {
+    // 아직 방문하지 않은 노드. 맨 위가 다음 차례다.
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while let Some(node) = self.stack.pop() {
+            match node {
+                Node::Leaf(s) if s.is_empty() => {}
+                Node::Leaf(s) => return Some(s),
+                Node::Branch { left, right, .. } => {
+                    self.stack.push(right);
+                    self.stack.push(left);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl From<&str> for Rope {
+    fn from(s: &str) -> Rope {
+        Rope {
+            root: Node::build(&mut chunk(s)),
+        }
+    }
+}
+
+impl From<String> for Rope {
+    fn from(s: String) -> Rope {
+        Rope::from(s.as_str())
+    }
+}
+
+impl From<&Rope> for String {
+    fn from(r: &Rope) -> String {
+        let mut s = String::with_capacity(r.len_bytes());
+        s.extend(r.chunks());
+        s
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Rope({:?})", String::from(self))
+    }
+}
+
+// 조각을 나눈 방식과 관계없이 내용이 같으면 같다.
+impl PartialEq for Rope {
+    fn eq(&self, other: &Rope) -> bool {
+        self.len_bytes() == other.len_bytes() && self.chars().eq(other.chars())
+    }
+}
+
+impl Eq for Rope {}
+
+impl PartialEq<str> for Rope {
+    fn eq(&self, other: &str) -> bool {
+        self.len_bytes() == other.len() && self.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for Rope {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+// ------------------------------------------------------------
+// 테스트: 짧은 편집 순서를 모두 만들어 String과 똑같이 수행하고 결과를 비교한다.
+
+// 1 ~ 4바이트 글자와 줄바꿈을 섞는다.
+#[cfg(test)]
+const ALPHABET: [char; 9] = ['a', 'b', ' ', 'é', '가', '힣', '😀', '\n', '\r'];
+
+// ALPHABET을 n글자가 될 때까지 되풀이한다. 조각(MAX_LEAF 바이트) 여러 개에 걸치는 텍스트를 만든다.
+#[cfg(test)]
+fn cycled(n: usize) -> String {
+    ALPHABET.iter().cycle().take(n).collect()
+}
+
+// 위치가 길이보다 크면 길이에 맞게 줄여서 쓴다.
+#[cfg(test)]
+#[derive(Debug, Clone, Copy)]
+enum Edit {
+    Insert(usize, &'static str),
+    // 조각 몇 개 크기의 텍스트를 넣는다.
+    InsertLong(usize),
+    Remove(usize, usize),
+    // 잘라 냈다가 다시 붙인다.
+    SplitAppend(usize),
+}
+
+#[cfg(test)]
+const EDITS: [Edit; 9] = [
+    Edit::Insert(0, "a"),
+    Edit::Insert(3, "é\r\n"),
+    Edit::Insert(usize::MAX, "😀\n"),
+    Edit::InsertLong(70),
+    Edit::Remove(1, 4),
+    Edit::Remove(60, 130),
+    Edit::Remove(0, usize::MAX),
+    Edit::SplitAppend(3),
+    Edit::SplitAppend(70),
+];
+
+// String의 글자 위치를 바이트 위치로 바꾼다.
+#[cfg(test)]
+fn byte_of(s: &str, at: usize) -> usize {
+    char_to_byte(s, at)
+}
+
+// 트리의 불변 조건: 가지의 info와 height가 정확하고, 양쪽 높이 차이가 1 이하이고, 조각이 MAX_LEAF를 넘지 않는다.
+#[cfg(test)]
+fn check(node: &Node) -> (Info, usize) {
+    match node {
+        Node::Leaf(s) => {
+            assert!(s.len() <= MAX_LEAF);
+            (Info::of(s), 0)
+        }
+        Node::Branch {
+            left,
+            right,
+            info,
+            height,
+        } => {
+            let (li, lh) = check(left);
+            let (ri, rh) = check(right);
+            assert!(lh.abs_diff(rh) <= 1, "unbalanced: {} vs {}", lh, rh);
+            assert_eq!(*info, li.add(ri));
+            assert_eq!(*height, 1 + lh.max(rh));
+            (*info, *height)
+        }
+    }
+}
+
+#[cfg(test)]
+fn assert_same(rope: &Rope, s: &str) {
+    check(&rope.root);
+    assert_eq!(rope.to_string(), s);
+    assert_eq!(rope.len_chars(), s.chars().count());
+    assert_eq!(rope.len_bytes(), s.len());
+    assert_eq!(rope.len_lines(), s.matches('\n').count() + 1);
+    assert_eq!(
+        rope.lines().collect::<Vec<_>>(),
+        s.lines().collect::<Vec<_>>()
+    );
+}
+
+// 여러 위치에서 slice, char, 줄 위치가 String에서 구한 것과 같은지
+#[cfg(test)]
+fn assert_same_lookups(rope: &Rope, s: &str) {
+    let chars: Vec<char> = s.chars().collect();
+    let len = chars.len();
+    let points: Vec<usize> = (0..=len).step_by(len / 7 + 1).chain([len]).collect();
+    for &a in &points {
+        assert_eq!(rope.char(a), chars.get(a).copied());
+        // 줄 위치: 글자 → 줄 → 그 줄의 시작 글자
+        let line = chars[..a].iter().filter(|&&c| c == '\n').count();
+        assert_eq!(rope.char_to_line(a), line);
+        let start = chars[..a]
+            .iter()
+            .rposition(|&c| c == '\n')
+            .map_or(0, |i| i + 1);
+        assert_eq!(rope.line_to_char(line), start);
+        for &b in points.iter().filter(|&&b| b >= a) {
+            assert_eq!(rope.slice(a..b), s[byte_of(s, a)..byte_of(s, b)]);
+        }
+    }
+}
+
+#[test]
+fn matches_string_on_all_short_edit_scripts() {
+    let starts = [
+        String::new(),
+        "a\r\nb가".to_string(),
+        cycled(150),
+        cycled(400),
+    ];
+    let mut scripts: Vec<Vec<Edit>> = vec![Vec::new()];
+    for _ in 0..3 {
+        scripts = scripts
+            .iter()
+            .flat_map(|script| {
+                EDITS.iter().map(move |&e| {
+                    let mut next = script.clone();
+                    next.push(e);
+                    next
+                })
+            })
+            .collect();
+    }
+    for start in &starts {
+        for script in &scripts {
+            let mut s = start.clone();
+            let mut rope = Rope::from(s.as_str());
+            assert_same(&rope, &s);
+            for &edit in script {
+                let len = s.chars().count();
+                match edit {
+                    Edit::Insert(at, text) => {
+                        let at = at.min(len);
+                        s.insert_str(byte_of(&s, at), text);
+                        rope.insert(at, text);
+                    }
+                    Edit::InsertLong(at) => {
+                        let (at, text) = (at.min(len), cycled(200));
+                        s.insert_str(byte_of(&s, at), &text);
+                        rope.insert(at, &text);
+                    }
+                    Edit::Remove(a, b) => {
+                        let (a, b) = (a.min(len), b.min(len));
+                        s.replace_range(byte_of(&s, a)..byte_of(&s, b), "");
+                        rope.remove(a..b);
+                    }
+                    Edit::SplitAppend(at) => {
+                        let at = at.min(len);
+                        let tail = rope.split_off(at);
+                        assert_eq!(tail, &s[byte_of(&s, at)..]);
+                        rope.append(tail);
+                    }
+                }
+                assert_same(&rope, &s);
+            }
+            assert_same_lookups(&rope, &s);
+        }
+    }
+}
+
+#[test]
+fn slice_deep_in_a_large_rope() {
+    let text = cycled(300_000);
+    let chars: Vec<char> = text.chars().collect();
+    let mut rope = Rope::from(text.as_str());
+    rope.insert(150_000, "가운데");
+    let mut s = text.clone();
+    s.insert_str(byte_of(&s, 150_000), "가운데");
+
+    assert_eq!(rope.slice(150_000..150_003), "가운데");
+    // 끝 가까운 곳을 여러 번 잘라도 앞쪽을 훑지 않는다.
+    for i in 0..1000 {
+        let a = 298_000 + i;
+        let expected: String = chars[a - 3..a + 2].iter().collect();
+        assert_eq!(rope.slice(a..a + 5), expected);
+    }
+    // 여러 조각에 걸치는 범위
+    assert_eq!(
+        rope.slice(1000..2000),
+        s[byte_of(&s, 1000)..byte_of(&s, 2000)]
+    );
+    assert_eq!(rope.slice(..), s);
+    assert_eq!(rope.slice(7..7), "");
+}
+
+#[test]
+fn large_text_stays_balanced() {
+    let mut rope = Rope::new();
+    let mut s = String::new();
+    // 항상 앞에 넣으면 균형을 잡지 않는 트리는 한쪽으로 길어진다.
+    for i in 0..5000 {
+        let text = format!("줄 {}\n", i);
+        rope.insert(0, &text);
+        s.insert_str(0, &text);
+    }
+    let (_, height) = check(&rope.root);
+    // 조각이 1000개 남짓이므로 AVL 높이 한계(약 1.44 log2 n)보다 낮아야 한다.
+    assert!(height <= 15, "height {}", height);
+    assert_eq!(rope.len_lines(), 5001);
+    assert_eq!(
+        rope.line_to_char(4999),
+        s.chars().count() - "줄 0\n".chars().count()
+    );
+    assert_eq!(rope.lines().next().as_deref(), Some("줄 4999"));
+    assert_same(&rope, &s);
+}
+
+#[test]
+fn line_indexing() {
+    let rope = Rope::from("첫째 줄\n둘째\n\n넷째");
+    assert_eq!(rope.len_lines(), 4);
+    assert_eq!(rope.line_to_char(0), 0);
+    assert_eq!(rope.line_to_char(1), 5);
+    assert_eq!(rope.line_to_char(2), 8);
+    assert_eq!(rope.line_to_char(3), 9);
+    assert_eq!(rope.char_to_line(4), 0); // 첫 줄의 \n은 첫 줄에 속한다.
+    assert_eq!(rope.char_to_line(5), 1);
+    assert_eq!(rope.char_to_line(rope.len_chars()), 3);
+    assert_eq!(
+        rope.lines().collect::<Vec<_>>(),
+        ["첫째 줄", "둘째", "", "넷째"]
+    );
+
+    let empty = Rope::new();
+    assert_eq!(empty.len_lines(), 1);
+    assert_eq!(empty.line_to_char(0), 0);
+    assert_eq!(empty.lines().count(), 0);
+}
+
+#[test]
+fn char_offsets_never_split_a_character() {
+    let mut rope = Rope::from("대한민국");
+    rope.insert(2, "🇰🇷");
+    assert_eq!(rope, "대한🇰🇷민국");
+    rope.remove(..2);
+    rope.insert_char(0, '新');
+    assert_eq!(rope.to_string(), "新🇰🇷민국");
+    assert_eq!(rope.slice(1..3), "🇰🇷");
+    assert_eq!(rope.char(4), Some('국'));
+    assert_eq!(rope.char(5), None);
+    assert_eq!(rope.chunks().collect::<String>(), "新🇰🇷민국");
+}
+
+#[test]
+#[should_panic(expected = "range end")]
+fn remove_out_of_range_panics() {
+    let mut rope = Rope::from("abc");
+    rope.remove(1..4);
+}
@@ -35,3 +35,11 @@ fn main() {
     따라서, filter_map안을 |s| s.parse::<i32>().ok()처럼 사용한 것
      */
 }
+
+#[test]
+fn filter_map_test() {
+    // 숫자 문자열 "1"과 "5"만 i32로 바뀌어 남는다.
+    let a = ["1", "two", "NaN", "four", "5"];
+    let v: Vec<_> = a.iter().filter_map(|s| s.parse::<i32>().ok()).collect();
+    assert_eq!(v, [1, 5]);
+}
@@ -48,6 +48,7 @@ fn filter_test1() {
 
     println!("{:?}", v);
     println!("{:?}", v1);
+    assert_eq!(v1, [&2, &4]);
 
     // 클로저를 |&x|로 받았다. 이것을 |x|
     // 클로저를 |x|로 받으면, x : &i32 형태가 된다.
@@ -57,6 +58,7 @@ fn filter_test1() {
     let v1: Vec<_> = v.iter().filter(|x| *x % 2 == 0).collect();
     println!("{:?}", v);
     println!("{:?}", v1);
+    assert_eq!(v1, [&2, &4]);
 
     // into_iter().filter은?
     let v = vec![1, 2, 3, 4, 5];
@@ -64,6 +66,7 @@ fn filter_test1() {
     // v.into_iter()에 의해 v에 대한 소유권이 넘어간 것이기에, 이 코드 밑 쪽에서 다시 v로의 접근은 에러가 발생
     // println!("{:?}", v); // error!
     println!("{:?}", v1);
+    assert_eq!(v1, [2, 4]);
 
     /*
     표준 라이브러리 명세상 Iterator::filter는 프레디킷을 FnMut(&Self::Item) -> bool 로 받습니다.
@@ -88,24 +91,29 @@ fn filter_test() {
     let v = vec![1, 2, 3, 4, 5];
     let v1: Vec<_> = v.iter().filter(|x| **x % 2 == 0).collect();
     println!("{:?}", v1);
+    assert_eq!(v1, [&2, &4]);
 
     // into_iter().filter
     let v = vec![1, 2, 3, 4, 5];
     let v1: Vec<_> = v.into_iter().filter(|x| x % 2 == 0).collect();
     println!("{:?}", v1);
+    assert_eq!(v1, [2, 4]);
 
     //2. 10이상이면서 홀수인 원소만 추려서 벡터로 만듦
     let v = vec![1, 2, 3, 4, 5, 10, 11, 12, 27];
     let v1: Vec<_> = v.iter().filter(|&&x| x >= 10 && x % 2 == 1).collect();
     println!("{:?}", v1);
+    assert_eq!(v1, [&11, &27]);
 
     // **x>=10 && **x%2==1"와 같이 해도 됨. 더블 * 사용
     let v = vec![1, 2, 3, 4, 5, 10, 11, 12, 27];
     let v1: Vec<_> = v.iter().filter(|x| **x >= 10 && **x % 2 == 1).collect();
     println!("{:?}", v1);
+    assert_eq!(v1, [&11, &27]);
 
     //into_iter().filter
     let v = vec![1, 2, 3, 4, 5, 10, 11, 12, 27];
     let v1: Vec<_> = v.into_iter().filter(|x| x >= &10 && x % 2 == 1).collect();
     println!("{:?}", v1);
+    assert_eq!(v1, [11, 27]);
 }
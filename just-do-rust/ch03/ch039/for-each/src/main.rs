@@ -17,3 +17,19 @@ fn main() {
         .for_each(|(_, val)| *val = 0);
     println!("{:?}", v)
 }
+
+#[test]
+fn for_each_test() {
+    //1. 각 원소에 대해 +1을 해서 업데이트
+    let mut v = vec![1, 2, 3];
+    v.iter_mut().for_each(|x| *x += 1);
+    assert_eq!(v, [2, 3, 4]);
+
+    //2. 홀수 인덱스에는 1, 짝수 인덱스에는 0을 가지는 배열
+    let mut v = vec![1; 10];
+    v.iter_mut()
+        .enumerate()
+        .filter(|(i, _)| *i % 2 == 0)
+        .for_each(|(_, val)| *val = 0);
+    assert_eq!(v, [0, 1, 0, 1, 0, 1, 0, 1, 0, 1]);
+}
@@ -0,0 +1,916 @@
+// ch039의 예제들은 map, filter, take_while 같은 표준 어댑터를 이어 붙여서(chain) 반복자 파이프라인을 만든다.
+// 표준 라이브러리에 없는 어댑터 몇 가지를 IterExt 트레이트로 만들어서, 모든 반복자에 . 으로 붙여 쓸 수 있게 한다.
+//
+//   use iter_into_iter_iter_mut::iter_ext::IterExt;
+//   let v: Vec<_> = (1..=7).chunks_exact(3).collect();   // [[1, 2, 3], [4, 5, 6]]
+//
+// 모든 어댑터는 표준 어댑터와 같은 규칙을 지킨다.
+// - 게으르다(lazy): collect, next 등으로 값을 꺼낼 때까지 아무 일도 하지 않는다. (sorted_by_key도 첫 next에서 정렬한다.)
+// - 한 번 None을 돌려주면 그 뒤로도 계속 None이다(fused). 안쪽 반복자가 그렇지 않더라도 그렇다.
+// - size_hint의 (최소, 최대)가 실제로 남은 개수를 항상 포함한다.
+//
+// 슬라이스의 chunks_exact, windows와 달리 Vec이 아닌 아무 반복자(범위, 파일의 줄 등)에나 쓸 수 있다.
+// 대신 슬라이스를 빌려줄 수 없으므로 묶음을 Vec으로 돌려준다.
+
+use std::cell::RefCell;
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+use std::iter::{Fuse, FusedIterator};
+use std::rc::Rc;
+
+pub trait IterExt: Iterator + Sized {
+    // n개씩 묶는다. 끝에 n개가 안 되게 남은 것은 버리고, into_remainder()로 꺼낼 수 있다. n == 0이면 패닉
+    fn chunks_exact(self, n: usize) -> ChunksExact<Self> {
+        assert!(n > 0, "chunk size must be non-zero");
+        ChunksExact {
+            iter: self.fuse(),
+            n,
+            remainder: Vec::new(),
+        }
+    }
+
+    // 한 칸씩 밀면서 n개씩 본다. [1, 2, 3, 4] → [1, 2, 3], [2, 3, 4]. n == 0이면 패닉
+    fn windows(self, n: usize) -> Windows<Self>
+    where
+        Self::Item: Clone,
+    {
+        assert!(n > 0, "window size must be non-zero");
+        Windows {
+            iter: self.fuse(),
+            n,
+            buf: VecDeque::with_capacity(n),
+            started: false,
+        }
+    }
+
+    // 두 반복자에서 번갈아 하나씩 꺼낸다. 한쪽이 끝나면 나머지 쪽을 이어서 꺼낸다.
+    fn interleave<J>(self, other: J) -> Interleave<Self, J::IntoIter>
+    where
+        J: IntoIterator<Item = Self::Item>,
+    {
+        Interleave {
+            a: self.fuse(),
+            b: other.into_iter().fuse(),
+            a_next: true,
+        }
+    }
+
+    // 바로 앞 원소와 key가 같은 원소를 건너뛴다. (Vec::dedup_by_key와 같다.)
+    fn dedup_by_key<K, F>(self, key: F) -> DedupByKey<Self, K, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        DedupByKey {
+            iter: self.fuse(),
+            key,
+            last: None,
+        }
+    }
+
+    // key가 같은 원소가 연속된 구간을 (key, 원소들)로 묶는다.
+    // SQL의 GROUP BY와 달리 떨어져 있는 같은 key는 다른 묶음이다. 전체를 묶으려면 먼저 sorted_by_key를 쓴다.
+    fn group_by<K, F>(self, key: F) -> GroupBy<Self, K, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        GroupBy {
+            iter: self.fuse(),
+            key,
+            pending: None,
+        }
+    }
+
+    // key 순서로 정렬해서 돌려준다. 첫 next에서 모두 읽어서 정렬한다. (안정 정렬)
+    fn sorted_by_key<K, F>(self, key: F) -> SortedByKey<Self, F>
+    where
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        SortedByKey {
+            state: SortState::Pending(self, key),
+        }
+    }
+
+    // 처음 나온 원소만 돌려주고, 이미 나온 값은 건너뛴다.
+    fn unique(self) -> Unique<Self>
+    where
+        Self::Item: Eq + Hash + Clone,
+    {
+        Unique {
+            iter: self.fuse(),
+            seen: HashSet::new(),
+        }
+    }
+
+    // 같은 원소들을 돌려주는 반복자 두 개로 나눈다. 한쪽이 앞서 나가면 뒤처진 쪽을 위해 원소를 복사해 둔다.
+    fn tee(self) -> (Tee<Self>, Tee<Self>)
+    where
+        Self::Item: Clone,
+    {
+        let shared = Rc::new(RefCell::new(TeeShared {
+            iter: self.fuse(),
+            buf: VecDeque::new(),
+            owner: false,
+        }));
+        (
+            Tee {
+                shared: Rc::clone(&shared),
+                id: false,
+            },
+            Tee { shared, id: true },
+        )
+    }
+
+    // scan처럼 상태를 들고 다니면서 값을 만든다. f가 None을 돌려주면 그 자리에서 완전히 끝난다.
+    fn scan_while<St, B, F>(self, init: St, f: F) -> ScanWhile<Self, St, F>
+    where
+        F: FnMut(&mut St, Self::Item) -> Option<B>,
+    {
+        ScanWhile {
+            iter: self.fuse(),
+            state: init,
+            f,
+            done: false,
+        }
+    }
+
+    // f가 반복자를 받아서 원하는 만큼 꺼내 값 하나를 만든다. f가 None을 돌려주면 끝난다.
+    // 묶음의 크기가 원소에 따라 달라질 때 쓴다. (예: 길이가 앞에 적힌 레코드 읽기)
+    fn batching<B, F>(self, f: F) -> Batching<Self, F>
+    where
+        F: FnMut(&mut Fuse<Self>) -> Option<B>,
+    {
+        Batching {
+            iter: self.fuse(),
+            f,
+            done: false,
+        }
+    }
+
+    // 모든 (a, b) 쌍. 이중 for 루프와 같은 순서다. other는 a마다 처음부터 다시 돌아야 하므로 Clone이어야 한다.
+    fn cartesian_product<J>(self, other: J) -> CartesianProduct<Self, J::IntoIter>
+    where
+        Self::Item: Clone,
+        J: IntoIterator,
+        J::IntoIter: Clone,
+    {
+        let b = other.into_iter();
+        CartesianProduct {
+            a: self.fuse(),
+            current: None,
+            b: b.clone(),
+            b_orig: b,
+            done: false,
+        }
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
+
+// size_hint 계산 도우미
+fn add_hint(a: (usize, Option<usize>), b: (usize, Option<usize>)) -> (usize, Option<usize>) {
+    let hi = match (a.1, b.1) {
+        (Some(x), Some(y)) => x.checked_add(y),
+        _ => None,
+    };
+    (a.0.saturating_add(b.0), hi)
+}
+
+// ------------------------------------------------------------
+
+pub struct ChunksExact<I: Iterator> {
+    iter: Fuse<I>,
+    n: usize,
+    remainder: Vec<I::Item>,
+}
+
+impl<I: Iterator> ChunksExact<I> {
+    // 끝까지 돈 뒤에 남은(n개가 안 되는) 원소들. 끝까지 돌지 않았으면 비어 있다.
+    pub fn into_remainder(self) -> Vec<I::Item> {
+        self.remainder
+    }
+}
+
+impl<I: Iterator> Iterator for ChunksExact<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(self.n).collect();
+        if chunk.len() == self.n {
+            Some(chunk)
+        } else {
+            if !chunk.is_empty() {
+                self.remainder = chunk;
+            }
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo / self.n, hi.map(|h| h / self.n))
+    }
+}
+
+impl<I: Iterator> FusedIterator for ChunksExact<I> {}
+
+// ------------------------------------------------------------
+
+pub struct Windows<I: Iterator> {
+    iter: Fuse<I>,
+    n: usize,
+    buf: VecDeque<I::Item>,
+    // 첫 창을 돌려줬는가. 그 뒤로는 원소 하나마다 창 하나다.
+    started: bool,
+}
+
+impl<I: Iterator> Iterator for Windows<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.started {
+            let x = self.iter.next()?;
+            self.buf.pop_front();
+            self.buf.push_back(x);
+        } else {
+            while self.buf.len() < self.n {
+                self.buf.push_back(self.iter.next()?);
+            }
+            self.started = true;
+        }
+        Some(self.buf.iter().cloned().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        if self.started {
+            return (lo, hi);
+        }
+        // 아직 창을 채우는 중: 원소 m개가 더 있으면 창은 (버퍼 + m) - n + 1개
+        let windows = |m: usize| (self.buf.len() + m + 1).saturating_sub(self.n);
+        (windows(lo), hi.map(windows))
+    }
+}
+
+impl<I: Iterator> FusedIterator for Windows<I> where I::Item: Clone {}
+
+// ------------------------------------------------------------
+
+pub struct Interleave<I, J> {
+    a: Fuse<I>,
+    b: Fuse<J>,
+    a_next: bool,
+}
+
+impl<I, J> Iterator for Interleave<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.a_next = !self.a_next;
+        if !self.a_next {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        add_hint(self.a.size_hint(), self.b.size_hint())
+    }
+}
+
+impl<I, J> FusedIterator for Interleave<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+}
+
+// ------------------------------------------------------------
+
+pub struct DedupByKey<I, K, F> {
+    iter: Fuse<I>,
+    key: F,
+    // 마지막으로 돌려준 원소의 key
+    last: Option<K>,
+}
+
+impl<I, K, F> Iterator for DedupByKey<I, K, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        for x in self.iter.by_ref() {
+            let k = (self.key)(&x);
+            if self.last.as_ref() != Some(&k) {
+                self.last = Some(k);
+                return Some(x);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        // 처음이 아니면 남은 원소가 모두 앞과 같을 수도 있다.
+        let lo = if self.last.is_none() { lo.min(1) } else { 0 };
+        (lo, hi)
+    }
+}
+
+impl<I, K, F> FusedIterator for DedupByKey<I, K, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+}
+
+// ------------------------------------------------------------
+
+pub struct GroupBy<I: Iterator, K, F> {
+    iter: Fuse<I>,
+    key: F,
+    // 앞 묶음을 끝내느라 미리 꺼낸 다음 묶음의 첫 원소
+    pending: Option<(K, I::Item)>,
+}
+
+impl<I, K, F> Iterator for GroupBy<I, K, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<(K, Vec<I::Item>)> {
+        let (k, first) = match self.pending.take() {
+            Some(p) => p,
+            None => {
+                let x = self.iter.next()?;
+                ((self.key)(&x), x)
+            }
+        };
+        let mut group = vec![first];
+        for x in self.iter.by_ref() {
+            let kx = (self.key)(&x);
+            if kx != k {
+                self.pending = Some((kx, x));
+                break;
+            }
+            group.push(x);
+        }
+        Some((k, group))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let extra = self.pending.is_some() as usize;
+        let (lo, hi) = add_hint(self.iter.size_hint(), (extra, Some(extra)));
+        (lo.min(1), hi)
+    }
+}
+
+impl<I, K, F> FusedIterator for GroupBy<I, K, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+}
+
+// ------------------------------------------------------------
+
+enum SortState<I: Iterator, F> {
+    Pending(I, F),
+    Sorted(std::vec::IntoIter<I::Item>),
+    // Pending에서 Sorted로 넘어가는 잠깐 동안만 쓰인다.
+    Empty,
+}
+
+pub struct SortedByKey<I: Iterator, F> {
+    state: SortState<I, F>,
+}
+
+impl<I, K, F> Iterator for SortedByKey<I, F>
+where
+    I: Iterator,
+    K: Ord,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if let SortState::Pending(..) = self.state {
+            let SortState::Pending(iter, key) =
+                std::mem::replace(&mut self.state, SortState::Empty)
+            else {
+                unreachable!()
+            };
+            let mut all: Vec<I::Item> = iter.collect();
+            all.sort_by_key(key);
+            self.state = SortState::Sorted(all.into_iter());
+        }
+        match &mut self.state {
+            SortState::Sorted(it) => it.next(),
+            _ => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.state {
+            SortState::Pending(iter, _) => iter.size_hint(),
+            SortState::Sorted(it) => it.size_hint(),
+            SortState::Empty => (0, Some(0)),
+        }
+    }
+}
+
+impl<I, K, F> FusedIterator for SortedByKey<I, F>
+where
+    I: Iterator,
+    K: Ord,
+    F: FnMut(&I::Item) -> K,
+{
+}
+
+// ------------------------------------------------------------
+
+pub struct Unique<I: Iterator> {
+    iter: Fuse<I>,
+    seen: HashSet<I::Item>,
+}
+
+impl<I> Iterator for Unique<I>
+where
+    I: Iterator,
+    I::Item: Eq + Hash + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.by_ref().find(|x| self.seen.insert(x.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let lo = if self.seen.is_empty() { lo.min(1) } else { 0 };
+        (lo, hi)
+    }
+}
+
+impl<I> FusedIterator for Unique<I>
+where
+    I: Iterator,
+    I::Item: Eq + Hash + Clone,
+{
+}
+
+// ------------------------------------------------------------
+
+struct TeeShared<I: Iterator> {
+    iter: Fuse<I>,
+    // owner 쪽이 아직 꺼내지 않은 원소들. 다른 쪽이 앞서 꺼내면서 복사해 둔 것이다.
+    buf: VecDeque<I::Item>,
+    owner: bool,
+}
+
+pub struct Tee<I: Iterator> {
+    shared: Rc<RefCell<TeeShared<I>>>,
+    id: bool,
+}
+
+impl<I> Iterator for Tee<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let mut shared = self.shared.borrow_mut();
+        if shared.owner == self.id
+            && let Some(x) = shared.buf.pop_front()
+        {
+            return Some(x);
+        }
+        // 내가 앞서 있다. 새로 꺼내고, 뒤처진 쪽을 위해 복사해 둔다.
+        let x = shared.iter.next()?;
+        shared.buf.push_back(x.clone());
+        shared.owner = !self.id;
+        Some(x)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let shared = self.shared.borrow();
+        let buffered = if shared.owner == self.id {
+            shared.buf.len()
+        } else {
+            0
+        };
+        add_hint(shared.iter.size_hint(), (buffered, Some(buffered)))
+    }
+}
+
+impl<I> FusedIterator for Tee<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
+
+// ------------------------------------------------------------
+
+pub struct ScanWhile<I, St, F> {
+    iter: Fuse<I>,
+    state: St,
+    f: F,
+    done: bool,
+}
+
+impl<I, St, B, F> Iterator for ScanWhile<I, St, F>
+where
+    I: Iterator,
+    F: FnMut(&mut St, I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        if self.done {
+            return None;
+        }
+        let out = self.iter.next().and_then(|x| (self.f)(&mut self.state, x));
+        self.done = out.is_none();
+        out
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I, St, B, F> FusedIterator for ScanWhile<I, St, F>
+where
+    I: Iterator,
+    F: FnMut(&mut St, I::Item) -> Option<B>,
+{
+}
+
+// ------------------------------------------------------------
+
+pub struct Batching<I, F> {
+    iter: Fuse<I>,
+    f: F,
+    done: bool,
+}
+
+impl<I, B, F> Iterator for Batching<I, F>
+where
+    I: Iterator,
+    F: FnMut(&mut Fuse<I>) -> Option<B>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        if self.done {
+            return None;
+        }
+        let out = (self.f)(&mut self.iter);
+        self.done = out.is_none();
+        out
+    }
+
+    // f가 원소를 하나도 꺼내지 않고 값을 만들 수도 있으므로 최대 개수는 알 수 없다.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done { (0, Some(0)) } else { (0, None) }
+    }
+}
+
+impl<I, B, F> FusedIterator for Batching<I, F>
+where
+    I: Iterator,
+    F: FnMut(&mut Fuse<I>) -> Option<B>,
+{
+}
+
+// ------------------------------------------------------------
+
+pub struct CartesianProduct<I: Iterator, J> {
+    a: Fuse<I>,
+    current: Option<I::Item>,
+    // current와 짝지을 남은 b
+    b: J,
+    b_orig: J,
+    done: bool,
+}
+
+impl<I, J> Iterator for CartesianProduct<I, J>
+where
+    I: Iterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<(I::Item, J::Item)> {
+        if self.done {
+            return None;
+        }
+        if let Some(a) = &self.current
+            && let Some(b) = self.b.next()
+        {
+            return Some((a.clone(), b));
+        }
+        // 다음 a로 넘어가서 b를 처음부터 다시 돈다.
+        let next_a = self.a.next();
+        self.b = self.b_orig.clone();
+        match (next_a, self.b.next()) {
+            (Some(a), Some(b)) => {
+                self.current = Some(a.clone());
+                Some((a, b))
+            }
+            // a가 끝났거나, b가 비어 있다. (b가 비어 있을 때 a를 끝까지 돌면 a가 무한할 경우 멈추지 않는다.)
+            _ => {
+                self.done = true;
+                self.current = None;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let rest = if self.current.is_some() {
+            self.b.size_hint()
+        } else {
+            (0, Some(0))
+        };
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b_orig.size_hint();
+        let full = (
+            a_lo.saturating_mul(b_lo),
+            a_hi.zip(b_hi).and_then(|(x, y)| x.checked_mul(y)),
+        );
+        add_hint(rest, full)
+    }
+}
+
+impl<I, J> FusedIterator for CartesianProduct<I, J>
+where
+    I: Iterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+}
+
+// ------------------------------------------------------------
+// 테스트
+
+// 매 단계마다 size_hint가 실제로 남은 개수를 포함하는지 확인하면서 끝까지 돌린다. 끝난 뒤에도 None인지(fused) 본다.
+// 전체 개수를 미리 세기 위해 같은 반복자를 두 번 만든다.
+#[cfg(test)]
+fn check<I: Iterator>(make: impl Fn() -> I) -> Vec<I::Item> {
+    let total = make().count();
+    let mut it = make();
+    let mut out = Vec::new();
+    loop {
+        let remaining = total - out.len();
+        let (lo, hi) = it.size_hint();
+        assert!(lo <= remaining, "lower bound {} > {}", lo, remaining);
+        assert!(
+            hi.is_none_or(|h| h >= remaining),
+            "upper bound {:?} < {}",
+            hi,
+            remaining
+        );
+        match it.next() {
+            Some(x) => out.push(x),
+            None => break,
+        }
+    }
+    assert_eq!(out.len(), total);
+    for _ in 0..3 {
+        assert!(it.next().is_none());
+    }
+    out
+}
+
+// 한 번 None을 돌려준 뒤에 다시 값을 돌려주는 반복자. 어댑터가 fused인지 확인하는 데 쓴다.
+#[cfg(test)]
+#[derive(Clone)]
+struct Flaky {
+    n: u32,
+}
+
+#[cfg(test)]
+impl Iterator for Flaky {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.n += 1;
+        if self.n.is_multiple_of(4) {
+            None
+        } else {
+            Some(self.n)
+        }
+    }
+}
+
+#[test]
+fn chunks_and_windows() {
+    assert_eq!((1..=7).chunks_exact(3).size_hint(), (2, Some(2)));
+    assert_eq!(
+        check(|| (1..=7).chunks_exact(3)),
+        [vec![1, 2, 3], vec![4, 5, 6]]
+    );
+    let mut it = (1..=7).chunks_exact(3);
+    it.by_ref().for_each(drop);
+    assert_eq!(it.into_remainder(), [7]);
+    assert_eq!(check(|| (1..=6).chunks_exact(2)).len(), 3);
+    assert!(check(|| (1..1).chunks_exact(2)).is_empty());
+
+    let windows = check(|| "abcde".chars().windows(3));
+    let strings: Vec<String> = windows.into_iter().map(String::from_iter).collect();
+    assert_eq!(strings, ["abc", "bcd", "cde"]);
+    assert_eq!((1..=4).windows(4).count(), 1);
+    assert!(check(|| (1..=3).windows(4)).is_empty());
+    assert_eq!(check(|| (1..=4).windows(1)), [[1], [2], [3], [4]]);
+
+    assert_eq!(check(|| Flaky { n: 0 }.chunks_exact(2)), [vec![1, 2]]);
+    assert_eq!(
+        check(|| Flaky { n: 0 }.windows(2)),
+        [vec![1, 2], vec![2, 3]]
+    );
+}
+
+#[test]
+fn interleave_dedup_unique() {
+    assert_eq!(
+        check(|| (1..=3).interleave(10..=15)),
+        [1, 10, 2, 11, 3, 12, 13, 14, 15]
+    );
+    assert_eq!(
+        check(|| (1..=5).interleave(std::iter::empty())),
+        [1, 2, 3, 4, 5]
+    );
+    assert_eq!(
+        check(|| Flaky { n: 0 }.interleave(Flaky { n: 4 })),
+        [1, 5, 2, 6, 3, 7]
+    );
+
+    let words = [
+        "apple",
+        "avocado",
+        "banana",
+        "blueberry",
+        "apricot",
+        "cherry",
+    ];
+    let firsts = check(|| words.iter().dedup_by_key(|w| w.as_bytes()[0]));
+    assert_eq!(firsts, [&"apple", &"banana", &"apricot", &"cherry"]);
+    assert_eq!(check(|| [1, 1, 1].into_iter().dedup_by_key(|&x| x)), [1]);
+
+    assert_eq!(
+        check(|| [3, 1, 3, 2, 1, 4].into_iter().unique()),
+        [3, 1, 2, 4]
+    );
+    assert_eq!(check(|| "hello world".chars().unique()).len(), 8);
+    assert_eq!(check(|| Flaky { n: 0 }.unique()), [1, 2, 3]);
+}
+
+#[test]
+fn grouping_and_sorting() {
+    let groups = check(|| "aaabccdaa".chars().group_by(|&c| c));
+    let runs: Vec<(char, usize)> = groups.into_iter().map(|(k, g)| (k, g.len())).collect();
+    assert_eq!(runs, [('a', 3), ('b', 1), ('c', 2), ('d', 1), ('a', 2)]);
+
+    // 정렬한 뒤에 묶으면 SQL의 GROUP BY와 같다.
+    let words = ["kiwi", "fig", "plum", "pear", "apple", "date"];
+    let by_len: Vec<(usize, Vec<&str>)> = check(|| {
+        words
+            .into_iter()
+            .sorted_by_key(|w| w.len())
+            .group_by(|w| w.len())
+    });
+    assert_eq!(
+        by_len,
+        [
+            (3, vec!["fig"]),
+            (4, vec!["kiwi", "plum", "pear", "date"]),
+            (5, vec!["apple"])
+        ]
+    );
+
+    // 첫 next 전에는 아무것도 읽지 않는다.
+    let reads = std::cell::Cell::new(0);
+    let mut it = (0..5)
+        .inspect(|_| reads.set(reads.get() + 1))
+        .sorted_by_key(|&x| std::cmp::Reverse(x));
+    assert_eq!(reads.get(), 0);
+    assert_eq!(it.next(), Some(4));
+    assert_eq!(reads.get(), 5);
+    assert_eq!(it.collect::<Vec<_>>(), [3, 2, 1, 0]);
+    assert_eq!(reads.get(), 5);
+    assert_eq!(check(|| (0..5).sorted_by_key(|&x| x % 2)), [0, 2, 4, 1, 3]);
+}
+
+#[test]
+fn tee_buffers_the_slower_side() {
+    let (a, b) = (1..=5).tee();
+    assert_eq!(a.size_hint(), (5, Some(5)));
+    let (mut a, mut b) = (a, b);
+    assert_eq!(a.next(), Some(1));
+    assert_eq!(a.next(), Some(2));
+    assert_eq!(b.size_hint(), (5, Some(5)));
+    assert_eq!(a.size_hint(), (3, Some(3)));
+    assert_eq!(b.next(), Some(1));
+    assert_eq!(b.by_ref().take(3).collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(a.size_hint(), (3, Some(3)));
+    assert_eq!(a.collect::<Vec<_>>(), [3, 4, 5]);
+    assert_eq!(b.collect::<Vec<_>>(), [5]);
+
+    // 합과 개수를 한 번의 순회로 (원래 반복자를 한 번만 돌리면서) 구한다.
+    let mut calls = 0;
+    let (x, y) = (1..=10).inspect(|_| calls += 1).tee();
+    let zipped: Vec<(i32, i32)> = x.zip(y.skip(1)).collect();
+    assert_eq!(zipped.len(), 9);
+    assert_eq!(calls, 10);
+}
+
+#[test]
+fn scan_while_and_batching() {
+    // 누적 합이 10을 넘기 전까지
+    let it = (1..).scan_while(0, |sum, x| {
+        *sum += x;
+        (*sum <= 10).then_some(*sum)
+    });
+    assert_eq!(it.collect::<Vec<_>>(), [1, 3, 6, 10]);
+    assert_eq!(
+        check(|| (1..=3).scan_while((), |_, x| Some(x * 2))),
+        [2, 4, 6]
+    );
+
+    // 길이가 앞에 적힌 레코드: [2, a, b, 1, c, 3, d, e, f]
+    let data = [2, 10, 11, 1, 20, 3, 30, 31, 32, 5, 40];
+    let records = || {
+        data.into_iter().batching(|it| {
+            let len = it.next()?;
+            let rec: Vec<_> = it.take(len).collect();
+            (rec.len() == len).then_some(rec)
+        })
+    };
+    assert_eq!(check(records), [vec![10, 11], vec![20], vec![30, 31, 32]]);
+    // f가 한 번 None을 돌려주면 안쪽에 원소가 남아 있어도 끝이다.
+    let mut pairs = (1..=5).batching(|it| Some((it.next()?, it.next()?)));
+    assert_eq!(pairs.by_ref().collect::<Vec<_>>(), [(1, 2), (3, 4)]);
+    assert_eq!(pairs.next(), None);
+}
+
+#[test]
+fn cartesian_product() {
+    let pairs = check(|| (1..=3).cartesian_product("ab".chars()));
+    assert_eq!(
+        pairs,
+        [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b'), (3, 'a'), (3, 'b')]
+    );
+    assert_eq!((1..=3).cartesian_product(0..4).size_hint(), (12, Some(12)));
+    assert!(check(|| (1..=3).cartesian_product(0..0)).is_empty());
+    assert!(check(|| (0..0).cartesian_product(1..=3)).is_empty());
+    // b가 비어 있으면 a가 무한해도 멈춘다.
+    assert_eq!(
+        (1..).cartesian_product(std::iter::empty::<u8>()).next(),
+        None
+    );
+    // 이중 for 루프 대신
+    let triples = (1..20)
+        .cartesian_product(1..20)
+        .filter(|&(a, b)| a < b)
+        .filter_map(|(a, b)| {
+            let c2 = a * a + b * b;
+            let c = (c2 as f64).sqrt() as i32;
+            (c * c == c2 && c < 20).then_some((a, b, c))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        triples,
+        [(3, 4, 5), (5, 12, 13), (6, 8, 10), (8, 15, 17), (9, 12, 15)]
+    );
+}
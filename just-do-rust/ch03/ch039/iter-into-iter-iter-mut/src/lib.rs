@@ -0,0 +1,2 @@
+// 표준 라이브러리에 없는 반복자 어댑터들을 IterExt 트레이트로 모든 반복자에 붙여 쓸 수 있게 한 모듈
+pub mod iter_ext;
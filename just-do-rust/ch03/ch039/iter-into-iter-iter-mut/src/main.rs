@@ -1,3 +1,5 @@
+use iter_into_iter_iter_mut::iter_ext::IterExt;
+
 fn main() {
     // 반복자의 생성은 데이터의 '모음' 혹은 수의 '범위'에서 이루어진다.
     // 데이터의 '모음'은 Rust에서 Collection이라고 부르는 것들이고 벡터(Vec), 배열, 해시 맵 같은 것들이다.
@@ -15,6 +17,26 @@ fn main() {
 
     iter_mut(): 컬렉션의 값을 수정해야할 때 사용한다. 레퍼런스로 받은 다음에 수정하는 것이다. 소유권이 넘어가지는 않는다.
      */
+
+    // 표준에 없는 어댑터들 (src/iter_ext.rs). 표준 어댑터처럼 이어 붙여 쓴다.
+    let words = ["apple", "avocado", "banana", "blueberry", "cherry", "apple"];
+    let groups: Vec<_> = words
+        .iter()
+        .unique()
+        .group_by(|w| w.chars().next().unwrap())
+        .collect();
+    println!("{:?}", groups);
+
+    let sums: Vec<i32> = (1..=10).windows(3).map(|w| w.iter().sum()).collect();
+    println!("{:?}", sums); // [6, 9, 12, ..., 27]
+
+    let (evens, odds) = (1..=6).tee();
+    let pairs: Vec<_> = evens
+        .filter(|x| x % 2 == 0)
+        .interleave(odds.filter(|x| x % 2 == 1))
+        .chunks_exact(2)
+        .collect();
+    println!("{:?}", pairs); // [[2, 1], [4, 3], [6, 5]]
 }
 
 #[test]
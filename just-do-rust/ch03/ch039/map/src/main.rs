@@ -28,6 +28,8 @@ fn map_test1() {
 
     println!("{:?}", v);
     println!("{:?}", v1);
+    assert_eq!(v, [1, 2, 3, 4, 5]);
+    assert_eq!(v1, [2, 3, 4, 5, 6]);
 
     /*
     map(...)에 의해서는 또 다른 iterator가 생성됨에 유의
@@ -59,6 +61,7 @@ fn map_test2() {
 
     // println!("{:?}", v);
     println!("{:?}", v1);
+    assert_eq!(v1, [2, 3, 4, 5, 6]);
 
     /*
     v.into_iter()를 사용하고 난 후, 그 아래 쪽에서 println!("v={:?}",v); 한 부분에서 에러가 발생한다.
@@ -78,11 +81,15 @@ fn map_test3() {
     // 명시적으로 Vec<u32>와 같이 지정해도 됨
     let ans: Vec<_> = (1..=100).map(|x| x * x).collect();
     println!("{:?}", ans);
+    assert_eq!(ans.len(), 100);
+    assert_eq!(ans[..5], [1, 4, 9, 16, 25]);
+    assert_eq!(ans[99], 10000);
 
     // let ans:Vec<_> =와 같이 변수에 어떤 컬렉션으로 collect()되는 지를 지정하지 않고
     // collect::<Vec<_>>();와 같이 collect에다가 어떤 컬렉션이 될 지 지정해준 것
     let ans: Vec<_> = (1..=100).map(|x| x * x).collect::<Vec<_>>();
     println!("{:?}", ans);
+    assert_eq!(ans.iter().sum::<i32>(), 338350);
 }
 
 #[test]
@@ -90,22 +97,27 @@ fn map_test() {
     let v = vec![1, 2, 3, 4, 5];
     let v1: Vec<_> = v.iter().map(|x| x + 1).collect();
     println!("{:?}", v1);
+    assert_eq!(v1, [2, 3, 4, 5, 6]);
 
     let v2: Vec<_> = v.iter().map(|x| x * 2).collect();
     println!("{:?}", v2);
+    assert_eq!(v2, [2, 4, 6, 8, 10]);
 
     let v = vec![1, 2, 3, 4, 5];
     let v1: Vec<_> = v.into_iter().map(|x| x * 2).collect();
     println!("{:?}", v1);
+    assert_eq!(v1, [2, 4, 6, 8, 10]);
 
     let v = vec![1, 2, 3, 4, 5];
     let v1 = v.iter().map(|x| (*x) * 2).collect::<Vec<u32>>();
     println!("{:?}", v1);
+    assert_eq!(v1, [2, 4, 6, 8, 10]);
 
     //2. 문자열을 모두 소문자로 변환
     let words: Vec<&str> = vec!["hello", "Good Morning", "Hi"];
     let low_words: Vec<String> = words.iter().map(|w| w.to_lowercase()).collect();
     println!("{:?}", low_words);
+    assert_eq!(low_words, ["hello", "good morning", "hi"]);
 
     //3. 문자열에서, 각 문자에 대해 문자열에서 해당 문자의 개수를 HashMap으로 저장. (ch: cnt)
     use std::collections::HashMap;
@@ -115,6 +127,7 @@ fn map_test() {
         .map(|c| (c, s.matches(c).count()))
         .collect::<HashMap<char, usize>>();
     println!("{:?}", map);
+    assert_eq!(map, HashMap::from([('a', 4), ('b', 3), ('c', 2)]));
 
     // (참조)어떤 문자열에서 알파벳만을 집합으로 뽑아낼 때
     use std::collections::HashSet;
@@ -124,4 +137,5 @@ fn map_test() {
         .filter(|c| c.is_alphabetic())
         .collect::<HashSet<_>>();
     println!("{:?}", set);
+    assert_eq!(set, HashSet::from(['a', 'b', 'c']));
 }
@@ -15,3 +15,13 @@ fn main() {
     // 위 코드에서 보면, filter의 경우는 벡터 v에 있는 모든 홀수를 골라낸다.
     // 반면, take_while은 홀수가 아닌 6을 만나는 순간 더 이상 진행을 하지 않는다
 }
+
+#[test]
+fn take_while_test() {
+    // filter는 끝까지 뒤지고, take_while은 조건을 벗어나는 6에서 멈춘다.
+    let v = [1, 3, 5, 6, 7, 8, 9, 10];
+    let v1: Vec<_> = v.iter().filter(|&&x| x % 2 == 1).collect();
+    let v2: Vec<_> = v.iter().take_while(|&&x| x % 2 == 1).collect();
+    assert_eq!(v1, [&1, &3, &5, &7, &9]);
+    assert_eq!(v2, [&1, &3, &5]);
+}
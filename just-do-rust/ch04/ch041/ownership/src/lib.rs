@@ -0,0 +1,2 @@
+// main.rs의 cal_point를 바탕으로 만든 단어 점수 게임 엔진
+pub mod score;
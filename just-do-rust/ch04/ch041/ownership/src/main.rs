@@ -1,3 +1,5 @@
+use ownership::score::{self, Scrabble, cal_point};
+
 fn main() {
     /*
     소유권 개념
@@ -48,15 +50,34 @@ fn main() {
     // "Hello Jeff" 값이 들어 있는 힙 주소를 가리키게 됨
     let s: String = String::from("hello jeff"); // String을 생성하고 변수 s에 할당
 
-    // 문제는 let point = cal_point(s);에서 s를 cal_point라는 함수로 넘긴데서 발생
-    // 이렇게 하면 cal_point 함수의 파라미터 변수인 w는 스택에 그 정보를 생성하게 되고,
+    // 문제는 let point = cal_point_owned(s);에서 s를 cal_point_owned라는 함수로 넘긴데서 발생
+    // 이렇게 하면 cal_point_owned 함수의 파라미터 변수인 w는 스택에 그 정보를 생성하게 되고,
     // 이 w 변수가 "Hello Jeff"라는 힙 영역을 가리키게 된다.
     // 즉, "Hello Jeff"의 힙 영역은 변수 s에서 변수 w로 그 소유권이 이동한다.
-    let point = cal_point(s); // s -> w 소유권이 이동
+    let point = cal_point_owned(s); // s -> w 소유권이 이동
 
     // println!("s = {}", s); // error 아무 소유권도 없는 빈털털이 ㅠㅠ
     println!("point = {}", point);
 
+    // 점수를 세는 데는 문자열을 읽기만 하면 되므로, score 모듈(src/score.rs)의 cal_point는 &str을 빌려 받는다.
+    // 이렇게 하면 소유권이 이동하지 않아 호출한 뒤에도 s를 쓸 수 있다.
+    let s: String = String::from("hello jeff");
+    let point = cal_point(&s);
+    println!("s = {}, point = {}", s, point);
+
+    // 점수 방식을 바꿔 끼우고, 단어들을 줄 세우고, 타일로 만들 수 있는 가장 좋은 단어를 찾는다.
+    let words = ["quiz", "zoo", "tea", "eat", "ate", "rust"];
+    for scored in score::rank(&Scrabble, words).iter().take(3) {
+        println!("{} = {}", scored.word, scored.score);
+    }
+    if let Some(best) = score::best_from_rack(&Scrabble, "TEAROZO", words) {
+        println!(
+            "TEAROZO로 만들 수 있는 최고 점수 단어: {} ({})",
+            best.word, best.score
+        );
+    }
+    println!("{:?}", score::anagram_groups(words));
+
     // **** Rust에서는, 메모리에 대해서 오직 한 개 변수만이 소유권을 가지게 한다는 것 ****
 
     /*
@@ -121,14 +142,11 @@ fn main() {
      */
 }
 
-fn cal_point(w: String) -> i32 {
-    // 주어진 String에 대해서, 모두 대문자로 바꾸고 'A'=1, 'B'=2 순서로 점수를 메겨서,
+fn cal_point_owned(w: String) -> i32 {
+    // 주어진 String에 대해서, 모두 대문자로 바꾸고 'A'=0, 'B'=1 순서로 점수를 메겨서,
     // 전체 알파벳에 대한 점수를 메긴 후 합계를 구하는 함수
-    w.to_uppercase()
-        .bytes()
-        .filter(|c| (*c >= b'A') && (*c <= b'Z'))
-        .map(|c| (c - b'A') as i32)
-        .sum()
+    // 소유권 이동을 보여 주려고 String을 값으로 받는다. 점수 계산은 score::cal_point에 맡긴다.
+    cal_point(&w)
 }
 
 fn sub_fn() {
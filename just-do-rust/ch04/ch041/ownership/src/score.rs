@@ -0,0 +1,496 @@
+// main.rs의 cal_point를 단어 점수 게임 엔진으로 키운 모듈
+//
+// cal_point는 처음에 String을 값으로 받아서 소유권 이동을 보여 줬다. 하지만 점수를 세는 데는 문자열을 읽기만 하면 되므로
+// 여기서는 &str을 빌려 받는다. 호출한 쪽은 점수를 센 뒤에도 문자열을 계속 쓸 수 있다.
+//
+// - Scoring 트레이트: 글자 하나의 점수를 정하는 방식. 방식을 바꿔 끼울 수 있다.
+//   ZeroBasedPosition(cal_point와 같은 A=0, B=1, ...), AlphabetPosition(A=1, B=2, ...), Scrabble, Table(파일에서 읽은 점수표)
+// - rank, score_file: 단어 목록이나 사전 파일의 단어들을 점수 순서로 줄 세운다.
+// - best_from_rack: 손에 든 글자 타일(rack)로 만들 수 있는 단어 중 점수가 가장 높은 것을 찾는다.
+// - anagram_groups: 글자를 섞어서 서로 만들 수 있는 단어(애너그램)끼리 묶는다.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+// 단어의 점수 = 대문자로 바꾼 글자마다 letter()의 합. 점수가 없는 글자(None)는 0점이다.
+pub trait Scoring {
+    // 대문자 하나의 점수
+    fn letter(&self, c: char) -> Option<i32>;
+
+    fn score(&self, word: &str) -> i32 {
+        word.to_uppercase()
+            .chars()
+            .filter_map(|c| self.letter(c))
+            .sum()
+    }
+}
+
+// cal_point의 방식. A=0, B=1, ..., Z=25
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZeroBasedPosition;
+
+impl Scoring for ZeroBasedPosition {
+    fn letter(&self, c: char) -> Option<i32> {
+        c.is_ascii_uppercase().then(|| (c as u8 - b'A') as i32)
+    }
+}
+
+// A=1, B=2, ..., Z=26
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlphabetPosition;
+
+impl Scoring for AlphabetPosition {
+    fn letter(&self, c: char) -> Option<i32> {
+        c.is_ascii_uppercase().then(|| (c as u8 - b'A') as i32 + 1)
+    }
+}
+
+// 영어판 스크래블의 글자 점수
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Scrabble;
+
+const SCRABBLE: [i32; 26] = [
+    1, 3, 3, 2, 1, 4, 2, 4, 1, 8, 5, 1, 3, 1, 1, 3, 10, 1, 1, 1, 1, 4, 4, 8, 4, 10,
+];
+
+impl Scoring for Scrabble {
+    fn letter(&self, c: char) -> Option<i32> {
+        c.is_ascii_uppercase()
+            .then(|| SCRABBLE[(c as u8 - b'A') as usize])
+    }
+}
+
+// 글자별 점수를 직접 정한 점수표. 영어가 아닌 글자에도 점수를 줄 수 있다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    points: HashMap<char, i32>,
+}
+
+#[derive(Debug)]
+pub enum TableError {
+    Io(io::Error),
+    // 점수표 파일의 형식이 잘못된 줄. line은 1부터 센다.
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::Io(e) => write!(f, "io error: {}", e),
+            TableError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for TableError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TableError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for TableError {
+    fn from(e: io::Error) -> TableError {
+        TableError::Io(e)
+    }
+}
+
+impl Table {
+    pub fn new() -> Table {
+        Table::default()
+    }
+
+    // 글자는 대문자로 바꿔서 저장한다. 바꿔 쓰기 전의 점수를 돌려준다.
+    pub fn set(&mut self, c: char, points: i32) -> Option<i32> {
+        self.points.insert(upper(c), points)
+    }
+
+    // 다른 방식의 A ~ Z 점수를 복사해서 시작한다.
+    pub fn from_scheme<S: Scoring + ?Sized>(scheme: &S) -> Table {
+        let mut t = Table::new();
+        for c in 'A'..='Z' {
+            if let Some(p) = scheme.letter(c) {
+                t.set(c, p);
+            }
+        }
+        t
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Table, TableError> {
+        let text = fs::read_to_string(path)?;
+        text.parse()
+    }
+}
+
+impl Scoring for Table {
+    fn letter(&self, c: char) -> Option<i32> {
+        self.points.get(&c).copied()
+    }
+}
+
+// 한 줄에 "글자 점수" 하나. 글자와 점수 사이는 공백이나 = 또는 :로 나눈다.
+// 빈 줄과 #으로 시작하는 줄은 건너뛴다.
+//
+//   # 모음은 낮게
+//   A 1
+//   Q = 10
+//   가: 3
+impl FromStr for Table {
+    type Err = TableError;
+
+    fn from_str(text: &str) -> Result<Table, TableError> {
+        let mut table = Table::new();
+        for (i, raw) in text.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parse_err = |message: String| TableError::Parse {
+                line: i + 1,
+                message,
+            };
+            let mut chars = line.chars();
+            let letter = chars.next().unwrap();
+            let rest = chars.as_str();
+            if !rest.starts_with(|c: char| c.is_whitespace() || c == '=' || c == ':') {
+                return Err(parse_err(format!(
+                    "expected '<letter> <points>', got {:?}",
+                    line
+                )));
+            }
+            let rest = rest.trim_start();
+            let rest = rest.strip_prefix(['=', ':']).unwrap_or(rest).trim_start();
+            let points: i32 = rest
+                .parse()
+                .map_err(|_| parse_err(format!("invalid points {:?}", rest)))?;
+            if table.set(letter, points).is_some() {
+                return Err(parse_err(format!("duplicate letter {:?}", letter)));
+            }
+        }
+        Ok(table)
+    }
+}
+
+// to_uppercase가 여러 글자가 되는 경우(ß → SS)는 그대로 둔다.
+fn upper(c: char) -> char {
+    let mut up = c.to_uppercase();
+    match (up.next(), up.next()) {
+        (Some(u), None) => u,
+        _ => c,
+    }
+}
+
+// 맨 처음의 cal_point. A=0, B=1, ... 점수를 더한다. 알파벳이 아닌 글자는 0점이다.
+pub fn cal_point(w: &str) -> i32 {
+    ZeroBasedPosition.score(w)
+}
+
+// ------------------------------------------------------------
+// 순위
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scored {
+    pub word: String,
+    pub score: i32,
+}
+
+// 점수가 높은 순서로, 점수가 같으면 단어의 사전 순서로 줄 세운다.
+pub fn rank<S, I>(scheme: &S, words: I) -> Vec<Scored>
+where
+    S: Scoring + ?Sized,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut scored: Vec<Scored> = words
+        .into_iter()
+        .map(|w| Scored {
+            word: w.as_ref().to_string(),
+            score: scheme.score(w.as_ref()),
+        })
+        .collect();
+    scored.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.word.cmp(&b.word)));
+    scored
+}
+
+// 한 줄에 한 단어인 사전 파일을 읽는다. 앞뒤 공백을 지우고, 빈 줄과 #으로 시작하는 줄은 건너뛴다.
+pub fn load_dictionary<P: AsRef<Path>>(path: P) -> io::Result<Vec<String>> {
+    let text = fs::read_to_string(path)?;
+    Ok(text
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
+
+// 사전 파일의 모든 단어에 점수를 매겨 줄 세운다.
+pub fn score_file<S, P>(scheme: &S, path: P) -> io::Result<Vec<Scored>>
+where
+    S: Scoring + ?Sized,
+    P: AsRef<Path>,
+{
+    Ok(rank(scheme, load_dictionary(path)?))
+}
+
+// ------------------------------------------------------------
+// 글자 타일(rack)
+
+// 빈 타일. 아무 글자로나 쓸 수 있지만 0점이다.
+pub const BLANK: char = '?';
+
+// 글자마다 개수. 대문자로 센다.
+fn letter_counts(s: &str) -> HashMap<char, usize> {
+    let mut counts = HashMap::new();
+    for c in s.to_uppercase().chars() {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
+}
+
+// rack의 타일로 word를 만들 수 있으면 그때의 점수. 만들 수 없으면 None
+// 같은 글자의 타일이 있으면 먼저 쓰고, 모자라는 글자만 빈 타일로 채운다. (빈 타일을 아껴야 점수가 높다.)
+pub fn rack_score<S: Scoring + ?Sized>(scheme: &S, rack: &str, word: &str) -> Option<i32> {
+    let mut tiles = letter_counts(rack);
+    let mut blanks = tiles.remove(&BLANK).unwrap_or(0);
+    let mut score = 0;
+    for c in word.to_uppercase().chars() {
+        match tiles.get_mut(&c) {
+            Some(n) if *n > 0 => {
+                *n -= 1;
+                score += scheme.letter(c).unwrap_or(0);
+            }
+            _ if blanks > 0 => blanks -= 1,
+            _ => return None,
+        }
+    }
+    Some(score)
+}
+
+// rack으로 만들 수 있는 단어 중 점수가 가장 높은 것. 점수가 같으면 사전 순서로 앞선 단어
+pub fn best_from_rack<S, I>(scheme: &S, rack: &str, words: I) -> Option<Scored>
+where
+    S: Scoring + ?Sized,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    words
+        .into_iter()
+        .filter(|w| !w.as_ref().is_empty())
+        .filter_map(|w| {
+            let score = rack_score(scheme, rack, w.as_ref())?;
+            Some(Scored {
+                word: w.as_ref().to_string(),
+                score,
+            })
+        })
+        .min_by(|a, b| b.score.cmp(&a.score).then_with(|| a.word.cmp(&b.word)))
+}
+
+// ------------------------------------------------------------
+// 애너그램
+
+// 글자(알파벳, 한글 등)만 소문자로 모아 정렬한 것. 애너그램끼리는 같다. "Listen" → "eilnst"
+pub fn anagram_key(word: &str) -> String {
+    let mut chars: Vec<char> = word
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .collect();
+    chars.sort_unstable();
+    chars.into_iter().collect()
+}
+
+// 서로 다른 두 단어가 같은 글자로 이루어졌는가 (대소문자, 공백, 문장 부호는 무시)
+pub fn is_anagram(a: &str, b: &str) -> bool {
+    let key = anagram_key(a);
+    !key.is_empty() && key == anagram_key(b) && a.to_lowercase() != b.to_lowercase()
+}
+
+// 애너그램끼리 묶는다. 두 단어 이상인 묶음만 돌려준다.
+// 묶음 안은 사전 순서, 묶음끼리는 첫 단어의 사전 순서다.
+// is_anagram과 맞추어 대소문자만 다른 단어("Tea", "tea")는 같은 단어로 보고, 사전 순서로 앞선 것 하나만 넣는다.
+pub fn anagram_groups<I>(words: I) -> Vec<Vec<String>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for w in words {
+        let key = anagram_key(w.as_ref());
+        if !key.is_empty() {
+            groups.entry(key).or_default().push(w.as_ref().to_string());
+        }
+    }
+    let mut out: Vec<Vec<String>> = groups
+        .into_values()
+        .map(|mut g| {
+            g.sort();
+            let mut seen = HashSet::new();
+            g.retain(|w| seen.insert(w.to_lowercase()));
+            g
+        })
+        .filter(|g| g.len() > 1)
+        .collect();
+    out.sort();
+    out
+}
+
+#[test]
+fn cal_point_keeps_zero_based_position_results() {
+    // 원래 cal_point(w: String)이 돌려주던 값
+    let table = [
+        ("hello jeff", 70),
+        ("", 0),
+        ("A", 0),
+        ("ABC", 3),
+        ("abc", 3),
+        ("Zz", 50),
+        ("Hello, World!", 114),
+        ("Rust 2024", 74),
+        ("대한민국", 0),
+        ("ß", 36), // 대문자로 바꾸면 "SS"가 된다.
+    ];
+    for (w, expected) in table {
+        assert_eq!(cal_point(w), expected, "{:?}", w);
+        assert_eq!(ZeroBasedPosition.score(w), expected);
+    }
+    // &str을 빌려 주므로 호출한 뒤에도 s를 쓸 수 있다.
+    let s = String::from("hello jeff");
+    assert_eq!(cal_point(&s), 70);
+    assert_eq!(s, "hello jeff");
+}
+
+#[test]
+fn schemes() {
+    assert_eq!(AlphabetPosition.score("abc"), 6);
+    assert_eq!(AlphabetPosition.score("hello jeff"), 70 + 9);
+    assert_eq!(Scrabble.score("quiz"), 22);
+    assert_eq!(Scrabble.score("Jazz!"), 29);
+    assert_eq!(Table::from_scheme(&Scrabble).score("quiz"), 22);
+
+    // 트레이트 객체로 바꿔 끼울 수 있다.
+    let schemes: Vec<Box<dyn Scoring>> = vec![
+        Box::new(ZeroBasedPosition),
+        Box::new(AlphabetPosition),
+        Box::new(Scrabble),
+    ];
+    let scores: Vec<i32> = schemes.iter().map(|s| s.score("rust")).collect();
+    assert_eq!(scores, [17 + 20 + 18 + 19, 18 + 21 + 19 + 20, 4]);
+}
+
+#[test]
+fn custom_tables() {
+    let t: Table = "# 점수표\nA 1\nb=2\n가: 5\n\n  Q   =  -3  \n"
+        .parse()
+        .unwrap();
+    assert_eq!(t.letter('A'), Some(1));
+    assert_eq!(t.letter('B'), Some(2));
+    assert_eq!(t.score("abq가나"), 5); // 1 + 2 - 3 + 5, 나는 점수표에 없다.
+
+    let table = [
+        ("A", 1, "expected '<letter> <points>', got \"A\""),
+        ("A 1\nB x", 2, "invalid points \"x\""),
+        ("A 1\n\na 2", 3, "duplicate letter 'a'"),
+        ("AB 3", 1, "expected '<letter> <points>', got \"AB 3\""),
+        ("A = = 1", 1, "invalid points \"= 1\""),
+    ];
+    for (text, line, message) in table {
+        match text.parse::<Table>() {
+            Err(TableError::Parse {
+                line: l,
+                message: m,
+            }) => {
+                assert_eq!((l, m.as_str()), (line, message), "{:?}", text);
+            }
+            other => panic!("{:?}: {:?}", text, other),
+        }
+    }
+
+    let path = std::env::temp_dir().join(format!("score-table-{}.txt", std::process::id()));
+    fs::write(&path, "A 5\nZ 1\n").unwrap();
+    let loaded = Table::load(&path);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap().score("Zaza"), 12);
+    assert!(matches!(Table::load(&path), Err(TableError::Io(_))));
+}
+
+#[test]
+fn ranking_and_dictionary_files() {
+    let ranked = rank(&Scrabble, ["cat", "quiz", "dog", "act", "zebra"]);
+    let words: Vec<(&str, i32)> = ranked.iter().map(|s| (s.word.as_str(), s.score)).collect();
+    assert_eq!(
+        words,
+        [
+            ("quiz", 22),
+            ("zebra", 16),
+            ("act", 5),
+            ("cat", 5),
+            ("dog", 5)
+        ]
+    );
+
+    let path = std::env::temp_dir().join(format!("score-words-{}.txt", std::process::id()));
+    fs::write(&path, "# 사전\napple\n\n  banana  \ncherry\r\n").unwrap();
+    let words = load_dictionary(&path);
+    let ranked = score_file(&AlphabetPosition, &path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(words.unwrap(), ["apple", "banana", "cherry"]);
+    let ranked = ranked.unwrap();
+    assert_eq!(ranked[0].word, "cherry");
+    assert_eq!(
+        ranked.iter().map(|s| s.score).collect::<Vec<_>>(),
+        [77, 50, 33]
+    );
+}
+
+#[test]
+fn rack_play() {
+    let words = ["quiz", "zoo", "tea", "eat", "treat", "tree", "ate"];
+    assert_eq!(rack_score(&Scrabble, "TEARZ", "tea"), Some(3));
+    assert_eq!(rack_score(&Scrabble, "TEARZ", "treat"), None); // T가 하나뿐
+    assert_eq!(rack_score(&Scrabble, "TEAR?", "treat"), Some(4)); // 빈 타일은 0점
+    assert_eq!(rack_score(&Scrabble, "??", "zz"), Some(0));
+    assert_eq!(rack_score(&Scrabble, "Z?", "zz"), Some(10));
+
+    // O가 하나뿐이면 zoo를 만들 수 없다.
+    let best = best_from_rack(&Scrabble, "TEAROZ", words).unwrap();
+    assert_eq!((best.word.as_str(), best.score), ("ate", 3));
+    let best = best_from_rack(&Scrabble, "TEAROZO", words).unwrap();
+    assert_eq!((best.word.as_str(), best.score), ("zoo", 12));
+    let best = best_from_rack(&Scrabble, "QUI?", words).unwrap();
+    assert_eq!((best.word.as_str(), best.score), ("quiz", 12));
+    assert_eq!(best_from_rack(&Scrabble, "XY", words), None);
+}
+
+#[test]
+fn anagrams() {
+    assert!(is_anagram("Listen", "Silent"));
+    assert!(is_anagram("Dormitory", "dirty room!"));
+    assert!(!is_anagram("listen", "LISTEN"));
+    assert!(!is_anagram("abc", "abd"));
+    assert!(!is_anagram("", "!!"));
+    assert!(is_anagram("국민", "민국"));
+
+    let groups = anagram_groups([
+        "listen", "enlist", "google", "silent", "tea", "eat", "ate", "eat", "x",
+    ]);
+    assert_eq!(
+        groups,
+        [
+            vec!["ate", "eat", "tea"],
+            vec!["enlist", "listen", "silent"]
+        ]
+    );
+
+    // 대소문자만 다른 단어는 애너그램이 아니므로 한 묶음이 되지 않는다.
+    assert!(anagram_groups(["Tea", "tea", "TEA"]).is_empty());
+    assert_eq!(anagram_groups(["tea", "Tea", "Eat"]), [vec!["Eat", "Tea"]]);
+}
@@ -0,0 +1,957 @@
+// main.rs에서 만들기만 하고 출력만 했던 Student2를 가지고 성적부(Gradebook)를 만든다.
+//
+// - 과목(Course)마다 평가 항목(Assessment)이 있고, 항목마다 가중치(weight)가 있다. (중간 30, 기말 40 ...)
+// - 학생은 과목에 수강 신청(enroll)을 하고, 평가 항목별로 점수(0~100)를 받는다.
+// - 과목별 가중 평균, 학점(A/B/C...), 석차, 백분위를 계산한다.
+// - 잘못된 입력은 panic이 아니라 GradebookError로 돌려준다.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write as _;
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Student2 {
+    pub name: String,
+    pub age: u8,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assessment {
+    pub name: String,
+    pub weight: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Course {
+    pub code: String,
+    pub assessments: Vec<Assessment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GradebookError {
+    DuplicateStudent(String),
+    AgeOutOfRange(u8),
+    UnknownStudent(String),
+    DuplicateCourse(String),
+    UnknownCourse(String),
+    UnknownAssessment {
+        course: String,
+        name: String,
+    },
+    DuplicateAssessment {
+        course: String,
+        name: String,
+    },
+    NotEnrolled {
+        student: String,
+        course: String,
+    },
+    InvalidWeight(f64),
+    InvalidScore(f64),
+    // CSV를 읽다가 잘못된 줄을 만난 경우. line은 1부터 센다.
+    Parse {
+        line: usize,
+        message: String,
+    },
+    // CSV의 한 줄을 들여오다가 위의 에러(AgeOutOfRange 등)가 난 경우. 원래 에러를 그대로 담는다.
+    AtLine {
+        line: usize,
+        error: Box<GradebookError>,
+    },
+}
+
+impl fmt::Display for GradebookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GradebookError::DuplicateStudent(n) => write!(f, "student {} already exists", n),
+            GradebookError::AgeOutOfRange(a) => write!(
+                f,
+                "age {} out of range {}..={}",
+                a,
+                Gradebook::AGE_RANGE.start(),
+                Gradebook::AGE_RANGE.end()
+            ),
+            GradebookError::UnknownStudent(n) => write!(f, "unknown student {}", n),
+            GradebookError::DuplicateCourse(c) => write!(f, "course {} already exists", c),
+            GradebookError::UnknownCourse(c) => write!(f, "unknown course {}", c),
+            GradebookError::UnknownAssessment { course, name } => {
+                write!(f, "unknown assessment {} in course {}", name, course)
+            }
+            GradebookError::DuplicateAssessment { course, name } => {
+                write!(f, "assessment {} already exists in course {}", name, course)
+            }
+            GradebookError::NotEnrolled { student, course } => {
+                write!(f, "student {} is not enrolled in {}", student, course)
+            }
+            GradebookError::InvalidWeight(w) => write!(f, "invalid weight {}", w),
+            GradebookError::InvalidScore(s) => write!(f, "score {} is not within 0..=100", s),
+            GradebookError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            GradebookError::AtLine { line, error } => write!(f, "line {}: {}", line, error),
+        }
+    }
+}
+
+impl Error for GradebookError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GradebookError::AtLine { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+// 학점 기준표. 점수가 높은 기준부터 차례로 비교해서 처음 만족하는 학점을 준다.
+// 어느 기준에도 못 미치면 fail 학점이다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradeCutoffs {
+    cutoffs: Vec<(f64, String)>,
+    fail: String,
+}
+
+impl GradeCutoffs {
+    // (최소 점수, 학점) 목록을 받는다. 순서는 상관없고, 내부에서 점수 내림차순으로 정렬한다.
+    pub fn new(cutoffs: &[(f64, &str)], fail: &str) -> GradeCutoffs {
+        let mut v: Vec<(f64, String)> = cutoffs
+            .iter()
+            .map(|(min, letter)| (*min, letter.to_string()))
+            .collect();
+        v.sort_by(|a, b| b.0.total_cmp(&a.0));
+        GradeCutoffs {
+            cutoffs: v,
+            fail: fail.to_string(),
+        }
+    }
+
+    pub fn letter(&self, score: f64) -> &str {
+        self.cutoffs
+            .iter()
+            .find(|(min, _)| score >= *min)
+            .map(|(_, l)| l.as_str())
+            .unwrap_or(&self.fail)
+    }
+}
+
+impl Default for GradeCutoffs {
+    fn default() -> GradeCutoffs {
+        GradeCutoffs::new(&[(90.0, "A"), (80.0, "B"), (70.0, "C"), (60.0, "D")], "F")
+    }
+}
+
+// 석차표의 한 줄
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    pub rank: usize,
+    pub name: String,
+    pub average: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct Gradebook {
+    students: BTreeMap<String, Student2>,
+    courses: BTreeMap<String, Course>,
+    // 과목 코드 -> 수강생 이름들
+    enrollments: BTreeMap<String, BTreeSet<String>>,
+    // (학생, 과목, 평가 항목) -> 점수
+    scores: BTreeMap<(String, String, String), f64>,
+    cutoffs: GradeCutoffs,
+}
+
+const CSV_HEADER: &str = "student,age,course,assessment,weight,score";
+
+impl Gradebook {
+    pub const AGE_RANGE: RangeInclusive<u8> = 6..=120;
+
+    pub fn new() -> Gradebook {
+        Gradebook::default()
+    }
+
+    pub fn with_cutoffs(cutoffs: GradeCutoffs) -> Gradebook {
+        Gradebook {
+            cutoffs,
+            ..Gradebook::default()
+        }
+    }
+
+    pub fn add_student(&mut self, student: Student2) -> Result<(), GradebookError> {
+        if !Gradebook::AGE_RANGE.contains(&student.age) {
+            return Err(GradebookError::AgeOutOfRange(student.age));
+        }
+        if self.students.contains_key(&student.name) {
+            return Err(GradebookError::DuplicateStudent(student.name));
+        }
+        self.students.insert(student.name.clone(), student);
+        Ok(())
+    }
+
+    pub fn student(&self, name: &str) -> Option<&Student2> {
+        self.students.get(name)
+    }
+
+    pub fn add_course(&mut self, code: &str) -> Result<(), GradebookError> {
+        if self.courses.contains_key(code) {
+            return Err(GradebookError::DuplicateCourse(code.to_string()));
+        }
+        self.courses.insert(
+            code.to_string(),
+            Course {
+                code: code.to_string(),
+                assessments: Vec::new(),
+            },
+        );
+        self.enrollments.insert(code.to_string(), BTreeSet::new());
+        Ok(())
+    }
+
+    pub fn add_assessment(
+        &mut self,
+        course: &str,
+        name: &str,
+        weight: f64,
+    ) -> Result<(), GradebookError> {
+        if !(weight.is_finite() && weight > 0.0) {
+            return Err(GradebookError::InvalidWeight(weight));
+        }
+        let c = self.course_mut(course)?;
+        if c.assessments.iter().any(|a| a.name == name) {
+            return Err(GradebookError::DuplicateAssessment {
+                course: course.to_string(),
+                name: name.to_string(),
+            });
+        }
+        c.assessments.push(Assessment {
+            name: name.to_string(),
+            weight,
+        });
+        Ok(())
+    }
+
+    pub fn enroll(&mut self, student: &str, course: &str) -> Result<(), GradebookError> {
+        self.check_student(student)?;
+        self.enrollments
+            .get_mut(course)
+            .ok_or_else(|| GradebookError::UnknownCourse(course.to_string()))?
+            .insert(student.to_string());
+        Ok(())
+    }
+
+    pub fn record_score(
+        &mut self,
+        student: &str,
+        course: &str,
+        assessment: &str,
+        score: f64,
+    ) -> Result<(), GradebookError> {
+        if !(0.0..=100.0).contains(&score) {
+            return Err(GradebookError::InvalidScore(score));
+        }
+        self.check_enrolled(student, course)?;
+        if !self.courses[course]
+            .assessments
+            .iter()
+            .any(|a| a.name == assessment)
+        {
+            return Err(GradebookError::UnknownAssessment {
+                course: course.to_string(),
+                name: assessment.to_string(),
+            });
+        }
+        self.scores.insert(
+            (
+                student.to_string(),
+                course.to_string(),
+                assessment.to_string(),
+            ),
+            score,
+        );
+        Ok(())
+    }
+
+    // 가중 평균 = sum(가중치 * 점수) / sum(가중치)
+    // 아직 점수가 없는 평가 항목은 분자, 분모 모두에서 빠진다. 즉 지금까지 치른 평가만으로 평균을 낸다.
+    // 점수가 하나도 없으면 None이다.
+    pub fn weighted_average(
+        &self,
+        student: &str,
+        course: &str,
+    ) -> Result<Option<f64>, GradebookError> {
+        self.check_enrolled(student, course)?;
+        let mut total = 0.0;
+        let mut weights = 0.0;
+        for a in &self.courses[course].assessments {
+            let key = (student.to_string(), course.to_string(), a.name.clone());
+            if let Some(score) = self.scores.get(&key) {
+                total += a.weight * score;
+                weights += a.weight;
+            }
+        }
+        if weights == 0.0 {
+            Ok(None)
+        } else {
+            Ok(Some(total / weights))
+        }
+    }
+
+    pub fn letter_grade(
+        &self,
+        student: &str,
+        course: &str,
+    ) -> Result<Option<&str>, GradebookError> {
+        Ok(self
+            .weighted_average(student, course)?
+            .map(|avg| self.cutoffs.letter(avg)))
+    }
+
+    // 과목의 석차표. 평균이 높은 순서이고, 평균이 같으면 같은 등수를 주고 다음 등수는 건너뛴다. (1, 2, 2, 4)
+    // 같은 등수끼리는 이름 순서로 나열한다. 점수가 하나도 없는 학생은 석차에서 빠진다.
+    pub fn ranking(&self, course: &str) -> Result<Vec<Standing>, GradebookError> {
+        let names = self
+            .enrollments
+            .get(course)
+            .ok_or_else(|| GradebookError::UnknownCourse(course.to_string()))?;
+        let mut rows = Vec::new();
+        for name in names {
+            if let Some(avg) = self.weighted_average(name, course)? {
+                rows.push((name.clone(), avg));
+            }
+        }
+        rows.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let mut standings: Vec<Standing> = Vec::with_capacity(rows.len());
+        for (i, (name, average)) in rows.into_iter().enumerate() {
+            let rank = match standings.last() {
+                Some(prev) if prev.average == average => prev.rank,
+                _ => i + 1,
+            };
+            standings.push(Standing {
+                rank,
+                name,
+                average,
+            });
+        }
+        Ok(standings)
+    }
+
+    // 백분위: 나보다 평균이 낮은 학생 수 + 나와 같은 학생 수의 절반을, 전체 학생 수로 나눈 백분율
+    // 동점자들은 같은 백분위를 받는다. 점수가 없으면 None이다.
+    pub fn percentile(&self, student: &str, course: &str) -> Result<Option<f64>, GradebookError> {
+        let mine = match self.weighted_average(student, course)? {
+            Some(avg) => avg,
+            None => return Ok(None),
+        };
+        let table = self.ranking(course)?;
+        let below = table.iter().filter(|s| s.average < mine).count() as f64;
+        let equal = table.iter().filter(|s| s.average == mine).count() as f64;
+        Ok(Some((below + 0.5 * equal) / table.len() as f64 * 100.0))
+    }
+
+    // 학생 한 명의 성적표를 표 형태의 문자열로 만든다.
+    pub fn report_card(&self, student: &str) -> Result<String, GradebookError> {
+        let s = self.check_student(student)?;
+        let mut out = String::new();
+        let _ = writeln!(out, "Report card: {} (age {})", s.name, s.age);
+        let _ = writeln!(
+            out,
+            "{:<10} {:>8} {:>6} {:>6} {:>10}",
+            "course", "average", "grade", "rank", "percentile"
+        );
+        for (code, names) in &self.enrollments {
+            if !names.contains(student) {
+                continue;
+            }
+            match self.weighted_average(student, code)? {
+                Some(avg) => {
+                    let table = self.ranking(code)?;
+                    let rank = table
+                        .iter()
+                        .find(|r| r.name == student)
+                        .map(|r| r.rank)
+                        .unwrap_or(0);
+                    let pct = self.percentile(student, code)?.unwrap_or(0.0);
+                    let _ = writeln!(
+                        out,
+                        "{:<10} {:>8.2} {:>6} {:>6} {:>10.1}",
+                        code,
+                        avg,
+                        self.cutoffs.letter(avg),
+                        format!("{}/{}", rank, table.len()),
+                        pct
+                    );
+                }
+                None => {
+                    let _ = writeln!(
+                        out,
+                        "{:<10} {:>8} {:>6} {:>6} {:>10}",
+                        code, "-", "-", "-", "-"
+                    );
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    // 한 줄에 점수 하나씩 내보낸다. 점수 없이 수강 신청만 한 경우는 assessment 이후 칸을 비운다.
+    // 평가 항목은 있는데 아무도 점수를 받지 않은 경우도 잃지 않도록, 점수 칸만 빈 줄로 남긴다.
+    // 평가 항목도 수강생도 없는 과목은 과목 칸만 채운 줄로 남긴다.
+    // 이름이나 과목 코드에 쉼표, 따옴표, 줄바꿈, 앞뒤 공백이 있으면 따옴표로 감싼다. (csv_escape)
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(CSV_HEADER);
+        out.push('\n');
+        let mut written_assessments = BTreeSet::new();
+        for ((student, course, assessment), score) in &self.scores {
+            let age = self.students[student].age;
+            let weight = self.courses[course]
+                .assessments
+                .iter()
+                .find(|a| &a.name == assessment)
+                .map(|a| a.weight)
+                .unwrap_or(0.0);
+            csv_line(
+                &mut out,
+                &[
+                    student,
+                    &age.to_string(),
+                    course,
+                    assessment,
+                    &weight.to_string(),
+                    &score.to_string(),
+                ],
+            );
+            written_assessments.insert((course.clone(), assessment.clone()));
+        }
+        for (course, names) in &self.enrollments {
+            for student in names {
+                let has_score = self
+                    .scores
+                    .keys()
+                    .any(|(s, c, _)| s == student && c == course);
+                if !has_score {
+                    let age = self.students[student].age.to_string();
+                    csv_line(&mut out, &[student, &age, course, "", "", ""]);
+                }
+            }
+        }
+        for (code, c) in &self.courses {
+            if c.assessments.is_empty() && self.enrollments[code].is_empty() {
+                csv_line(&mut out, &["", "", code, "", "", ""]);
+            }
+            for a in &c.assessments {
+                if !written_assessments.contains(&(code.clone(), a.name.clone())) {
+                    csv_line(
+                        &mut out,
+                        &["", "", code, &a.name, &a.weight.to_string(), ""],
+                    );
+                }
+            }
+        }
+        out
+    }
+
+    // to_csv가 만든 형식을 읽어서 성적부를 만든다.
+    // 처음 보는 학생, 과목, 평가 항목은 자동으로 등록하고,
+    // 같은 학생의 나이나 같은 평가 항목의 가중치가 줄마다 다르면 에러로 처리한다.
+    // 따옴표 안의 줄바꿈 때문에 한 레코드가 여러 줄일 수 있고, 오류의 줄 번호는 레코드가 시작하는 줄이다.
+    // 형식이 틀린 줄은 Parse, 형식은 맞지만 add_student 등이 거절한 줄은 원래 에러를 담은 AtLine이다.
+    pub fn from_csv(text: &str, cutoffs: GradeCutoffs) -> Result<Gradebook, GradebookError> {
+        let mut book = Gradebook::with_cutoffs(cutoffs);
+        for (line_no, line) in csv_records(text) {
+            if line.trim().is_empty() || (line_no == 1 && line == CSV_HEADER) {
+                continue;
+            }
+            book.import_line(&line).map_err(|e| match e {
+                GradebookError::Parse { message, .. } => GradebookError::Parse {
+                    line: line_no,
+                    message,
+                },
+                other => GradebookError::AtLine {
+                    line: line_no,
+                    error: Box::new(other),
+                },
+            })?;
+        }
+        Ok(book)
+    }
+
+    fn import_line(&mut self, line: &str) -> Result<(), GradebookError> {
+        let parse_err = |message: String| GradebookError::Parse { line: 0, message };
+        let f = csv_split(line).map_err(parse_err)?;
+        if f.len() != 6 {
+            return Err(parse_err(format!("expected 6 fields, got {}", f.len())));
+        }
+        let (student, age, course, assessment, weight, score) = (
+            f[0].as_str(),
+            f[1].as_str(),
+            f[2].as_str(),
+            f[3].as_str(),
+            f[4].as_str(),
+            f[5].as_str(),
+        );
+        if course.is_empty() {
+            return Err(parse_err("course is empty".to_string()));
+        }
+        if !self.courses.contains_key(course) {
+            self.add_course(course)?;
+        }
+        if !assessment.is_empty() {
+            let weight: f64 = weight
+                .parse()
+                .map_err(|_| parse_err(format!("bad weight {:?}", weight)))?;
+            match self.courses[course]
+                .assessments
+                .iter()
+                .find(|a| a.name == assessment)
+            {
+                Some(a) if a.weight != weight => {
+                    return Err(parse_err(format!(
+                        "assessment {} has weight {} but {} was given earlier",
+                        assessment, weight, a.weight
+                    )));
+                }
+                Some(_) => {}
+                None => self.add_assessment(course, assessment, weight)?,
+            }
+        }
+        if student.is_empty() {
+            return Ok(());
+        }
+        let age: u8 = age
+            .parse()
+            .map_err(|_| parse_err(format!("bad age {:?}", age)))?;
+        match self.students.get(student) {
+            Some(s) if s.age != age => {
+                return Err(parse_err(format!(
+                    "student {} has age {} but {} was given earlier",
+                    student, age, s.age
+                )));
+            }
+            Some(_) => {}
+            None => self.add_student(Student2 {
+                name: student.to_string(),
+                age,
+            })?,
+        }
+        self.enroll(student, course)?;
+        if !score.is_empty() {
+            let score: f64 = score
+                .parse()
+                .map_err(|_| parse_err(format!("bad score {:?}", score)))?;
+            self.record_score(student, course, assessment, score)?;
+        }
+        Ok(())
+    }
+
+    fn check_student(&self, name: &str) -> Result<&Student2, GradebookError> {
+        self.students
+            .get(name)
+            .ok_or_else(|| GradebookError::UnknownStudent(name.to_string()))
+    }
+
+    fn check_enrolled(&self, student: &str, course: &str) -> Result<(), GradebookError> {
+        self.check_student(student)?;
+        let names = self
+            .enrollments
+            .get(course)
+            .ok_or_else(|| GradebookError::UnknownCourse(course.to_string()))?;
+        if names.contains(student) {
+            Ok(())
+        } else {
+            Err(GradebookError::NotEnrolled {
+                student: student.to_string(),
+                course: course.to_string(),
+            })
+        }
+    }
+
+    fn course_mut(&mut self, code: &str) -> Result<&mut Course, GradebookError> {
+        self.courses
+            .get_mut(code)
+            .ok_or_else(|| GradebookError::UnknownCourse(code.to_string()))
+    }
+}
+
+// 필드들을 쉼표로 이어서 한 줄로 쓴다.
+fn csv_line(out: &mut String, fields: &[&str]) {
+    let line: Vec<String> = fields.iter().map(|f| csv_escape(f)).collect();
+    out.push_str(&line.join(","));
+    out.push('\n');
+}
+
+// 쉼표, 따옴표, 줄바꿈이 들어간 필드는 따옴표로 감싸고, 안의 따옴표는 두 번 쓴다. (RFC 4180 방식)
+// 따옴표 없는 필드는 읽을 때 앞뒤 공백을 떼므로, 앞뒤에 공백이 있는 필드도 감싼다.
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) || field.trim() != field {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// text를 레코드 하나씩의 (시작 줄 번호, 레코드)로 나눈다.
+// 따옴표가 홀수 개 나왔으면 따옴표 안에서 줄이 바뀐 것이므로 다음 줄을 이어 붙인다.
+fn csv_records(text: &str) -> Vec<(usize, String)> {
+    let mut records = Vec::new();
+    let mut cur = String::new();
+    let mut start = 1;
+    for (i, line) in text.split_inclusive('\n').enumerate() {
+        if cur.is_empty() {
+            start = i + 1;
+        }
+        cur.push_str(line);
+        if cur.matches('"').count().is_multiple_of(2) {
+            let record = std::mem::take(&mut cur);
+            let record = record.strip_suffix('\n').unwrap_or(&record);
+            let record = record.strip_suffix('\r').unwrap_or(record);
+            records.push((start, record.to_string()));
+        }
+    }
+    if !cur.is_empty() {
+        records.push((start, cur));
+    }
+    records
+}
+
+// 따옴표 없는 필드는 손으로 고친 파일을 위해 앞뒤 공백을 떼고, 따옴표로 감싼 필드는 그대로 둔다.
+fn csv_split(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut cur = String::new();
+    let mut chars = line.chars().peekable();
+    let mut in_quotes = false;
+    let mut was_quoted = false;
+    let finish = |cur: &mut String, was_quoted: bool| {
+        let field = std::mem::take(cur);
+        if was_quoted {
+            field
+        } else {
+            field.trim().to_string()
+        }
+    };
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cur.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => cur.push(c),
+            }
+        } else {
+            match c {
+                ',' => {
+                    fields.push(finish(&mut cur, was_quoted));
+                    was_quoted = false;
+                }
+                '"' if cur.trim().is_empty() && !was_quoted => {
+                    cur.clear();
+                    in_quotes = true;
+                    was_quoted = true;
+                }
+                '"' => return Err("unexpected quote in unquoted field".to_string()),
+                c if was_quoted && c.is_whitespace() => {}
+                _ if was_quoted => return Err("text after closing quote".to_string()),
+                _ => cur.push(c),
+            }
+        }
+    }
+    if in_quotes {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(finish(&mut cur, was_quoted));
+    Ok(fields)
+}
+
+#[cfg(test)]
+fn student(name: &str, age: u8) -> Student2 {
+    Student2 {
+        name: name.to_string(),
+        age,
+    }
+}
+
+// 중간(40), 기말(60) 두 평가가 있는 RUST101 과목에 네 명이 수강하는 성적부
+#[cfg(test)]
+fn sample() -> Gradebook {
+    let mut b = Gradebook::new();
+    b.add_course("RUST101").unwrap();
+    b.add_assessment("RUST101", "mid", 40.0).unwrap();
+    b.add_assessment("RUST101", "final", 60.0).unwrap();
+    for (name, mid, fin) in [
+        ("Jeff", 90.0, 100.0),
+        ("Alice", 100.0, 90.0),
+        ("Bob", 80.0, 70.0),
+        ("Carol", 100.0, 90.0),
+    ] {
+        b.add_student(student(name, 25)).unwrap();
+        b.enroll(name, "RUST101").unwrap();
+        b.record_score(name, "RUST101", "mid", mid).unwrap();
+        b.record_score(name, "RUST101", "final", fin).unwrap();
+    }
+    b
+}
+
+#[cfg(test)]
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn weighted_average_and_letters() {
+    let b = sample();
+    // Jeff: (90*40 + 100*60) / 100 = 96
+    assert!(close(
+        b.weighted_average("Jeff", "RUST101").unwrap().unwrap(),
+        96.0
+    ));
+    // Bob: (80*40 + 70*60) / 100 = 74
+    assert!(close(
+        b.weighted_average("Bob", "RUST101").unwrap().unwrap(),
+        74.0
+    ));
+    assert_eq!(b.letter_grade("Jeff", "RUST101").unwrap(), Some("A"));
+    assert_eq!(b.letter_grade("Bob", "RUST101").unwrap(), Some("C"));
+}
+
+#[test]
+fn missing_scores_are_excluded_from_average() {
+    let mut b = sample();
+    b.add_student(student("Dave", 30)).unwrap();
+    b.enroll("Dave", "RUST101").unwrap();
+    assert_eq!(b.weighted_average("Dave", "RUST101").unwrap(), None);
+    assert_eq!(b.letter_grade("Dave", "RUST101").unwrap(), None);
+    b.record_score("Dave", "RUST101", "mid", 50.0).unwrap();
+    assert!(close(
+        b.weighted_average("Dave", "RUST101").unwrap().unwrap(),
+        50.0
+    ));
+}
+
+#[test]
+fn configurable_cutoffs() {
+    let pass_fail = GradeCutoffs::new(&[(75.0, "P")], "NP");
+    assert_eq!(pass_fail.letter(75.0), "P");
+    assert_eq!(pass_fail.letter(74.9), "NP");
+
+    let plus = GradeCutoffs::new(&[(80.0, "B"), (95.0, "A+"), (90.0, "A")], "F");
+    assert_eq!(plus.letter(96.0), "A+");
+    assert_eq!(plus.letter(92.0), "A");
+    assert_eq!(plus.letter(85.0), "B");
+    assert_eq!(plus.letter(10.0), "F");
+}
+
+#[test]
+fn ranking_with_ties() {
+    let b = sample();
+    // Jeff 96, Alice 94, Carol 94, Bob 74
+    let table = b.ranking("RUST101").unwrap();
+    let ranks: Vec<(usize, &str)> = table.iter().map(|s| (s.rank, s.name.as_str())).collect();
+    assert_eq!(
+        ranks,
+        vec![(1, "Jeff"), (2, "Alice"), (2, "Carol"), (4, "Bob")]
+    );
+
+    // 동점자는 같은 백분위를 받는다.
+    let alice = b.percentile("Alice", "RUST101").unwrap().unwrap();
+    let carol = b.percentile("Carol", "RUST101").unwrap().unwrap();
+    assert!(close(alice, carol));
+    // 아래에 1명(Bob), 동점 2명 -> (1 + 1) / 4 = 50%
+    assert!(close(alice, 50.0));
+    assert!(close(
+        b.percentile("Jeff", "RUST101").unwrap().unwrap(),
+        87.5
+    ));
+    assert!(close(
+        b.percentile("Bob", "RUST101").unwrap().unwrap(),
+        12.5
+    ));
+}
+
+#[test]
+fn empty_course() {
+    let mut b = sample();
+    b.add_course("EMPTY").unwrap();
+    assert_eq!(b.ranking("EMPTY").unwrap(), vec![]);
+
+    // 수강생은 있지만 평가 항목이 없는 과목
+    b.enroll("Jeff", "EMPTY").unwrap();
+    assert_eq!(b.ranking("EMPTY").unwrap(), vec![]);
+    assert_eq!(b.weighted_average("Jeff", "EMPTY").unwrap(), None);
+    assert_eq!(b.percentile("Jeff", "EMPTY").unwrap(), None);
+    assert!(b.report_card("Jeff").unwrap().contains("EMPTY"));
+}
+
+#[test]
+fn validation_errors() {
+    let mut b = sample();
+    assert_eq!(
+        b.add_student(student("Jeff", 30)),
+        Err(GradebookError::DuplicateStudent("Jeff".to_string()))
+    );
+    assert_eq!(
+        b.add_student(student("Baby", 2)),
+        Err(GradebookError::AgeOutOfRange(2))
+    );
+    assert_eq!(
+        b.enroll("Jeff", "MATH"),
+        Err(GradebookError::UnknownCourse("MATH".to_string()))
+    );
+    assert_eq!(
+        b.enroll("Nobody", "RUST101"),
+        Err(GradebookError::UnknownStudent("Nobody".to_string()))
+    );
+    assert_eq!(
+        b.record_score("Jeff", "RUST101", "quiz", 10.0),
+        Err(GradebookError::UnknownAssessment {
+            course: "RUST101".to_string(),
+            name: "quiz".to_string()
+        })
+    );
+    assert_eq!(
+        b.record_score("Jeff", "RUST101", "mid", 101.0),
+        Err(GradebookError::InvalidScore(101.0))
+    );
+    assert_eq!(
+        b.add_assessment("RUST101", "quiz", 0.0),
+        Err(GradebookError::InvalidWeight(0.0))
+    );
+    b.add_course("MATH").unwrap();
+    assert_eq!(
+        b.weighted_average("Jeff", "MATH"),
+        Err(GradebookError::NotEnrolled {
+            student: "Jeff".to_string(),
+            course: "MATH".to_string()
+        })
+    );
+}
+
+#[test]
+fn csv_round_trip() {
+    let mut b = sample();
+    b.add_student(student("Dave", 30)).unwrap();
+    b.enroll("Dave", "RUST101").unwrap();
+    b.add_course("MATH").unwrap();
+    b.add_assessment("MATH", "quiz", 10.0).unwrap();
+
+    let csv = b.to_csv();
+    let loaded = Gradebook::from_csv(&csv, GradeCutoffs::default()).unwrap();
+    assert_eq!(loaded.to_csv(), csv);
+    assert_eq!(
+        loaded.ranking("RUST101").unwrap(),
+        b.ranking("RUST101").unwrap()
+    );
+    assert_eq!(loaded.student("Dave"), Some(&student("Dave", 30)));
+    assert_eq!(loaded.weighted_average("Dave", "RUST101").unwrap(), None);
+}
+
+#[test]
+fn csv_round_trip_keeps_empty_course() {
+    let mut b = sample();
+    b.add_course("EMPTY").unwrap();
+
+    let csv = b.to_csv();
+    assert!(csv.contains("\n,,EMPTY,,,\n"), "{}", csv);
+    let loaded = Gradebook::from_csv(&csv, GradeCutoffs::default()).unwrap();
+    assert_eq!(loaded.to_csv(), csv);
+    assert_eq!(loaded.ranking("EMPTY").unwrap(), vec![]);
+}
+
+#[test]
+fn csv_round_trip_with_commas_and_spaces() {
+    let mut b = Gradebook::new();
+    b.add_course("CS, 101").unwrap();
+    b.add_assessment("CS, 101", "mid \"A\"", 50.0).unwrap();
+    b.add_assessment("CS, 101", "final\nexam", 50.0).unwrap();
+    b.add_course(" padded ").unwrap();
+    for name in ["Kim, Minsu", "  Lee  ", "Park"] {
+        b.add_student(student(name, 20)).unwrap();
+        b.enroll(name, "CS, 101").unwrap();
+        b.record_score(name, "CS, 101", "mid \"A\"", 80.0).unwrap();
+    }
+    b.record_score("Kim, Minsu", "CS, 101", "final\nexam", 95.0)
+        .unwrap();
+    b.enroll("  Lee  ", " padded ").unwrap();
+
+    let csv = b.to_csv();
+    assert!(csv.contains("\"Kim, Minsu\",20,\"CS, 101\",\"mid \"\"A\"\"\",50,80"));
+    let loaded = Gradebook::from_csv(&csv, GradeCutoffs::default()).unwrap();
+    assert_eq!(loaded.to_csv(), csv);
+    assert_eq!(
+        loaded.student("Kim, Minsu"),
+        Some(&student("Kim, Minsu", 20))
+    );
+    assert_eq!(loaded.student("  Lee  "), Some(&student("  Lee  ", 20)));
+    assert_eq!(
+        loaded.weighted_average("Kim, Minsu", "CS, 101").unwrap(),
+        b.weighted_average("Kim, Minsu", "CS, 101").unwrap()
+    );
+    assert_eq!(
+        loaded.ranking("CS, 101").unwrap(),
+        b.ranking("CS, 101").unwrap()
+    );
+
+    // 손으로 고친 파일의 따옴표 없는 필드는 앞뒤 공백을 뗀다.
+    let hand = "Jeff , 25 ,RUST101, mid ,40, 90\n";
+    let loaded = Gradebook::from_csv(hand, GradeCutoffs::default()).unwrap();
+    assert_eq!(loaded.student("Jeff"), Some(&student("Jeff", 25)));
+    let bad = "\"Jeff\"x,25,RUST101,mid,40,90\n";
+    assert!(matches!(
+        Gradebook::from_csv(bad, GradeCutoffs::default()),
+        Err(GradebookError::Parse { line: 1, .. })
+    ));
+}
+
+#[test]
+fn csv_import_errors_have_line_numbers() {
+    let csv = "student,age,course,assessment,weight,score\n\
+               Jeff,25,RUST101,mid,40,90\n\
+               Jeff,26,RUST101,final,60,100\n";
+    match Gradebook::from_csv(csv, GradeCutoffs::default()) {
+        Err(GradebookError::Parse { line, message }) => {
+            assert_eq!(line, 3);
+            assert!(message.contains("age"), "{}", message);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let csv = "Baby,3,RUST101,mid,40,90\n";
+    let err = Gradebook::from_csv(csv, GradeCutoffs::default()).unwrap_err();
+    assert_eq!(
+        err,
+        GradebookError::AtLine {
+            line: 1,
+            error: Box::new(GradebookError::AgeOutOfRange(3))
+        }
+    );
+    assert_eq!(err.to_string(), "line 1: age 3 out of range 6..=120");
+    assert!(err.source().is_some());
+
+    // 점수가 0..=100을 벗어난 줄
+    let csv = "Jeff,25,RUST101,mid,40,90\n\
+               Jeff,25,RUST101,final,60,190\n";
+    assert_eq!(
+        Gradebook::from_csv(csv, GradeCutoffs::default()).unwrap_err(),
+        GradebookError::AtLine {
+            line: 2,
+            error: Box::new(GradebookError::InvalidScore(190.0))
+        }
+    );
+
+    let csv = "Jeff,25,RUST101,mid,40,abc\n";
+    assert!(matches!(
+        Gradebook::from_csv(csv, GradeCutoffs::default()),
+        Err(GradebookError::Parse { line: 1, .. })
+    ));
+}
+
+#[test]
+fn report_card_format() {
+    let b = sample();
+    let card = b.report_card("Alice").unwrap();
+    let lines: Vec<&str> = card.lines().collect();
+    assert_eq!(lines[0], "Report card: Alice (age 25)");
+    assert_eq!(lines[2], "RUST101       94.00      A    2/4       50.0");
+    assert_eq!(
+        b.report_card("Nobody"),
+        Err(GradebookError::UnknownStudent("Nobody".to_string()))
+    );
+}
@@ -0,0 +1,2 @@
+// Student2 구조체를 중심으로 과목, 평가, 점수를 관리하는 성적부 모듈
+pub mod gradebook;
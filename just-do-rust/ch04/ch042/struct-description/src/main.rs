@@ -6,11 +6,14 @@
         상속 개념에서 오는 장점보다 단점을 더 크게 본 듯하다.
 */
 
-#[derive(Debug)]
-struct Student2 {
-    name: String,
-    age: u8,
-}
+// Student2는 성적부 모듈(src/gradebook.rs)에서도 쓰기 위해 그쪽으로 옮겼다. 정의는 아래와 같다.
+//
+//     #[derive(Debug)]
+//     pub struct Student2 {
+//         pub name: String,
+//         pub age: u8,
+//     }
+use struct_description::gradebook::{Gradebook, Student2};
 
 fn main() {
     // 구조체의 정의
@@ -67,6 +70,25 @@ fn main() {
     // let s1 = make_student("Jeff".to_owned(), 25);라고 함수를 호출해서 인스턴스를 만들 수 있다.
     // make_student에서는 주어진 name과 point를 가지고 Student 인스턴스를 만들었다.
     // 리턴할 때는 return 키워드를 안쓰고 이처럼 expression 형태로 놔둬도 함수의 리턴값이 된다.
+
+    // 4. 구조체를 모아서 쓰기
+    // make_student로 만든 Student2를 성적부에 등록하고, 과목별 점수를 넣어서 성적표를 출력한다.
+    let mut book = Gradebook::new();
+    book.add_course("RUST101").unwrap();
+    book.add_assessment("RUST101", "mid", 40.0).unwrap();
+    book.add_assessment("RUST101", "final", 60.0).unwrap();
+    for (name, mid, fin) in [("Jeff", 90.0, 100.0), ("Alice", 100.0, 90.0)] {
+        book.add_student(make_student(name.to_owned(), 25)).unwrap();
+        book.enroll(name, "RUST101").unwrap();
+        book.record_score(name, "RUST101", "mid", mid).unwrap();
+        book.record_score(name, "RUST101", "final", fin).unwrap();
+    }
+    print!("{}", book.report_card("Jeff").unwrap());
+
+    // 같은 학생을 두 번 등록하면 panic이 아니라 에러가 리턴된다.
+    if let Err(e) = book.add_student(make_student("Jeff".to_owned(), 30)) {
+        println!("error: {}", e);
+    }
 }
 
 // name과 point를 주면 Student 인스턴스를 만들어서 리턴하는 함수
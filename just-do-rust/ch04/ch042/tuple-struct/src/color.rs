@@ -0,0 +1,764 @@
+// main.rs의 Color(u8, u8, u8)는 선언만 되어 있었다. 여기서는 같은 튜플 구조체 모양의 Rgb를 중심으로
+// 여러 색 공간과 그 사이의 변환, 문자열 파싱, 색 섞기, 명암비, 그라디언트를 만든다.
+//
+// - Rgb(r, g, b), Rgba(r, g, b, a): 0 ~ 255 정수. 화면에 쓰는 sRGB 값이다.
+// - Hsl { h, s, l }, Hsv { h, s, v }: h는 0 ~ 360도, 나머지는 0 ~ 1 실수
+// - LinearRgb { r, g, b }: sRGB의 감마를 풀어서 빛의 세기에 비례하게 만든 0 ~ 1 실수
+//
+// 실수 공간으로 바꾸는 것은 손실이 없고, 다시 Rgb로 돌아올 때는 반올림해서 원래 값과 같아진다. (테스트로 확인한다.)
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgba(pub u8, pub u8, pub u8, pub u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LinearRgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    // 0 ~ 1 실수 세 개로 바꾼다.
+    fn unit(self) -> [f64; 3] {
+        [to_unit(self.0), to_unit(self.1), to_unit(self.2)]
+    }
+
+    fn from_unit([r, g, b]: [f64; 3]) -> Rgb {
+        Rgb(from_unit(r), from_unit(g), from_unit(b))
+    }
+
+    pub fn with_alpha(self, a: u8) -> Rgba {
+        Rgba(self.0, self.1, self.2, a)
+    }
+
+    // CSS의 rgb(r, g, b) 형식 문자열
+    pub fn to_css(self) -> String {
+        format!("rgb({}, {}, {})", self.0, self.1, self.2)
+    }
+
+    // WCAG 2.x의 상대 휘도. 0(검정) ~ 1(흰색)
+    pub fn relative_luminance(self) -> f64 {
+        let LinearRgb { r, g, b } = self.into();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    // WCAG 명암비. 1 ~ 21이고, 두 색의 순서와 관계없이 같은 값이다.
+    // 본문 글자는 4.5 이상, 큰 글자는 3 이상이면 AA 기준을 만족한다.
+    pub fn contrast_ratio(self, other: Rgb) -> f64 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        let (hi, lo) = if a > b { (a, b) } else { (b, a) };
+        (hi + 0.05) / (lo + 0.05)
+    }
+}
+
+impl Rgba {
+    pub fn rgb(self) -> Rgb {
+        Rgb(self.0, self.1, self.2)
+    }
+
+    pub fn alpha(self) -> f64 {
+        to_unit(self.3)
+    }
+
+    pub fn to_css(self) -> String {
+        format!(
+            "rgba({}, {}, {}, {})",
+            self.0,
+            self.1,
+            self.2,
+            round_to(self.alpha(), 3)
+        )
+    }
+}
+
+impl From<Rgb> for Rgba {
+    fn from(c: Rgb) -> Rgba {
+        c.with_alpha(255)
+    }
+}
+
+fn to_unit(c: u8) -> f64 {
+    c as f64 / 255.0
+}
+
+fn from_unit(x: f64) -> u8 {
+    (x.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn round_to(x: f64, digits: i32) -> f64 {
+    let p = 10f64.powi(digits);
+    (x * p).round() / p
+}
+
+// ------------------------------------------------------------
+// 색 공간 변환
+
+// r, g, b(0 ~ 1)에서 hue(0 ~ 360), 최댓값, 최솟값을 구한다. HSL과 HSV가 같이 쓴다.
+fn hue_max_min([r, g, b]: [f64; 3]) -> (f64, f64, f64) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let d = max - min;
+    let h = if d == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / d)
+    } else if max == g {
+        60.0 * ((b - r) / d + 2.0)
+    } else {
+        60.0 * ((r - g) / d + 4.0)
+    };
+    (h.rem_euclid(360.0), max, min)
+}
+
+// hue와 채도(chroma)로 r, g, b를 만든 뒤 m을 더한다. HSL과 HSV가 같이 쓴다.
+fn from_hue_chroma(h: f64, c: f64, m: f64) -> [f64; 3] {
+    let hp = h.rem_euclid(360.0) / 60.0;
+    let x = c * (1.0 - (hp % 2.0 - 1.0).abs());
+    let (r, g, b) = match hp as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    [r + m, g + m, b + m]
+}
+
+impl From<Rgb> for Hsl {
+    fn from(c: Rgb) -> Hsl {
+        let (h, max, min) = hue_max_min(c.unit());
+        let l = (max + min) / 2.0;
+        let d = max - min;
+        let s = if d == 0.0 {
+            0.0
+        } else {
+            d / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Hsl { h, s, l }
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(c: Hsl) -> Rgb {
+        let s = c.s.clamp(0.0, 1.0);
+        let l = c.l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        Rgb::from_unit(from_hue_chroma(c.h, chroma, l - chroma / 2.0))
+    }
+}
+
+impl From<Rgb> for Hsv {
+    fn from(c: Rgb) -> Hsv {
+        let (h, max, min) = hue_max_min(c.unit());
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Hsv { h, s, v: max }
+    }
+}
+
+impl From<Hsv> for Rgb {
+    fn from(c: Hsv) -> Rgb {
+        let s = c.s.clamp(0.0, 1.0);
+        let v = c.v.clamp(0.0, 1.0);
+        let chroma = v * s;
+        Rgb::from_unit(from_hue_chroma(c.h, chroma, v - chroma))
+    }
+}
+
+impl From<Hsl> for Hsv {
+    fn from(c: Hsl) -> Hsv {
+        let v = c.l + c.s * c.l.min(1.0 - c.l);
+        let s = if v == 0.0 { 0.0 } else { 2.0 * (1.0 - c.l / v) };
+        Hsv { h: c.h, s, v }
+    }
+}
+
+impl From<Hsv> for Hsl {
+    fn from(c: Hsv) -> Hsl {
+        let l = c.v * (1.0 - c.s / 2.0);
+        let s = if l == 0.0 || l == 1.0 {
+            0.0
+        } else {
+            (c.v - l) / l.min(1.0 - l)
+        };
+        Hsl { h: c.h, s, l }
+    }
+}
+
+// sRGB 값(0 ~ 1)의 감마를 풀어 빛의 세기로 바꾼다.
+fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> f64 {
+    if c <= 0.003_130_8 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+impl From<Rgb> for LinearRgb {
+    fn from(c: Rgb) -> LinearRgb {
+        let [r, g, b] = c.unit().map(srgb_to_linear);
+        LinearRgb { r, g, b }
+    }
+}
+
+impl From<LinearRgb> for Rgb {
+    fn from(c: LinearRgb) -> Rgb {
+        Rgb::from_unit([c.r, c.g, c.b].map(|x| linear_to_srgb(x.clamp(0.0, 1.0))))
+    }
+}
+
+// ------------------------------------------------------------
+// 문자열 변환
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    // #, rgb(, rgba( 중 어느 것으로도 시작하지 않는 경우
+    UnknownFormat,
+    // # 뒤의 글자 수가 3, 4, 6, 8이 아닌 경우
+    InvalidLength(usize),
+    InvalidHex(String),
+    InvalidComponent(String),
+    // rgb(...)의 값 개수가 맞지 않는 경우
+    WrongComponentCount { expected: usize, found: usize },
+    // Rgb로 읽으려는데 투명도가 있는 경우
+    UnexpectedAlpha,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::UnknownFormat => write!(f, "unknown color format"),
+            ParseColorError::InvalidLength(n) => write!(f, "invalid hex length {}", n),
+            ParseColorError::InvalidHex(s) => write!(f, "invalid hex digits {:?}", s),
+            ParseColorError::InvalidComponent(s) => write!(f, "invalid component {:?}", s),
+            ParseColorError::WrongComponentCount { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+            ParseColorError::UnexpectedAlpha => write!(f, "color has an alpha channel"),
+        }
+    }
+}
+
+impl Error for ParseColorError {}
+
+// #rgb, #rgba, #rrggbb, #rrggbbaa, rgb(r, g, b), rgba(r, g, b, a)를 읽는다.
+// rgba의 a는 CSS처럼 0 ~ 1 실수다. 앞뒤 공백과 대소문자는 무시한다.
+impl FromStr for Rgba {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Rgba, ParseColorError> {
+        let s = s.trim();
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+        let lower = s.to_ascii_lowercase();
+        if let Some(body) = strip_call(&lower, "rgba") {
+            let [r, g, b, a] = components::<4>(body)?;
+            return Ok(Rgba(byte(r)?, byte(g)?, byte(b)?, alpha(a)?));
+        }
+        if let Some(body) = strip_call(&lower, "rgb") {
+            let [r, g, b] = components::<3>(body)?;
+            return Ok(Rgba(byte(r)?, byte(g)?, byte(b)?, 255));
+        }
+        Err(ParseColorError::UnknownFormat)
+    }
+}
+
+// Rgba로 읽은 뒤 불투명한 경우만 받아들인다.
+impl FromStr for Rgb {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Rgb, ParseColorError> {
+        let c: Rgba = s.parse()?;
+        if c.3 != 255 {
+            return Err(ParseColorError::UnexpectedAlpha);
+        }
+        Ok(c.rgb())
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<Rgba, ParseColorError> {
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParseColorError::InvalidHex(hex.to_string()));
+    }
+    // 검사를 통과했으므로 모두 ASCII이고, 글자 단위로 잘라도 안전하다.
+    let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).unwrap();
+    let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+    match hex.len() {
+        // #abc는 #aabbcc와 같다. 0xa * 17 = 0xaa
+        3 => Ok(Rgba(digit(0) * 17, digit(1) * 17, digit(2) * 17, 255)),
+        4 => Ok(Rgba(
+            digit(0) * 17,
+            digit(1) * 17,
+            digit(2) * 17,
+            digit(3) * 17,
+        )),
+        6 => Ok(Rgba(pair(0), pair(2), pair(4), 255)),
+        8 => Ok(Rgba(pair(0), pair(2), pair(4), pair(6))),
+        n => Err(ParseColorError::InvalidLength(n)),
+    }
+}
+
+// "rgb(1, 2, 3)"에서 "1, 2, 3"을 꺼낸다.
+fn strip_call<'a>(s: &'a str, name: &str) -> Option<&'a str> {
+    s.strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+fn components<const N: usize>(body: &str) -> Result<[&str; N], ParseColorError> {
+    let parts: Vec<&str> = body.split(',').map(str::trim).collect();
+    parts
+        .try_into()
+        .map_err(|parts: Vec<&str>| ParseColorError::WrongComponentCount {
+            expected: N,
+            found: parts.len(),
+        })
+}
+
+fn byte(s: &str) -> Result<u8, ParseColorError> {
+    s.parse()
+        .map_err(|_| ParseColorError::InvalidComponent(s.to_string()))
+}
+
+fn alpha(s: &str) -> Result<u8, ParseColorError> {
+    match s.parse::<f64>() {
+        Ok(a) if (0.0..=1.0).contains(&a) => Ok(from_unit(a)),
+        _ => Err(ParseColorError::InvalidComponent(s.to_string())),
+    }
+}
+
+// {}로 출력하면 #rrggbb 형식이다. 소문자로 쓴다.
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+impl fmt::Display for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{:02x}{:02x}{:02x}{:02x}",
+            self.0, self.1, self.2, self.3
+        )
+    }
+}
+
+// ------------------------------------------------------------
+// 색 섞기
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    // 위의 색이 그대로 덮는다.
+    Normal,
+    // 두 색을 곱한다. 항상 어두워진다. 흰색과 곱하면 그대로다.
+    Multiply,
+    // 반전해서 곱하고 다시 반전한다. 항상 밝아진다. 검정과 섞으면 그대로다.
+    Screen,
+}
+
+impl BlendMode {
+    fn apply(self, backdrop: f64, source: f64) -> f64 {
+        match self {
+            BlendMode::Normal => source,
+            BlendMode::Multiply => backdrop * source,
+            BlendMode::Screen => backdrop + source - backdrop * source,
+        }
+    }
+}
+
+// 불투명한 배경(backdrop) 위에 source를 mode로 섞는다. source의 투명도만큼만 섞인다.
+// CSS의 mix-blend-mode와 같은 식으로, sRGB 값에 대해 계산한다.
+pub fn blend(backdrop: Rgb, source: Rgba, mode: BlendMode) -> Rgb {
+    let a = source.alpha();
+    let cb = backdrop.unit();
+    let cs = source.rgb().unit();
+    Rgb::from_unit(std::array::from_fn(|i| {
+        (1.0 - a) * cb[i] + a * mode.apply(cb[i], cs[i])
+    }))
+}
+
+// ------------------------------------------------------------
+// 그라디언트
+
+// 두 색 사이를 어느 색 공간에서 보간할지
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Space {
+    // r, g, b 값을 그대로 보간한다. 가운데가 어둡게 보이는 경향이 있다.
+    Srgb,
+    // 빛의 세기로 보간한다. 물리적으로 두 빛을 섞은 것과 같다.
+    LinearRgb,
+    // 색상환을 따라 보간한다. hue는 짧은 쪽으로 돈다.
+    Hsl,
+    Hsv,
+}
+
+fn lerp_f(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+// 색상(hue)은 각도라서 350도와 10도 사이는 180도가 아니라 0도를 지나는 쪽이 가깝다.
+// 채도가 0인 회색은 hue가 의미 없으므로 상대편 hue를 쓴다.
+fn lerp_hue(h1: f64, s1: f64, h2: f64, s2: f64, t: f64) -> f64 {
+    let (h1, h2) = match (s1 == 0.0, s2 == 0.0) {
+        (true, false) => (h2, h2),
+        (false, true) => (h1, h1),
+        _ => (h1, h2),
+    };
+    let mut d = (h2 - h1).rem_euclid(360.0);
+    if d > 180.0 {
+        d -= 360.0;
+    }
+    (h1 + d * t).rem_euclid(360.0)
+}
+
+// t = 0이면 a, t = 1이면 b. t는 0 ~ 1로 잘린다.
+pub fn lerp(a: Rgb, b: Rgb, t: f64, space: Space) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    match space {
+        Space::Srgb => {
+            let (x, y) = (a.unit(), b.unit());
+            Rgb::from_unit(std::array::from_fn(|i| lerp_f(x[i], y[i], t)))
+        }
+        Space::LinearRgb => {
+            let (x, y): (LinearRgb, LinearRgb) = (a.into(), b.into());
+            LinearRgb {
+                r: lerp_f(x.r, y.r, t),
+                g: lerp_f(x.g, y.g, t),
+                b: lerp_f(x.b, y.b, t),
+            }
+            .into()
+        }
+        Space::Hsl => {
+            let (x, y): (Hsl, Hsl) = (a.into(), b.into());
+            Hsl {
+                h: lerp_hue(x.h, x.s, y.h, y.s, t),
+                s: lerp_f(x.s, y.s, t),
+                l: lerp_f(x.l, y.l, t),
+            }
+            .into()
+        }
+        Space::Hsv => {
+            let (x, y): (Hsv, Hsv) = (a.into(), b.into());
+            Hsv {
+                h: lerp_hue(x.h, x.s, y.h, y.s, t),
+                s: lerp_f(x.s, y.s, t),
+                v: lerp_f(x.v, y.v, t),
+            }
+            .into()
+        }
+    }
+}
+
+// a에서 b까지 steps개의 색. 처음과 끝은 a, b다. steps가 1이면 [a], 0이면 빈 Vec
+pub fn gradient(a: Rgb, b: Rgb, steps: usize, space: Space) -> Vec<Rgb> {
+    match steps {
+        0 => Vec::new(),
+        1 => vec![a],
+        n => (0..n)
+            .map(|i| lerp(a, b, i as f64 / (n - 1) as f64, space))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+fn close(a: f64, b: f64, eps: f64) -> bool {
+    (a - b).abs() <= eps
+}
+
+#[cfg(test)]
+fn sample_colors() -> impl Iterator<Item = Rgb> {
+    // 0, 5, 10, ..., 255 (52단계)의 모든 조합
+    let steps = || (0..=255u8).step_by(5);
+    steps().flat_map(move |r| steps().flat_map(move |g| steps().map(move |b| Rgb(r, g, b))))
+}
+
+#[test]
+fn hsl_hsv_reference_values() {
+    let red = Hsl::from(Rgb(255, 0, 0));
+    assert_eq!(
+        red,
+        Hsl {
+            h: 0.0,
+            s: 1.0,
+            l: 0.5
+        }
+    );
+    assert_eq!(
+        Hsv::from(Rgb(255, 0, 0)),
+        Hsv {
+            h: 0.0,
+            s: 1.0,
+            v: 1.0
+        }
+    );
+
+    let green = Hsl::from(Rgb(0, 255, 0));
+    assert_eq!((green.h, green.s, green.l), (120.0, 1.0, 0.5));
+
+    // navy: hsl(240, 100%, 25.1%)
+    let navy = Hsl::from(Rgb(0, 0, 128));
+    assert_eq!(navy.h, 240.0);
+    assert!(close(navy.l, 0.251, 1e-3));
+
+    // CSS 색 이름 coral = #ff7f50 = hsl(16.1, 100%, 65.7%) = hsv(16.1, 68.6%, 100%)
+    let coral = Hsl::from(Rgb(255, 127, 80));
+    assert!(close(coral.h, 16.114, 1e-3));
+    assert!(close(coral.s, 1.0, 1e-9));
+    assert!(close(coral.l, 0.657, 1e-3));
+    let coral = Hsv::from(Rgb(255, 127, 80));
+    assert!(close(coral.s, 0.686, 1e-3));
+    assert_eq!(coral.v, 1.0);
+
+    // 회색은 채도 0
+    assert_eq!(Hsl::from(Rgb(128, 128, 128)).s, 0.0);
+    assert_eq!(
+        Rgb::from(Hsl {
+            h: 300.0,
+            s: 0.0,
+            l: 1.0
+        }),
+        Rgb::WHITE
+    );
+    // hue는 360도를 넘거나 음수여도 된다.
+    assert_eq!(
+        Rgb::from(Hsv {
+            h: -120.0,
+            s: 1.0,
+            v: 1.0
+        }),
+        Rgb(0, 0, 255)
+    );
+    assert_eq!(
+        Rgb::from(Hsv {
+            h: 480.0,
+            s: 1.0,
+            v: 1.0
+        }),
+        Rgb(0, 255, 0)
+    );
+}
+
+#[test]
+fn linear_reference_values() {
+    let mid = LinearRgb::from(Rgb(128, 128, 128));
+    assert!(close(mid.r, 0.215_861, 1e-6));
+    assert_eq!(
+        LinearRgb::from(Rgb::WHITE),
+        LinearRgb {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0
+        }
+    );
+    // 선형 0.5는 sRGB로 약 188
+    assert_eq!(
+        Rgb::from(LinearRgb {
+            r: 0.5,
+            g: 0.0,
+            b: 1.0
+        }),
+        Rgb(188, 0, 255)
+    );
+}
+
+#[test]
+fn rgb_round_trips_are_lossless() {
+    for c in sample_colors() {
+        assert_eq!(Rgb::from(Hsl::from(c)), c, "hsl {:?}", c);
+        assert_eq!(Rgb::from(Hsv::from(c)), c, "hsv {:?}", c);
+        assert_eq!(Rgb::from(Hsv::from(Hsl::from(c))), c, "hsl->hsv {:?}", c);
+    }
+    // 선형 변환은 채널마다 독립이므로 256개 값만 확인하면 모든 색을 확인한 것과 같다.
+    for v in 0..=255u8 {
+        let c = Rgb(v, v, v);
+        assert_eq!(Rgb::from(LinearRgb::from(c)), c);
+    }
+}
+
+#[test]
+fn hsl_hsv_float_round_trip_error() {
+    for c in sample_colors() {
+        let hsl = Hsl::from(c);
+        let back = Hsl::from(Hsv::from(hsl));
+        assert!(close(back.l, hsl.l, 1e-12), "{:?}", c);
+        if hsl.l > 0.0 && hsl.l < 1.0 {
+            assert!(close(back.s, hsl.s, 1e-9), "{:?} {:?}", hsl, back);
+        }
+        assert_eq!(back.h, hsl.h);
+    }
+}
+
+#[test]
+fn parse_and_format() {
+    assert_eq!("#ff7f50".parse(), Ok(Rgb(255, 127, 80)));
+    assert_eq!("#FF7F50".parse(), Ok(Rgb(255, 127, 80)));
+    assert_eq!("  #abc ".parse(), Ok(Rgb(0xaa, 0xbb, 0xcc)));
+    assert_eq!("rgb(1, 2, 3)".parse(), Ok(Rgb(1, 2, 3)));
+    assert_eq!("RGB( 10,20 ,30 )".parse(), Ok(Rgb(10, 20, 30)));
+    assert_eq!("#11223344".parse(), Ok(Rgba(0x11, 0x22, 0x33, 0x44)));
+    assert_eq!("#1234".parse(), Ok(Rgba(0x11, 0x22, 0x33, 0x44)));
+    assert_eq!("rgba(0, 0, 0, 0.5)".parse(), Ok(Rgba(0, 0, 0, 128)));
+    assert_eq!("rgb(1, 2, 3)".parse(), Ok(Rgba(1, 2, 3, 255)));
+
+    assert_eq!(Rgb(255, 127, 80).to_string(), "#ff7f50");
+    assert_eq!(Rgba(1, 2, 3, 4).to_string(), "#01020304");
+    assert_eq!(Rgb(1, 2, 3).to_css(), "rgb(1, 2, 3)");
+    assert_eq!(Rgba(1, 2, 3, 128).to_css(), "rgba(1, 2, 3, 0.502)");
+
+    for c in sample_colors() {
+        assert_eq!(c.to_string().parse(), Ok(c));
+        assert_eq!(c.to_css().parse(), Ok(c));
+    }
+}
+
+#[test]
+fn parse_errors() {
+    let err = |s: &str| s.parse::<Rgb>().unwrap_err();
+    assert_eq!(err("red"), ParseColorError::UnknownFormat);
+    assert_eq!(err(""), ParseColorError::UnknownFormat);
+    assert_eq!(err("#12345"), ParseColorError::InvalidLength(5));
+    assert_eq!(err("#"), ParseColorError::InvalidLength(0));
+    assert_eq!(err("#ggg"), ParseColorError::InvalidHex("ggg".to_string()));
+    // 멀티바이트 글자가 섞여도 패닉 없이 에러
+    assert_eq!(err("#가a"), ParseColorError::InvalidHex("가a".to_string()));
+    assert_eq!(
+        err("rgb(256, 0, 0)"),
+        ParseColorError::InvalidComponent("256".to_string())
+    );
+    assert_eq!(
+        err("rgb(1, 2)"),
+        ParseColorError::WrongComponentCount {
+            expected: 3,
+            found: 2
+        }
+    );
+    assert_eq!(err("#0000"), ParseColorError::UnexpectedAlpha);
+    assert_eq!(
+        "rgba(0, 0, 0, 1.5)".parse::<Rgba>(),
+        Err(ParseColorError::InvalidComponent("1.5".to_string()))
+    );
+}
+
+#[test]
+fn blend_modes() {
+    let red = Rgb(255, 0, 0);
+    let blue = Rgb(0, 0, 255);
+    let opaque = |c: Rgb| c.with_alpha(255);
+
+    assert_eq!(blend(red, opaque(blue), BlendMode::Normal), blue);
+    assert_eq!(blend(red, opaque(blue), BlendMode::Multiply), Rgb::BLACK);
+    assert_eq!(
+        blend(red, opaque(blue), BlendMode::Screen),
+        Rgb(255, 0, 255)
+    );
+
+    // 항등원: 흰색과 multiply, 검정과 screen
+    for c in sample_colors().step_by(97) {
+        assert_eq!(blend(c, opaque(Rgb::WHITE), BlendMode::Multiply), c);
+        assert_eq!(blend(c, opaque(Rgb::BLACK), BlendMode::Screen), c);
+        // 완전히 투명하면 배경 그대로
+        assert_eq!(blend(c, Rgba(9, 9, 9, 0), BlendMode::Normal), c);
+    }
+
+    // 반투명 빨강을 흰 배경에: G, B는 255 * (1 - 128/255) = 127
+    assert_eq!(
+        blend(Rgb::WHITE, Rgba(255, 0, 0, 128), BlendMode::Normal),
+        Rgb(255, 127, 127)
+    );
+    // multiply 128 * 128 / 255 = 64.25
+    assert_eq!(
+        blend(
+            Rgb(128, 128, 128),
+            opaque(Rgb(128, 128, 128)),
+            BlendMode::Multiply
+        ),
+        Rgb(64, 64, 64)
+    );
+}
+
+#[test]
+fn wcag_contrast() {
+    assert!(close(Rgb::BLACK.contrast_ratio(Rgb::WHITE), 21.0, 1e-9));
+    assert!(close(Rgb::WHITE.contrast_ratio(Rgb::BLACK), 21.0, 1e-9));
+    assert_eq!(Rgb(12, 34, 56).contrast_ratio(Rgb(12, 34, 56)), 1.0);
+    // #777777은 흰 배경에서 4.48로 AA(4.5)를 살짝 못 넘고, #767676은 4.54로 넘는다.
+    assert!(close(
+        Rgb(0x77, 0x77, 0x77).contrast_ratio(Rgb::WHITE),
+        4.48,
+        5e-3
+    ));
+    assert!(close(
+        Rgb(0x76, 0x76, 0x76).contrast_ratio(Rgb::WHITE),
+        4.54,
+        5e-3
+    ));
+    assert!(close(Rgb(255, 0, 0).relative_luminance(), 0.2126, 1e-12));
+}
+
+#[test]
+fn gradients_by_space() {
+    let red = Rgb(255, 0, 0);
+    let green = Rgb(0, 255, 0);
+    assert_eq!(lerp(red, green, 0.5, Space::Srgb), Rgb(128, 128, 0));
+    assert_eq!(lerp(red, green, 0.5, Space::LinearRgb), Rgb(188, 188, 0));
+    // 색상환에서 빨강(0)과 초록(120)의 가운데는 노랑(60)
+    assert_eq!(lerp(red, green, 0.5, Space::Hsl), Rgb(255, 255, 0));
+    assert_eq!(lerp(red, green, 0.5, Space::Hsv), Rgb(255, 255, 0));
+
+    // hue는 짧은 쪽으로: 빨강(0)과 자홍(300)의 가운데는 330(장미색)이지 150(초록 계열)이 아니다.
+    let magenta = Rgb(255, 0, 255);
+    assert_eq!(
+        Hsl::from(lerp(red, magenta, 0.5, Space::Hsl)).h.round(),
+        330.0
+    );
+
+    // 회색과 섞을 때는 상대편의 hue를 유지한다.
+    let mid = Hsl::from(lerp(Rgb(128, 128, 128), Rgb(0, 0, 255), 0.5, Space::Hsl));
+    assert!(close(mid.h, 240.0, 1.0));
+
+    for space in [Space::Srgb, Space::LinearRgb, Space::Hsl, Space::Hsv] {
+        let g = gradient(red, green, 5, space);
+        assert_eq!(g.len(), 5);
+        assert_eq!((g[0], g[4]), (red, green), "{:?}", space);
+        assert_eq!(lerp(red, green, -1.0, space), red);
+        assert_eq!(lerp(red, green, 2.0, space), green);
+    }
+    assert!(gradient(red, green, 0, Space::Srgb).is_empty());
+    assert_eq!(gradient(red, green, 1, Space::Srgb), vec![red]);
+}
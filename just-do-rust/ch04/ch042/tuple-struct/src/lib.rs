@@ -0,0 +1,2 @@
+// main.rs의 튜플 구조체 Color(u8, u8, u8)를 실제로 쓸 수 있게 만든 색상 모듈
+pub mod color;
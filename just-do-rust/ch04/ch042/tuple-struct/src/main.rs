@@ -1,3 +1,5 @@
+use tuple_struct::color::{self, BlendMode, Hsl, Rgb, Rgba, Space};
+
 struct Color(u8, u8, u8);
 struct Point(i32, i32);
 // 만약 Color를 일반적인 구조체로 선언하면 이런 형태가 될 것이다. 이에 비해 튜플 구조체가 심플하게 사용할 수 있어 좋다.
@@ -17,6 +19,21 @@ fn main() {
     let p2 = Point(3, 4);
     let distance = cal_distance(&p1, &p2);
     assert_eq!(distance, 5.0);
+
+    // Color도 Point처럼 .0, .1, .2로 값에 접근한다.
+    // 색 공간 변환, 문자열 파싱, 색 섞기 등은 같은 모양의 튜플 구조체 Rgb로 src/color.rs에 만들었다.
+    let orange = Color(255, 128, 0);
+    let rgb = Rgb(orange.0, orange.1, orange.2);
+    println!("{} {} {:?}", rgb, rgb.to_css(), Hsl::from(rgb));
+    println!("contrast with white: {:.2}", rgb.contrast_ratio(Rgb::WHITE));
+
+    let sky: Rgb = "#87ceeb".parse().unwrap();
+    let shaded = color::blend(sky, Rgba(0, 0, 0, 64), BlendMode::Multiply);
+    println!("{} -> {}", sky, shaded);
+    for c in color::gradient(rgb, sky, 5, Space::LinearRgb) {
+        print!("{} ", c);
+    }
+    println!();
 }
 
 //cal_distance라는 함수를 만들었다. Point 타입 구조체 2개를 받아서, 두 점 사이의 거리를 계산 후 리턴한다.
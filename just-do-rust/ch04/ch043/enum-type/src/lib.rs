@@ -0,0 +1,2 @@
+// get_customer처럼 id로 고객을 찾는 일을 실제 데이터로 하는 고객 명부 모듈
+pub mod registry;
@@ -1,3 +1,5 @@
+use enum_type::registry::{self, Attribute, Customer, CustomerRegistry};
+
 // Rust에서 enum의 선언은 구조체와 유사하다. enum 키워드 다음에 이름을 적고 블록 안에 타입들을 열거한다.
 enum Gender {
     Male,
@@ -55,6 +57,27 @@ fn main() {
     // 위에서 살펴본 바와 같이, 열거형은 해당 타입 차원에서 뭔가 구분을 하고 싶을 때 사용한다.
     // Rust에서 디폴트로 제공되고, 매우 많이 사용되는 Option과 Result가 열거형이다.
     // 이 두 가지를 모르면 Rust로 된 코드를 이해하기 힘들다. 다음 페이지에서는 이 두 가지 열거형의 사용법을 소개한다.
+
+    // get_customer는 id가 짝수인지로 성별을 정했지만, 실제로는 등록된 고객 정보에서 찾아야 한다.
+    // registry 모듈의 CustomerRegistry에 고객을 넣어두고, id나 이름으로 찾아서 성별로 match한다.
+    let mut customers = CustomerRegistry::new();
+    customers
+        .insert(Customer::new(10, "Jaff", registry::Gender::Male).with(Attribute::Military(true)))
+        .unwrap();
+    customers
+        .insert(Customer::new(11, "Alice", registry::Gender::Female))
+        .unwrap();
+
+    for id in [10, 11, 12] {
+        match customers.get(id) {
+            Ok(c) => match (c.gender, c.is_military()) {
+                (registry::Gender::Male, Some(b)) => println!("name={}, Military={}", c.name, b),
+                (registry::Gender::Male, None) => println!("name={}", c.name),
+                (registry::Gender::Female, _) => println!("Female: {}", c.name),
+            },
+            Err(e) => println!("{}", e),
+        }
+    }
 }
 
 fn get_customer(id: i32) -> Gender {
@@ -0,0 +1,659 @@
+// main.rs의 get_customer, get_add_info_customer는 id가 짝수냐 홀수냐로 성별을 정하고
+// 이름도 "Jaff", "Alice"로 고정되어 있었다. 여기서는 실제로 고객을 등록하고, 찾고, 고치고, 지우는
+// 고객 명부(CustomerRegistry)를 만든다.
+//
+// GenderAddInfo처럼 열거형의 베리언트마다 필드를 다르게 두면, 정보가 하나 늘 때마다 모든 베리언트를 고쳐야 한다.
+// 그래서 성별은 Gender 열거형으로 두고, 군복무 여부 같은 부가 정보는 Attribute 열거형의 목록으로 뺐다.
+// 새로운 종류의 부가 정보는 Attribute에 베리언트를 추가하거나 Custom으로 넣으면 된다.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+impl fmt::Display for Gender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Gender::Male => write!(f, "Male"),
+            Gender::Female => write!(f, "Female"),
+        }
+    }
+}
+
+impl FromStr for Gender {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Gender, String> {
+        match s {
+            "Male" => Ok(Gender::Male),
+            "Female" => Ok(Gender::Female),
+            _ => Err(format!("unknown gender {:?}", s)),
+        }
+    }
+}
+
+// 고객의 부가 정보
+// CSV에는 key, value 두 칸으로 저장된다. (military,true / email,a@b.com / custom:memo,VIP)
+// Custom의 key에는 "custom:"을 붙여서 저장하므로, Custom { key: "email", .. }도 Email로 바뀌지 않고 그대로 읽힌다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Attribute {
+    Military(bool),
+    Email(String),
+    Phone(String),
+    Custom { key: String, value: String },
+}
+
+impl Attribute {
+    pub fn key(&self) -> &str {
+        match self {
+            Attribute::Military(_) => "military",
+            Attribute::Email(_) => "email",
+            Attribute::Phone(_) => "phone",
+            Attribute::Custom { key, .. } => key,
+        }
+    }
+
+    // CSV에 쓰는 key. Custom만 앞에 CUSTOM_PREFIX가 붙는다.
+    fn csv_key(&self) -> String {
+        match self {
+            Attribute::Custom { key, .. } => format!("{}{}", CUSTOM_PREFIX, key),
+            _ => self.key().to_string(),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            Attribute::Military(b) => b.to_string(),
+            Attribute::Email(s) | Attribute::Phone(s) => s.clone(),
+            Attribute::Custom { value, .. } => value.clone(),
+        }
+    }
+
+    // key, value 문자열로부터 Attribute를 만든다.
+    // "custom:"으로 시작하면 나머지가 key인 Custom이고, 그 밖에 알려진 key가 아니면 역시 Custom이 된다.
+    pub fn parse(key: &str, value: &str) -> Result<Attribute, String> {
+        if let Some(custom) = key.strip_prefix(CUSTOM_PREFIX) {
+            return Ok(Attribute::Custom {
+                key: custom.to_string(),
+                value: value.to_string(),
+            });
+        }
+        match key {
+            "military" => value
+                .parse::<bool>()
+                .map(Attribute::Military)
+                .map_err(|_| format!("military must be true or false, got {:?}", value)),
+            "email" => Ok(Attribute::Email(value.to_string())),
+            "phone" => Ok(Attribute::Phone(value.to_string())),
+            "" => Err("attribute key is empty".to_string()),
+            _ => Ok(Attribute::Custom {
+                key: key.to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+const CUSTOM_PREFIX: &str = "custom:";
+
+// GenderAddInfo를 대신하는 고객 레코드
+#[derive(Debug, Clone, PartialEq)]
+pub struct Customer {
+    pub id: u32,
+    pub name: String,
+    pub gender: Gender,
+    pub attributes: Vec<Attribute>,
+}
+
+impl Customer {
+    pub fn new(id: u32, name: &str, gender: Gender) -> Customer {
+        Customer {
+            id,
+            name: name.to_string(),
+            gender,
+            attributes: Vec::new(),
+        }
+    }
+
+    // 빌더처럼 이어서 부가 정보를 붙일 수 있게 self를 받아서 self를 리턴한다.
+    pub fn with(mut self, attr: Attribute) -> Customer {
+        self.attributes.push(attr);
+        self
+    }
+
+    // 군복무 정보가 없으면 None이다. (여성 고객이거나, 아직 입력하지 않은 경우)
+    pub fn is_military(&self) -> Option<bool> {
+        self.attributes.iter().find_map(|a| match a {
+            Attribute::Military(b) => Some(*b),
+            _ => None,
+        })
+    }
+
+    pub fn attribute(&self, key: &str) -> Option<&Attribute> {
+        self.attributes.iter().find(|a| a.key() == key)
+    }
+}
+
+#[derive(Debug)]
+pub enum RegistryError {
+    NotFound(u32),
+    Duplicate(u32),
+    Io(io::Error),
+    // CSV를 읽다가 형식이 잘못된 줄을 만난 경우. line은 1부터 센다.
+    Parse {
+        line: usize,
+        message: String,
+    },
+    // CSV의 한 줄을 들여오다가 위의 에러(Duplicate 등)가 난 경우. 원래 에러를 그대로 담는다.
+    AtLine {
+        line: usize,
+        error: Box<RegistryError>,
+    },
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::NotFound(id) => write!(f, "customer {} not found", id),
+            RegistryError::Duplicate(id) => write!(f, "customer {} already exists", id),
+            RegistryError::Io(e) => write!(f, "io error: {}", e),
+            RegistryError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            RegistryError::AtLine { line, error } => write!(f, "line {}: {}", line, error),
+        }
+    }
+}
+
+impl Error for RegistryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RegistryError::Io(e) => Some(e),
+            RegistryError::AtLine { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RegistryError {
+    fn from(e: io::Error) -> RegistryError {
+        RegistryError::Io(e)
+    }
+}
+
+// 고객 명부
+// id 순서대로 나열되도록 BTreeMap에 저장하고, 이름으로 빨리 찾기 위해 이름 -> id 목록의 색인을 따로 둔다.
+#[derive(Debug, Default)]
+pub struct CustomerRegistry {
+    customers: BTreeMap<u32, Customer>,
+    by_name: HashMap<String, BTreeSet<u32>>,
+}
+
+const CSV_HEADER: &str = "id,name,gender,attributes...";
+
+impl CustomerRegistry {
+    pub fn new() -> CustomerRegistry {
+        CustomerRegistry::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.customers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.customers.is_empty()
+    }
+
+    pub fn insert(&mut self, customer: Customer) -> Result<(), RegistryError> {
+        if self.customers.contains_key(&customer.id) {
+            return Err(RegistryError::Duplicate(customer.id));
+        }
+        self.index(&customer);
+        self.customers.insert(customer.id, customer);
+        Ok(())
+    }
+
+    // 같은 id의 고객 정보를 통째로 바꾸고, 바뀌기 전의 정보를 돌려준다.
+    pub fn update(&mut self, customer: Customer) -> Result<Customer, RegistryError> {
+        let old = self
+            .customers
+            .remove(&customer.id)
+            .ok_or(RegistryError::NotFound(customer.id))?;
+        self.unindex(&old);
+        self.index(&customer);
+        self.customers.insert(customer.id, customer);
+        Ok(old)
+    }
+
+    pub fn delete(&mut self, id: u32) -> Result<Customer, RegistryError> {
+        let old = self
+            .customers
+            .remove(&id)
+            .ok_or(RegistryError::NotFound(id))?;
+        self.unindex(&old);
+        Ok(old)
+    }
+
+    pub fn get(&self, id: u32) -> Result<&Customer, RegistryError> {
+        self.customers.get(&id).ok_or(RegistryError::NotFound(id))
+    }
+
+    // 동명이인이 있을 수 있기에 목록으로 돌려준다. id 순서로 정렬되어 있다.
+    pub fn find_by_name(&self, name: &str) -> Vec<&Customer> {
+        match self.by_name.get(name) {
+            Some(ids) => ids.iter().map(|id| &self.customers[id]).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Customer> {
+        self.customers.values()
+    }
+
+    pub fn filter<P: Fn(&Customer) -> bool>(&self, pred: P) -> Vec<&Customer> {
+        self.customers.values().filter(|c| pred(c)).collect()
+    }
+
+    pub fn by_gender(&self, gender: Gender) -> Vec<&Customer> {
+        self.filter(|c| c.gender == gender)
+    }
+
+    // 군복무 정보가 있는 고객 중에서, 복무 여부가 served와 같은 고객
+    pub fn by_military(&self, served: bool) -> Vec<&Customer> {
+        self.filter(|c| c.is_military() == Some(served))
+    }
+
+    fn index(&mut self, c: &Customer) {
+        self.by_name.entry(c.name.clone()).or_default().insert(c.id);
+    }
+
+    fn unindex(&mut self, c: &Customer) {
+        if let Some(ids) = self.by_name.get_mut(&c.name) {
+            ids.remove(&c.id);
+            if ids.is_empty() {
+                self.by_name.remove(&c.name);
+            }
+        }
+    }
+
+    // 한 줄에 한 고객. id,name,gender 다음에 부가 정보가 key,value 쌍으로 이어진다.
+    //   1,Jaff,Male,military,true,email,jaff@example.com
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(CSV_HEADER);
+        out.push('\n');
+        for c in self.customers.values() {
+            let mut fields = vec![c.id.to_string(), c.name.clone(), c.gender.to_string()];
+            for a in &c.attributes {
+                fields.push(a.csv_key());
+                fields.push(a.value());
+            }
+            let line: Vec<String> = fields.iter().map(|f| csv_escape(f)).collect();
+            out.push_str(&line.join(","));
+            out.push('\n');
+        }
+        out
+    }
+
+    // 따옴표 안의 줄바꿈은 필드의 일부이므로, 한 고객이 여러 줄에 걸칠 수 있다.
+    // 오류의 줄 번호는 그 고객이 시작하는 줄이다. 형식이 틀린 줄은 Parse, 이미 있는 id는 Duplicate를 담은 AtLine이다.
+    pub fn from_csv(text: &str) -> Result<CustomerRegistry, RegistryError> {
+        let mut reg = CustomerRegistry::new();
+        for (line_no, line) in csv_records(text) {
+            if line.trim().is_empty() || (line_no == 1 && line == CSV_HEADER) {
+                continue;
+            }
+            let parse_err = |message: String| RegistryError::Parse {
+                line: line_no,
+                message,
+            };
+            let fields = csv_split(&line).map_err(parse_err)?;
+            if fields.len() < 3 {
+                return Err(parse_err(format!(
+                    "expected at least 3 fields, got {}",
+                    fields.len()
+                )));
+            }
+            if fields.len() % 2 == 0 {
+                return Err(parse_err("attribute without a value".to_string()));
+            }
+            let id = fields[0]
+                .parse::<u32>()
+                .map_err(|e| parse_err(format!("bad id {:?}: {}", fields[0], e)))?;
+            let gender = fields[2].parse::<Gender>().map_err(parse_err)?;
+            let mut customer = Customer::new(id, &fields[1], gender);
+            for pair in fields[3..].chunks(2) {
+                customer
+                    .attributes
+                    .push(Attribute::parse(&pair[0], &pair[1]).map_err(parse_err)?);
+            }
+            reg.insert(customer).map_err(|e| RegistryError::AtLine {
+                line: line_no,
+                error: Box::new(e),
+            })?;
+        }
+        Ok(reg)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), RegistryError> {
+        fs::write(path, self.to_csv())?;
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<CustomerRegistry, RegistryError> {
+        let text = fs::read_to_string(path)?;
+        CustomerRegistry::from_csv(&text)
+    }
+}
+
+// 쉼표, 따옴표, 줄바꿈이 들어간 필드는 따옴표로 감싸고, 안의 따옴표는 두 번 쓴다. (RFC 4180 방식)
+// 따옴표 없는 필드는 읽을 때 앞뒤 공백을 떼므로, 앞뒤에 공백이 있는 필드도 감싼다.
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) || field.trim() != field {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// text를 고객 하나씩의 (시작 줄 번호, 레코드)로 나눈다.
+// 따옴표가 홀수 개 나왔으면 따옴표 안에서 줄이 바뀐 것이므로 다음 줄을 이어 붙인다. ("" 는 두 개라 짝이 맞는다)
+// 레코드 끝의 \n, \r\n은 떼지만 따옴표 안의 것은 그대로 둔다.
+fn csv_records(text: &str) -> Vec<(usize, String)> {
+    let mut records = Vec::new();
+    let mut cur = String::new();
+    let mut start = 1;
+    for (i, line) in text.split_inclusive('\n').enumerate() {
+        if cur.is_empty() {
+            start = i + 1;
+        }
+        cur.push_str(line);
+        if cur.matches('"').count().is_multiple_of(2) {
+            let record = std::mem::take(&mut cur);
+            let record = record.strip_suffix('\n').unwrap_or(&record);
+            let record = record.strip_suffix('\r').unwrap_or(record);
+            records.push((start, record.to_string()));
+        }
+    }
+    // 따옴표가 닫히지 않은 채로 끝났다. csv_split이 오류로 알려준다.
+    if !cur.is_empty() {
+        records.push((start, cur));
+    }
+    records
+}
+
+// 따옴표 없는 필드는 손으로 고친 파일을 위해 앞뒤 공백을 떼고, 따옴표로 감싼 필드는 그대로 둔다.
+fn csv_split(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut cur = String::new();
+    let mut chars = line.chars().peekable();
+    let mut in_quotes = false;
+    let mut was_quoted = false;
+    let finish = |cur: &mut String, was_quoted: bool| {
+        let field = std::mem::take(cur);
+        if was_quoted {
+            field
+        } else {
+            field.trim().to_string()
+        }
+    };
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cur.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => cur.push(c),
+            }
+        } else {
+            match c {
+                ',' => {
+                    fields.push(finish(&mut cur, was_quoted));
+                    was_quoted = false;
+                }
+                '"' if cur.trim().is_empty() && !was_quoted => {
+                    cur.clear();
+                    in_quotes = true;
+                    was_quoted = true;
+                }
+                '"' => return Err("unexpected quote in unquoted field".to_string()),
+                c if was_quoted && c.is_whitespace() => {}
+                _ if was_quoted => return Err("text after closing quote".to_string()),
+                _ => cur.push(c),
+            }
+        }
+    }
+    if in_quotes {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(finish(&mut cur, was_quoted));
+    Ok(fields)
+}
+
+#[cfg(test)]
+fn sample() -> CustomerRegistry {
+    let mut reg = CustomerRegistry::new();
+    reg.insert(Customer::new(10, "Jaff", Gender::Male).with(Attribute::Military(true)))
+        .unwrap();
+    reg.insert(
+        Customer::new(11, "Alice", Gender::Female)
+            .with(Attribute::Email("alice@example.com".to_string())),
+    )
+    .unwrap();
+    reg.insert(Customer::new(12, "Bob", Gender::Male).with(Attribute::Military(false)))
+        .unwrap();
+    reg.insert(Customer::new(13, "Alice", Gender::Female))
+        .unwrap();
+    reg
+}
+
+#[test]
+fn insert_get_and_duplicate() {
+    let mut reg = sample();
+    assert_eq!(reg.len(), 4);
+    assert_eq!(reg.get(10).unwrap().name, "Jaff");
+    assert!(matches!(reg.get(99), Err(RegistryError::NotFound(99))));
+    let dup = reg.insert(Customer::new(10, "Other", Gender::Female));
+    assert!(matches!(dup, Err(RegistryError::Duplicate(10))));
+    // 중복 삽입이 실패해도 기존 정보는 그대로다.
+    assert_eq!(reg.get(10).unwrap().name, "Jaff");
+}
+
+#[test]
+fn update_and_delete_keep_name_index() {
+    let mut reg = sample();
+    let old = reg
+        .update(Customer::new(13, "Carol", Gender::Female))
+        .unwrap();
+    assert_eq!(old.name, "Alice");
+    assert_eq!(reg.find_by_name("Alice").len(), 1);
+    assert_eq!(reg.find_by_name("Carol")[0].id, 13);
+
+    assert!(matches!(
+        reg.update(Customer::new(50, "Nobody", Gender::Male)),
+        Err(RegistryError::NotFound(50))
+    ));
+
+    let removed = reg.delete(11).unwrap();
+    assert_eq!(removed.name, "Alice");
+    assert!(reg.find_by_name("Alice").is_empty());
+    assert!(matches!(reg.delete(11), Err(RegistryError::NotFound(11))));
+    assert_eq!(reg.len(), 3);
+}
+
+#[test]
+fn lookup_by_name_returns_all_in_id_order() {
+    let reg = sample();
+    let ids: Vec<u32> = reg.find_by_name("Alice").iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec![11, 13]);
+    assert!(reg.find_by_name("alice").is_empty());
+}
+
+#[test]
+fn filter_by_variant_and_fields() {
+    let reg = sample();
+    let males: Vec<u32> = reg.by_gender(Gender::Male).iter().map(|c| c.id).collect();
+    assert_eq!(males, vec![10, 12]);
+    let served: Vec<u32> = reg.by_military(true).iter().map(|c| c.id).collect();
+    assert_eq!(served, vec![10]);
+    let not_served: Vec<u32> = reg.by_military(false).iter().map(|c| c.id).collect();
+    assert_eq!(not_served, vec![12]);
+    let with_email = reg.filter(|c| c.attribute("email").is_some());
+    assert_eq!(with_email.len(), 1);
+    assert_eq!(with_email[0].id, 11);
+}
+
+#[test]
+fn csv_round_trip_through_file() {
+    let mut reg = sample();
+    reg.insert(
+        Customer::new(20, "Kim, \"Junior\"", Gender::Male).with(Attribute::Custom {
+            key: "memo".to_string(),
+            value: "VIP, since 2020".to_string(),
+        }),
+    )
+    .unwrap();
+
+    let path = std::env::temp_dir().join(format!("customers-{}.csv", std::process::id()));
+    reg.save(&path).unwrap();
+    let loaded = CustomerRegistry::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.len(), reg.len());
+    for c in reg.iter() {
+        assert_eq!(loaded.get(c.id).unwrap(), c);
+    }
+    assert_eq!(loaded.to_csv(), reg.to_csv());
+}
+
+#[test]
+fn csv_multi_line_fields_and_custom_keys() {
+    let mut reg = CustomerRegistry::new();
+    reg.insert(
+        Customer::new(1, "Jaff", Gender::Male)
+            .with(Attribute::Custom {
+                key: "address".to_string(),
+                value: "Seoul\nGangnam-gu\r\n\"A\" building".to_string(),
+            })
+            .with(Attribute::Military(true)),
+    )
+    .unwrap();
+    // 기본 key와 같은 이름의 Custom은 Custom 그대로 돌아와야 한다.
+    reg.insert(
+        Customer::new(2, "Alice", Gender::Female)
+            .with(Attribute::Custom {
+                key: "email".to_string(),
+                value: "not really".to_string(),
+            })
+            .with(Attribute::Custom {
+                key: "military".to_string(),
+                value: "maybe".to_string(),
+            }),
+    )
+    .unwrap();
+    reg.insert(Customer::new(3, "Bob", Gender::Male)).unwrap();
+
+    let csv = reg.to_csv();
+    assert!(csv.contains("custom:email,not really"));
+    let loaded = CustomerRegistry::from_csv(&csv).unwrap();
+    assert_eq!(loaded.len(), 3);
+    for c in reg.iter() {
+        assert_eq!(loaded.get(c.id).unwrap(), c);
+    }
+    assert_eq!(loaded.get(2).unwrap().is_military(), None);
+
+    // 줄 번호는 여러 줄짜리 레코드 다음에도 맞아야 한다.
+    let broken = csv.replace("3,Bob,Male", "3,Bob,Robot");
+    assert!(matches!(
+        CustomerRegistry::from_csv(&broken),
+        Err(RegistryError::Parse { line: 6, .. })
+    ));
+
+    // 접두어가 없는 옛날 형식의 모르는 key도 Custom으로 읽는다.
+    let old = CustomerRegistry::from_csv("1,Jaff,Male,memo,VIP\n").unwrap();
+    assert_eq!(
+        old.get(1).unwrap().attributes,
+        [Attribute::Custom {
+            key: "memo".to_string(),
+            value: "VIP".to_string()
+        }]
+    );
+}
+
+#[test]
+fn csv_padded_fields_and_hand_edited_spaces() {
+    let mut reg = CustomerRegistry::new();
+    reg.insert(Customer::new(1, "  Jaff ", Gender::Male))
+        .unwrap();
+    let csv = reg.to_csv();
+    assert!(csv.contains("1,\"  Jaff \",Male"), "{}", csv);
+    let loaded = CustomerRegistry::from_csv(&csv).unwrap();
+    assert_eq!(loaded.get(1).unwrap().name, "  Jaff ");
+
+    // 손으로 고친 파일의 따옴표 없는 필드는 앞뒤 공백을 뗀다.
+    let hand = CustomerRegistry::from_csv(" 2 , Alice , Female , military , false \n").unwrap();
+    let alice = hand.get(2).unwrap();
+    assert_eq!(alice.name, "Alice");
+    assert_eq!(alice.is_military(), Some(false));
+    assert!(matches!(
+        CustomerRegistry::from_csv("3,\"Bob\"x,Male\n"),
+        Err(RegistryError::Parse { line: 1, .. })
+    ));
+}
+
+#[test]
+fn csv_errors_carry_line_numbers() {
+    let text = "id,name,gender,attributes...\n1,Jaff,Male\n2,Alice,Unknown\n";
+    match CustomerRegistry::from_csv(text) {
+        Err(RegistryError::Parse { line, message }) => {
+            assert_eq!(line, 3);
+            assert!(message.contains("Unknown"));
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let text = "1,Jaff,Male,military\n";
+    assert!(matches!(
+        CustomerRegistry::from_csv(text),
+        Err(RegistryError::Parse { line: 1, .. })
+    ));
+
+    let text = "1,Jaff,Male,military,maybe\n";
+    assert!(matches!(
+        CustomerRegistry::from_csv(text),
+        Err(RegistryError::Parse { line: 1, .. })
+    ));
+
+    let text = "1,Jaff,Male\n2,Alice,Female\n1,Bob,Male\n";
+    match CustomerRegistry::from_csv(text) {
+        Err(e @ RegistryError::AtLine { line: 3, .. }) => {
+            assert!(matches!(
+                e.source().and_then(|s| s.downcast_ref()),
+                Some(RegistryError::Duplicate(1))
+            ));
+            assert_eq!(e.to_string(), "line 3: customer 1 already exists");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+
+    // 닫히지 않은 따옴표는 시작한 줄로 알려준다
+    let text = "1,Jaff,Male\n2,\"Alice\n,Female\n";
+    assert!(matches!(
+        CustomerRegistry::from_csv(text),
+        Err(RegistryError::Parse { line: 2, .. })
+    ));
+
+    let missing = std::env::temp_dir().join("no-such-customers-file.csv");
+    assert!(matches!(
+        CustomerRegistry::load(missing),
+        Err(RegistryError::Io(_))
+    ));
+}
@@ -0,0 +1,24 @@
+// main.rs에서 설명만 했던 Option과, generic_enum 예제의 Result를 직접 구현한 모듈
+// std의 Option/Result와 이름이 같기에, 쓸 때는 option::option::Option처럼 경로를 붙여서 구분한다.
+pub mod option;
+pub mod result;
+
+// option.rs와 result.rs의 테스트가 같이 쓰는 입력들. 이 값들의 모든 조합에 대해 std와 결과를 비교한다.
+// 경계값(0, 최댓값, 최솟값)과 작은 짝수, 홀수, 음수를 넣었다.
+#[cfg(test)]
+pub(crate) const INTS: [i32; 7] = [0, 1, -1, 2, -7, i32::MAX, i32::MIN];
+
+#[cfg(test)]
+pub(crate) const ERRS: [u8; 4] = [0, 1, 2, u8::MAX];
+
+// None과, INTS 각각을 담은 Some
+#[cfg(test)]
+pub(crate) fn std_options() -> Vec<std::option::Option<i32>> {
+    std::iter::once(None).chain(INTS.map(Some)).collect()
+}
+
+// INTS 각각을 담은 Ok와, ERRS 각각을 담은 Err
+#[cfg(test)]
+pub(crate) fn std_results() -> Vec<std::result::Result<i32, u8>> {
+    INTS.map(Ok).into_iter().chain(ERRS.map(Err)).collect()
+}
@@ -31,6 +31,10 @@ tip
 // Option::Some Option::None처럼 사용할 수도 있고, 그냥 Some None으로 사용할 수도 있다.
 // 너무 많이 사용되기에 Rust에서는 Some과 None 타입을 별도로 만들어 뒀기 때문이다.
 
+// 위의 enum Option<T>를 실제로 동작하게 구현한 것이 src/option.rs다. (Result는 src/result.rs)
+// std의 Option과 이름이 겹치기에 MyOption이라는 별칭으로 가져와서 쓴다.
+use option::option::Option as MyOption;
+
 use std::collections::HashMap;
 fn main() {
     // HashMap에서 해당 Key에 매칭되어 있는 값을 가져오는 메서드는 get이고, 이 메서드는 결과를 Option 타입으로 리턴한다.
@@ -75,4 +79,14 @@ fn main() {
     비슷한 표현식으로 while let이 있다. 이것도 마찬가지로 "<타입>=<값>"의 형태다.
     while let Some(val) = num {...} 형태로 쓰이고, num이 Some 타입인한 계속 수행하라는 것이 된다.
     */
+
+    // 직접 구현한 Option도 std의 Option과 똑같이 쓸 수 있다.
+    // map.get이 돌려준 std의 Option을 into()로 바꾼 뒤 map, filter, unwrap_or를 이어서 호출했다.
+    let mine: MyOption<&i32> = map.get("Alice").into();
+    let grade = mine
+        .map(|p| *p)
+        .filter(|p| *p >= 90)
+        .map(|_| "A")
+        .unwrap_or("B");
+    println!("Alice {}", grade);
 }
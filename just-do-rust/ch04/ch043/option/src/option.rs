@@ -0,0 +1,336 @@
+// main.rs에서 설명을 위해 적어만 뒀던 Option을 실제로 동작하게 만든 것이다.
+// std의 Option과 이름이 같기 때문에, 이 파일 안에서 std 쪽을 가리킬 때는 StdOption이라고 쓴다.
+//
+//     enum Option<T> {
+//         Some(T),
+//         None,
+//     }
+//
+// 메서드들은 모두 match 하나로 구현된다. Option은 결국 "Some이면 이렇게, None이면 저렇게"가 전부이기 때문이다.
+
+use crate::result::Result::{self, Err, Ok};
+use std::option::Option as StdOption;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Option<T> {
+    #[default]
+    None,
+    Some(T),
+}
+
+pub use self::Option::{None, Some};
+
+impl<T> Option<T> {
+    pub fn is_some(&self) -> bool {
+        matches!(self, Some(_))
+    }
+
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    // &Option<T>를 Option<&T>로 바꾼다. 안에 든 값의 소유권을 가져가지 않고 들여다 볼 때 쓴다.
+    pub fn as_ref(&self) -> Option<&T> {
+        match self {
+            Some(v) => Some(v),
+            None => None,
+        }
+    }
+
+    pub fn as_mut(&mut self) -> Option<&mut T> {
+        match self {
+            Some(v) => Some(v),
+            None => None,
+        }
+    }
+
+    pub fn expect(self, msg: &str) -> T {
+        match self {
+            Some(v) => v,
+            None => panic!("{}", msg),
+        }
+    }
+
+    pub fn unwrap(self) -> T {
+        match self {
+            Some(v) => v,
+            None => panic!("called `Option::unwrap()` on a `None` value"),
+        }
+    }
+
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Some(v) => v,
+            None => default,
+        }
+    }
+
+    pub fn unwrap_or_else<F: FnOnce() -> T>(self, f: F) -> T {
+        match self {
+            Some(v) => v,
+            None => f(),
+        }
+    }
+
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        match self {
+            Some(v) => v,
+            None => T::default(),
+        }
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Option<U> {
+        match self {
+            Some(v) => Some(f(v)),
+            None => None,
+        }
+    }
+
+    // map과 달리 f가 Option을 리턴한다. Some(Some(x))처럼 중첩되지 않게 한 겹을 벗겨준다.
+    pub fn and_then<U, F: FnOnce(T) -> Option<U>>(self, f: F) -> Option<U> {
+        match self {
+            Some(v) => f(v),
+            None => None,
+        }
+    }
+
+    pub fn or(self, other: Option<T>) -> Option<T> {
+        match self {
+            Some(v) => Some(v),
+            None => other,
+        }
+    }
+
+    pub fn or_else<F: FnOnce() -> Option<T>>(self, f: F) -> Option<T> {
+        match self {
+            Some(v) => Some(v),
+            None => f(),
+        }
+    }
+
+    // 조건을 만족하지 않는 Some은 None으로 바꾼다.
+    pub fn filter<P: FnOnce(&T) -> bool>(self, predicate: P) -> Option<T> {
+        match self {
+            Some(v) if predicate(&v) => Some(v),
+            _ => None,
+        }
+    }
+
+    // 둘 다 Some일 때만 값을 튜플로 묶는다.
+    pub fn zip<U>(self, other: Option<U>) -> Option<(T, U)> {
+        match (self, other) {
+            (Some(a), Some(b)) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    // 값을 꺼내가고 그 자리에는 None을 남긴다. &mut self라서 소유권 없이도 값을 빼낼 수 있다.
+    pub fn take(&mut self) -> Option<T> {
+        std::mem::replace(self, None)
+    }
+
+    // 새 값을 넣고, 원래 있던 값을 돌려준다.
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        std::mem::replace(self, Some(value))
+    }
+
+    // None을 지정한 에러로 바꿔서 Result로 만든다.
+    pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        match self {
+            Some(v) => Ok(v),
+            None => Err(err),
+        }
+    }
+
+    pub fn ok_or_else<E, F: FnOnce() -> E>(self, f: F) -> Result<T, E> {
+        match self {
+            Some(v) => Ok(v),
+            None => Err(f()),
+        }
+    }
+
+    // Some이면 원소가 1개, None이면 0개인 반복자
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.as_ref(),
+        }
+    }
+}
+
+impl<T, E> Option<Result<T, E>> {
+    // Option<Result<T, E>>를 Result<Option<T>, E>로 뒤집는다.
+    // None -> Ok(None), Some(Ok(v)) -> Ok(Some(v)), Some(Err(e)) -> Err(e)
+    pub fn transpose(self) -> Result<Option<T>, E> {
+        match self {
+            None => Ok(None),
+            Some(Ok(v)) => Ok(Some(v)),
+            Some(Err(e)) => Err(e),
+        }
+    }
+}
+
+// Option::iter()가 돌려주는 반복자. Result::iter()도 같은 반복자를 쓴다.
+pub struct Iter<'a, T> {
+    pub(crate) inner: Option<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> StdOption<&'a T> {
+        self.inner.take().into()
+    }
+
+    fn size_hint(&self) -> (usize, StdOption<usize>) {
+        let n = if self.inner.is_some() { 1 } else { 0 };
+        (n, StdOption::Some(n))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a Option<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+// ?는 Try라는 트레잇에 의해 동작하는데, 이 트레잇은 아직 stable이 아니라서 직접 구현할 수 없다.
+// 대신 std의 Option과 서로 변환되게 해두면, 변환 후에 ?를 쓸 수 있다.
+//   let v = StdOption::<T>::from(my_opt)?;
+//   let my_opt: Option<_> = std_opt.into();
+impl<T> From<StdOption<T>> for Option<T> {
+    fn from(o: StdOption<T>) -> Option<T> {
+        match o {
+            StdOption::Some(v) => Some(v),
+            StdOption::None => None,
+        }
+    }
+}
+
+impl<T> From<Option<T>> for StdOption<T> {
+    fn from(o: Option<T>) -> StdOption<T> {
+        match o {
+            Some(v) => StdOption::Some(v),
+            None => StdOption::None,
+        }
+    }
+}
+
+// 아래 테스트들은 작은 입력 표(lib.rs의 INTS)의 모든 조합에 대해 std의 Option과 결과가 같은지 비교한다.
+#[cfg(test)]
+use crate::{ERRS, INTS, std_options};
+
+#[test]
+fn prop_basic_queries_match_std() {
+    for s in std_options() {
+        let m: Option<i32> = s.into();
+        for d in INTS {
+            assert_eq!(m.is_some(), s.is_some());
+            assert_eq!(m.is_none(), s.is_none());
+            assert_eq!(m.unwrap_or(d), s.unwrap_or(d));
+            let fd = || d;
+            assert_eq!(m.unwrap_or_else(fd), s.unwrap_or_else(fd));
+            assert_eq!(m.unwrap_or_default(), s.unwrap_or_default());
+            assert_eq!(m.as_ref().map(|v| *v), s.as_ref().copied().into());
+            assert_eq!(
+                m.iter().copied().collect::<Vec<_>>(),
+                s.iter().copied().collect::<Vec<_>>()
+            );
+            assert_eq!(m.iter().len(), s.iter().len());
+        }
+    }
+}
+
+#[test]
+fn prop_combinators_match_std() {
+    for (s, s2) in std_options()
+        .into_iter()
+        .flat_map(|s| std_options().into_iter().map(move |s2| (s, s2)))
+    {
+        let (m, m2): (Option<i32>, Option<i32>) = (s.into(), s2.into());
+        for k in INTS {
+            let f = |v: i32| v.wrapping_mul(3).wrapping_add(k);
+            assert_eq!(m.map(f), s.map(f).into());
+
+            let g = |v: i32| {
+                if v % 2 == 0 {
+                    StdOption::Some(v / 2)
+                } else {
+                    StdOption::None
+                }
+            };
+            assert_eq!(m.and_then(|v| g(v).into()), s.and_then(g).into());
+
+            assert_eq!(m.or(m2), s.or(s2).into());
+            let (fm2, fs2) = (|| m2, || s2);
+            assert_eq!(m.or_else(fm2), s.or_else(fs2).into());
+
+            let p = |v: &i32| *v > k;
+            assert_eq!(m.filter(p), s.filter(p).into());
+
+            assert_eq!(m.zip(m2), s.zip(s2).into());
+
+            assert_eq!(m.ok_or("none"), s.ok_or("none").into());
+            let fk = || k;
+            assert_eq!(m.ok_or_else(fk), s.ok_or_else(fk).into());
+        }
+    }
+}
+
+#[test]
+fn prop_take_replace_match_std() {
+    for start in std_options() {
+        for (take, v) in [true, false].into_iter().flat_map(|t| INTS.map(|v| (t, v))) {
+            let (mut m, mut s): (Option<i32>, StdOption<i32>) = (start.into(), start);
+            if take {
+                assert_eq!(m.take(), s.take().into());
+            } else {
+                assert_eq!(m.replace(v), s.replace(v).into());
+            }
+            assert_eq!(m, s.into());
+
+            if let (Some(a), StdOption::Some(b)) = (m.as_mut(), s.as_mut()) {
+                *a = a.wrapping_add(1);
+                *b = b.wrapping_add(1);
+            }
+            assert_eq!(m, s.into());
+        }
+    }
+}
+
+#[test]
+fn prop_transpose_matches_std() {
+    let inputs = std::iter::once(StdOption::None)
+        .chain(INTS.map(|v| StdOption::Some(std::result::Result::Ok(v))))
+        .chain(ERRS.map(|e| StdOption::Some(std::result::Result::Err(e))));
+    for s in inputs {
+        let m: Option<Result<i32, u8>> = s.map(Result::from).into();
+        let expected: Result<Option<i32>, u8> = s.transpose().map(Option::from).into();
+        assert_eq!(m.transpose(), expected);
+    }
+}
+
+#[test]
+fn question_mark_through_std_conversion() {
+    fn first_even(v: &[i32]) -> StdOption<i32> {
+        let mine: Option<&i32> = v.iter().find(|x| **x % 2 == 0).into();
+        let x = StdOption::<&i32>::from(mine)?;
+        StdOption::Some(*x * 10)
+    }
+    assert_eq!(first_even(&[1, 3, 4, 6]), StdOption::Some(40));
+    assert_eq!(first_even(&[1, 3]), StdOption::None);
+}
+
+#[test]
+#[should_panic(expected = "no value")]
+fn expect_panics_with_message() {
+    let n: Option<i32> = None;
+    n.expect("no value");
+}
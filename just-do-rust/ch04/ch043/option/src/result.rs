@@ -0,0 +1,255 @@
+// generic_enum 예제에서 설명용으로만 적어뒀던 Result를 실제로 동작하게 만든 것이다.
+//
+//     enum Result<T, E> {
+//        Ok(T),
+//        Err(E),
+//     }
+//
+// option.rs와 마찬가지로 std 쪽 Result는 StdResult라고 부른다.
+
+use crate::option::Iter;
+use crate::option::Option::{self, None, Some};
+use std::fmt;
+use std::result::Result as StdResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Result<T, E> {
+    Ok(T),
+    Err(E),
+}
+
+pub use self::Result::{Err, Ok};
+
+impl<T, E> Result<T, E> {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Ok(_))
+    }
+
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+
+    // 성공 값만 Option으로 꺼낸다. 에러는 버려진다.
+    pub fn ok(self) -> Option<T> {
+        match self {
+            Ok(v) => Some(v),
+            Err(_) => None,
+        }
+    }
+
+    pub fn err(self) -> Option<E> {
+        match self {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        }
+    }
+
+    pub fn as_ref(&self) -> Result<&T, &E> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn as_mut(&mut self) -> Result<&mut T, &mut E> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Result<U, E> {
+        match self {
+            Ok(v) => Ok(f(v)),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn map_err<G, F: FnOnce(E) -> G>(self, f: F) -> Result<T, G> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => Err(f(e)),
+        }
+    }
+
+    pub fn and_then<U, F: FnOnce(T) -> Result<U, E>>(self, f: F) -> Result<U, E> {
+        match self {
+            Ok(v) => f(v),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn or_else<G, F: FnOnce(E) -> Result<T, G>>(self, f: F) -> Result<T, G> {
+        match self {
+            Ok(v) => Ok(v),
+            Err(e) => f(e),
+        }
+    }
+
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Ok(v) => v,
+            Err(_) => default,
+        }
+    }
+
+    pub fn unwrap_or_else<F: FnOnce(E) -> T>(self, f: F) -> T {
+        match self {
+            Ok(v) => v,
+            Err(e) => f(e),
+        }
+    }
+
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        match self {
+            Ok(v) => v,
+            Err(_) => T::default(),
+        }
+    }
+
+    // Ok면 원소가 1개, Err면 0개인 반복자
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.as_ref().ok(),
+        }
+    }
+}
+
+impl<T, E: fmt::Debug> Result<T, E> {
+    pub fn expect(self, msg: &str) -> T {
+        match self {
+            Ok(v) => v,
+            Err(e) => panic!("{}: {:?}", msg, e),
+        }
+    }
+
+    pub fn unwrap(self) -> T {
+        self.expect("called `Result::unwrap()` on an `Err` value")
+    }
+}
+
+impl<T, E> Result<Option<T>, E> {
+    // Result<Option<T>, E>를 Option<Result<T, E>>로 뒤집는다.
+    // Ok(None) -> None, Ok(Some(v)) -> Some(Ok(v)), Err(e) -> Some(Err(e))
+    pub fn transpose(self) -> Option<Result<T, E>> {
+        match self {
+            Ok(None) => None,
+            Ok(Some(v)) => Some(Ok(v)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+// Option과 마찬가지로 std의 Result와 서로 변환된다. 변환 후에는 ?를 쓸 수 있다.
+impl<T, E> From<StdResult<T, E>> for Result<T, E> {
+    fn from(r: StdResult<T, E>) -> Result<T, E> {
+        match r {
+            StdResult::Ok(v) => Ok(v),
+            StdResult::Err(e) => Err(e),
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for StdResult<T, E> {
+    fn from(r: Result<T, E>) -> StdResult<T, E> {
+        match r {
+            Ok(v) => StdResult::Ok(v),
+            Err(e) => StdResult::Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::{ERRS, INTS, std_results};
+#[cfg(test)]
+use std::option::Option as StdOption;
+
+#[test]
+fn prop_basic_queries_match_std() {
+    for s in std_results() {
+        let m: Result<i32, u8> = s.into();
+        for d in INTS {
+            assert_eq!(m.is_ok(), s.is_ok());
+            assert_eq!(m.is_err(), s.is_err());
+            assert_eq!(m.ok(), s.ok().into());
+            assert_eq!(m.err(), s.err().into());
+            assert_eq!(m.unwrap_or(d), s.unwrap_or(d));
+            assert_eq!(
+                m.unwrap_or_else(|e| e as i32),
+                s.unwrap_or_else(|e| e as i32)
+            );
+            assert_eq!(m.unwrap_or_default(), s.unwrap_or_default());
+            assert_eq!(
+                m.iter().copied().collect::<Vec<_>>(),
+                s.iter().copied().collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[test]
+fn prop_combinators_match_std() {
+    for s in std_results() {
+        let m: Result<i32, u8> = s.into();
+        for k in INTS {
+            let f = |v: i32| v.wrapping_sub(k);
+            assert_eq!(m.map(f), s.map(f).into());
+
+            let g = |e: u8| e.wrapping_mul(7) as i64;
+            assert_eq!(m.map_err(g), s.map_err(g).into());
+
+            let h = |v: i32| {
+                if v > k {
+                    StdResult::Ok(v as i64)
+                } else {
+                    StdResult::Err(v.to_le_bytes()[0])
+                }
+            };
+            assert_eq!(m.and_then(|v| h(v).into()), s.and_then(h).into());
+
+            let r = |e: u8| {
+                if e.is_multiple_of(2) {
+                    StdResult::Ok(e as i32)
+                } else {
+                    StdResult::Err(format!("odd {}", e))
+                }
+            };
+            assert_eq!(m.or_else(|e| r(e).into()), s.or_else(r).into());
+        }
+    }
+}
+
+#[test]
+fn prop_transpose_matches_std() {
+    let inputs = std::iter::once(StdResult::Ok(StdOption::None))
+        .chain(INTS.map(|v| StdResult::Ok(StdOption::Some(v))))
+        .chain(ERRS.map(StdResult::Err));
+    for s in inputs {
+        let m: Result<Option<i32>, u8> = s.map(Option::from).into();
+        let expected: Option<Result<i32, u8>> = s.transpose().map(Result::from).into();
+        assert_eq!(m.transpose(), expected);
+    }
+}
+
+#[test]
+fn question_mark_through_std_conversion() {
+    fn parse_mine(s: &str) -> Result<i32, std::num::ParseIntError> {
+        s.parse::<i32>().into()
+    }
+    fn double(s: &str) -> StdResult<i32, std::num::ParseIntError> {
+        let v = StdResult::<i32, _>::from(parse_mine(s))?;
+        StdResult::Ok(v * 2)
+    }
+    assert_eq!(double("21"), StdResult::Ok(42));
+    assert!(double("x").is_err());
+}
+
+#[test]
+#[should_panic(expected = "bad input: 7")]
+fn expect_panics_with_error() {
+    let r: Result<i32, u8> = Err(7);
+    r.expect("bad input");
+}
@@ -0,0 +1,379 @@
+// Result를 다룰 때 match, ?, unwrap, expect 말고도 자주 쓰이는 패턴들이 있다.
+//
+// - retry: 일시적인 에러(네트워크, 잠긴 파일 등)는 잠시 기다렸다가 다시 시도하면 성공하기도 한다.
+// - fallback_chain: 첫 번째 방법이 실패하면 두 번째, 세 번째 방법을 차례로 시도한다.
+// - collect_errors: 여러 개의 Result를 성공한 값과 실패한 에러로 나눈다.
+// - Validated: ?는 첫 에러에서 멈추지만, 입력 검증처럼 모든 에러를 한꺼번에 보여줘야 할 때가 있다.
+
+use std::thread;
+use std::time::Duration;
+
+// 재시도 사이에 얼마나 기다릴지를 정하는 정책
+// attempt는 1부터 시작한다. 즉 첫 번째 실패 후의 대기 시간이 delay(1)이다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Backoff {
+    // 매번 같은 시간만큼 기다린다.
+    Fixed(Duration),
+    // 실패할 때마다 step씩 늘려가며 기다린다. step, 2*step, 3*step, ...
+    Linear(Duration),
+    // 실패할 때마다 두 배씩 늘려가되, max를 넘지 않게 한다. base, 2*base, 4*base, ...
+    Exponential { base: Duration, max: Duration },
+}
+
+impl Backoff {
+    pub fn delay(&self, attempt: u32) -> Duration {
+        match *self {
+            Backoff::Fixed(d) => d,
+            Backoff::Linear(step) => step.saturating_mul(attempt),
+            Backoff::Exponential { base, max } => {
+                let factor = 1u32
+                    .checked_shl(attempt.saturating_sub(1))
+                    .unwrap_or(u32::MAX);
+                base.saturating_mul(factor).min(max)
+            }
+        }
+    }
+}
+
+// 기다리는 동작을 트레잇으로 빼놓았다.
+// 실제 프로그램에서는 thread::sleep을 하고, 테스트에서는 기다린 시간만 기록하는 가짜 시계를 넣는다.
+pub trait Clock {
+    fn sleep(&mut self, d: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn sleep(&mut self, d: Duration) {
+        thread::sleep(d);
+    }
+}
+
+// f를 최대 attempts번 실행한다. 성공하면 바로 그 값을 리턴하고,
+// 모두 실패하면 마지막 에러를 리턴한다. attempts가 0이어도 최소 한 번은 실행한다.
+pub fn retry<T, E, F>(attempts: u32, backoff: Backoff, f: F) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    retry_with_clock(attempts, backoff, &mut SystemClock, f)
+}
+
+pub fn retry_with_clock<T, E, F, C>(
+    attempts: u32,
+    backoff: Backoff,
+    clock: &mut C,
+    mut f: F,
+) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+    C: Clock,
+{
+    let attempts = attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Ok(v) => return Ok(v),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(_) => {
+                clock.sleep(backoff.delay(attempt));
+                attempt += 1;
+            }
+        }
+    }
+}
+
+// 함수들을 앞에서부터 차례로 실행해서 처음으로 Ok가 나온 값을 리턴한다.
+// 모두 실패하면 각 함수의 에러를 실행한 순서대로 모아서 리턴한다.
+//
+// 클로저는 각각 타입이 달라서 배열에 바로 넣을 수 없다. fn 포인터나 Box<dyn FnOnce>로 넘기면 된다.
+//   fallback_chain([from_env as fn() -> Result<..>, from_file, from_default])
+pub fn fallback_chain<T, E, I, F>(fs: I) -> Result<T, Vec<E>>
+where
+    I: IntoIterator<Item = F>,
+    F: FnOnce() -> Result<T, E>,
+{
+    let mut errors = Vec::new();
+    for f in fs {
+        match f() {
+            Ok(v) => return Ok(v),
+            Err(e) => errors.push(e),
+        }
+    }
+    Err(errors)
+}
+
+// Result들을 성공한 값과 에러로 나눈다. 각각의 순서는 입력 순서를 유지한다.
+pub fn collect_errors<T, E, I>(results: I) -> (Vec<T>, Vec<E>)
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    let mut oks = Vec::new();
+    let mut errs = Vec::new();
+    for r in results {
+        match r {
+            Ok(v) => oks.push(v),
+            Err(e) => errs.push(e),
+        }
+    }
+    (oks, errs)
+}
+
+// Result와 비슷하지만, 에러를 하나가 아니라 Vec로 쌓아간다.
+// and, FromIterator로 여러 검증 결과를 합치면 실패한 것들의 에러가 모두 모인다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Validated<T, E> {
+    Valid(T),
+    Invalid(Vec<E>),
+}
+
+impl<T, E> Validated<T, E> {
+    pub fn invalid(e: E) -> Validated<T, E> {
+        Validated::Invalid(vec![e])
+    }
+
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Validated::Valid(_))
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Validated<U, E> {
+        match self {
+            Validated::Valid(v) => Validated::Valid(f(v)),
+            Validated::Invalid(es) => Validated::Invalid(es),
+        }
+    }
+
+    // 두 검증 결과를 합친다. 둘 다 성공이면 값을 튜플로 묶고,
+    // 하나라도 실패면 양쪽의 에러를 모두 이어 붙인다. ?와 달리 첫 에러에서 멈추지 않는다.
+    pub fn and<U>(self, other: Validated<U, E>) -> Validated<(T, U), E> {
+        match (self, other) {
+            (Validated::Valid(a), Validated::Valid(b)) => Validated::Valid((a, b)),
+            (Validated::Valid(_), Validated::Invalid(es))
+            | (Validated::Invalid(es), Validated::Valid(_)) => Validated::Invalid(es),
+            (Validated::Invalid(mut es1), Validated::Invalid(es2)) => {
+                es1.extend(es2);
+                Validated::Invalid(es1)
+            }
+        }
+    }
+
+    pub fn errors(&self) -> &[E] {
+        match self {
+            Validated::Valid(_) => &[],
+            Validated::Invalid(es) => es,
+        }
+    }
+
+    pub fn into_result(self) -> Result<T, Vec<E>> {
+        match self {
+            Validated::Valid(v) => Ok(v),
+            Validated::Invalid(es) => Err(es),
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for Validated<T, E> {
+    fn from(r: Result<T, E>) -> Validated<T, E> {
+        match r {
+            Ok(v) => Validated::Valid(v),
+            Err(e) => Validated::invalid(e),
+        }
+    }
+}
+
+// Validated들을 collect하면, 모두 성공일 때만 Valid(Vec<T>)가 되고
+// 하나라도 실패면 모든 에러를 모은 Invalid가 된다.
+impl<T, E> FromIterator<Validated<T, E>> for Validated<Vec<T>, E> {
+    fn from_iter<I: IntoIterator<Item = Validated<T, E>>>(iter: I) -> Self {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        for v in iter {
+            match v {
+                Validated::Valid(x) => values.push(x),
+                Validated::Invalid(es) => errors.extend(es),
+            }
+        }
+        if errors.is_empty() {
+            Validated::Valid(values)
+        } else {
+            Validated::Invalid(errors)
+        }
+    }
+}
+
+// 테스트용 가짜 시계. 실제로 기다리지 않고, 기다리라고 요청받은 시간만 기록한다.
+#[cfg(test)]
+#[derive(Default)]
+struct FakeClock {
+    sleeps: Vec<Duration>,
+}
+
+#[cfg(test)]
+impl Clock for FakeClock {
+    fn sleep(&mut self, d: Duration) {
+        self.sleeps.push(d);
+    }
+}
+
+#[test]
+fn backoff_delays() {
+    let ms = Duration::from_millis;
+    assert_eq!(Backoff::Fixed(ms(10)).delay(1), ms(10));
+    assert_eq!(Backoff::Fixed(ms(10)).delay(5), ms(10));
+    assert_eq!(Backoff::Linear(ms(10)).delay(1), ms(10));
+    assert_eq!(Backoff::Linear(ms(10)).delay(3), ms(30));
+
+    let exp = Backoff::Exponential {
+        base: ms(10),
+        max: ms(100),
+    };
+    let delays: Vec<_> = (1..=6).map(|a| exp.delay(a)).collect();
+    assert_eq!(
+        delays,
+        vec![ms(10), ms(20), ms(40), ms(80), ms(100), ms(100)]
+    );
+    // 시도 횟수가 아주 커도 오버플로 없이 max에서 멈춘다.
+    assert_eq!(exp.delay(1000), ms(100));
+}
+
+#[test]
+fn retry_succeeds_after_failures() {
+    let mut clock = FakeClock::default();
+    let mut calls = 0;
+    let r: Result<i32, String> = retry_with_clock(
+        5,
+        Backoff::Linear(Duration::from_millis(5)),
+        &mut clock,
+        || {
+            calls += 1;
+            if calls < 3 {
+                Err(format!("fail {}", calls))
+            } else {
+                Ok(calls)
+            }
+        },
+    );
+    assert_eq!(r, Ok(3));
+    assert_eq!(calls, 3);
+    assert_eq!(
+        clock.sleeps,
+        vec![Duration::from_millis(5), Duration::from_millis(10)]
+    );
+}
+
+#[test]
+fn retry_gives_up_with_last_error() {
+    let mut clock = FakeClock::default();
+    let mut calls = 0;
+    let r: Result<(), String> = retry_with_clock(
+        3,
+        Backoff::Exponential {
+            base: Duration::from_millis(1),
+            max: Duration::from_secs(1),
+        },
+        &mut clock,
+        || {
+            calls += 1;
+            Err(format!("fail {}", calls))
+        },
+    );
+    assert_eq!(r, Err("fail 3".to_string()));
+    // 마지막 실패 후에는 기다리지 않는다.
+    assert_eq!(
+        clock.sleeps,
+        vec![Duration::from_millis(1), Duration::from_millis(2)]
+    );
+
+    let mut calls = 0;
+    let r: Result<(), &str> =
+        retry_with_clock(0, Backoff::Fixed(Duration::ZERO), &mut clock, || {
+            calls += 1;
+            Err("no")
+        });
+    assert_eq!(r, Err("no"));
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn fallback_chain_returns_first_ok() {
+    fn from_env() -> Result<u16, String> {
+        Err("PORT not set".to_string())
+    }
+    fn from_file() -> Result<u16, String> {
+        "80a".parse::<u16>().map_err(|e| e.to_string())
+    }
+    fn from_default() -> Result<u16, String> {
+        Ok(8080)
+    }
+    fn never() -> Result<u16, String> {
+        panic!("must not be called after an Ok")
+    }
+
+    let r = fallback_chain([
+        from_env as fn() -> Result<u16, String>,
+        from_file,
+        from_default,
+        never,
+    ]);
+    assert_eq!(r, Ok(8080));
+
+    let r = fallback_chain([from_env as fn() -> Result<u16, String>, from_file]);
+    assert_eq!(
+        r,
+        Err(vec![
+            "PORT not set".to_string(),
+            "invalid digit found in string".to_string()
+        ])
+    );
+
+    let empty: Vec<fn() -> Result<u16, String>> = Vec::new();
+    assert_eq!(fallback_chain(empty), Err(vec![]));
+}
+
+#[test]
+fn collect_errors_partitions() {
+    let inputs = ["1", "x", "3", "", "5"];
+    let (nums, errs) = collect_errors(inputs.iter().map(|s| s.parse::<i32>()));
+    assert_eq!(nums, vec![1, 3, 5]);
+    assert_eq!(errs.len(), 2);
+}
+
+#[test]
+fn validated_accumulates_all_errors() {
+    fn check_name(s: &str) -> Validated<String, String> {
+        if s.is_empty() {
+            Validated::invalid("name is empty".to_string())
+        } else {
+            Validated::Valid(s.to_string())
+        }
+    }
+    fn check_age(a: i32) -> Validated<u8, String> {
+        u8::try_from(a)
+            .map_err(|_| format!("age {} out of range", a))
+            .into()
+    }
+
+    let ok = check_name("Alice").and(check_age(30));
+    assert_eq!(ok, Validated::Valid(("Alice".to_string(), 30)));
+
+    let bad = check_name("").and(check_age(-1));
+    assert!(!bad.is_valid());
+    assert_eq!(
+        bad.errors(),
+        &[
+            "name is empty".to_string(),
+            "age -1 out of range".to_string()
+        ]
+    );
+
+    let all: Validated<Vec<u8>, String> = [10, 300, 20, -5].into_iter().map(check_age).collect();
+    assert_eq!(
+        all.into_result(),
+        Err(vec![
+            "age 300 out of range".to_string(),
+            "age -5 out of range".to_string()
+        ])
+    );
+
+    let all: Validated<Vec<u8>, String> = [10, 20].into_iter().map(check_age).collect();
+    assert_eq!(all.map(|v| v.len()), Validated::Valid(2));
+}
@@ -0,0 +1,2 @@
+// Result를 리턴하는 함수들을 재시도하거나, 대체 함수로 넘어가거나, 에러를 모으는 도구 모음
+pub mod combinator;
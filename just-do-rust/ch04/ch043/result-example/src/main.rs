@@ -2,7 +2,10 @@
 // 파일을 오픈하려는데 없는 파일이라거나, 파일을 생성하려는데 생성이 안된다거나 하는 에러가 발생할 수 있는데,
 // Rust에서는 이런 에러를 처리할 수 있게 관련한 함수의 리턴값을 Result로 하고 있다.
 
+use std::time::Duration;
 use std::{fs::File, io::Write};
+
+use result_example::combinator::{Backoff, retry};
 // File 패키지에 파일 핸들링관련 메서드들이 있다. 사용하려면 use std::fs::File;를 해줘야 한다.
 // f.write_all 메서드를 쓸려면 io::Write 패키지를 use해야하기 때문
 fn main() {
@@ -38,6 +41,16 @@ fn main() {
     // 위 코드의 expect를 사용한 부분을 unwrap을 사용할 수도 있다.
     // unwrap을 쓰면 프로그래머가 지정하는 err_msg 없이, 에러를 발생시킨다.
     write_file_error_short("roo.txt").unwrap();
+
+    // retry의 사용
+    // 파일이 잠깐 잠겨 있는 경우처럼 일시적인 에러라면, 잠시 기다렸다가 다시 시도하면 성공할 수 있다.
+    // 최대 3번 시도하고, 실패할 때마다 10ms, 20ms, ... 처럼 대기 시간을 늘려간다.
+    match retry(3, Backoff::Linear(Duration::from_millis(10)), || {
+        write_file_error_short("too.txt")
+    }) {
+        Ok(()) => println!("write file success (with retry)"),
+        Err(e) => println!("write file error after retry {:?}", e),
+    }
 }
 
 fn write_file(f_name: &str) {
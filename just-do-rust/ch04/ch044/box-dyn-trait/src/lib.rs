@@ -0,0 +1,3 @@
+// main.rs의 Moving 트레잇과 이를 구현한 타입들, 그리고 이름으로 Moving 객체를 만드는 레지스트리
+pub mod moving;
+pub mod registry;
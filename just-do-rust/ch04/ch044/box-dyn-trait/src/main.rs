@@ -1,24 +1,10 @@
 // 함수의 리턴 타입에 대해 어떤 트레잇을 구현한 여러 객체가 가능하도록 하려면 Box<dyn Trait>
 // 'dynamic'이라는 의미의 dyn 키워드에서 알 수 있듯이,
 // 코드가 실행되는 런타임에 리턴되는 타입을 바인딩해서 수행하는 동적 디스패치
-struct Dog;
-struct Human;
 
-trait Moving {
-    fn run(&self);
-}
-
-impl Moving for Dog {
-    fn run(&self) {
-        println!("This dog is moving!");
-    }
-}
-
-impl Moving for Human {
-    fn run(&self) {
-        println!("This Human is moving!");
-    }
-}
+// Moving 트레잇과 Dog, Human은 src/moving.rs로 옮겼다. name, speed, move_by 메서드가 추가되었다.
+use box_dyn_trait::moving::{Dog, Human, Moving};
+use box_dyn_trait::registry::MovingRegistry;
 
 fn run(x: impl Moving) {
     //  x는 Moving을 impl한 모든 것
@@ -57,4 +43,42 @@ fn main() {
     현재 x의 타입인 Box<dyn Moving>을 받을 수는 없기 때문이다.
     따라서, x.run() 처럼 Moving 트레잇의 메서드를 직접 호출해야 한다.
      */
+
+    // find_runner는 bool로 Human과 Dog 중 하나만 고를 수 있다.
+    // MovingRegistry를 쓰면 이름으로 고를 수 있고, Cat이나 Robot처럼 새로운 종류도 실행 중에 등록할 수 있다.
+    let mut registry = MovingRegistry::with_builtins();
+    registry.register("cat", || Box::new(Cat));
+    registry.register("robot", || Box::new(Robot { speed: 3.0 }));
+
+    for kind in registry.kinds() {
+        let mover = registry.build(kind).unwrap();
+        mover.run();
+        println!(
+            "  {} takes {:?} to move 100m",
+            mover.name(),
+            mover.move_by(100.0)
+        );
+    }
+    if let Err(e) = registry.build("bird") {
+        println!("{}", e);
+    }
+}
+
+// 레지스트리 바깥에서 정의한 타입들. run, name은 기본 구현을 그대로 쓴다.
+struct Cat;
+
+impl Moving for Cat {
+    fn speed(&self) -> f64 {
+        12.0
+    }
+}
+
+struct Robot {
+    speed: f64,
+}
+
+impl Moving for Robot {
+    fn speed(&self) -> f64 {
+        self.speed
+    }
 }
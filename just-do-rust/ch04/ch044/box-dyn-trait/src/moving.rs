@@ -0,0 +1,107 @@
+// main.rs에 있던 Moving 트레잇을 라이브러리로 옮기고 메서드를 늘린 것이다.
+// run 외의 메서드는 모두 기본 구현이 있어서, 새 타입은 필요한 것만 다시 구현하면 된다.
+// 트레잇 객체(dyn Moving)로 쓸 수 있어야 하므로 제네릭 메서드나 Self를 리턴하는 메서드는 넣지 않았다.
+
+use std::time::Duration;
+
+pub trait Moving {
+    fn run(&self) {
+        println!("This {} is moving!", self.name());
+    }
+
+    // 기본값은 타입 이름에서 경로와 제네릭 인자를 뺀 것이다.
+    // (box_dyn_trait::moving::Dog -> Dog, my::Pack<my::Dog> -> Pack)
+    fn name(&self) -> &str {
+        let full = std::any::type_name::<Self>();
+        let base = full.split('<').next().unwrap_or(full);
+        base.rsplit("::").next().unwrap_or(base)
+    }
+
+    // 초당 이동 거리(m/s)
+    fn speed(&self) -> f64 {
+        1.0
+    }
+
+    // distance(m)를 이동하는 데 걸리는 시간
+    // 속도가 0 이하이거나 숫자가 아니면 영원히 도착할 수 없으므로 Duration::MAX를 돌려준다.
+    fn move_by(&self, distance: f64) -> Duration {
+        let speed = self.speed();
+        if !(speed > 0.0 && speed.is_finite()) {
+            return Duration::MAX;
+        }
+        Duration::try_from_secs_f64(distance.abs() / speed).unwrap_or(Duration::MAX)
+    }
+}
+
+pub struct Dog;
+pub struct Human;
+
+impl Moving for Dog {
+    fn run(&self) {
+        println!("This dog is moving!");
+    }
+
+    fn speed(&self) -> f64 {
+        8.0
+    }
+}
+
+impl Moving for Human {
+    fn run(&self) {
+        println!("This Human is moving!");
+    }
+
+    fn speed(&self) -> f64 {
+        1.4
+    }
+}
+
+#[test]
+fn default_name_is_type_name() {
+    assert_eq!(Dog.name(), "Dog");
+    assert_eq!(Human.name(), "Human");
+
+    struct Turtle;
+    impl Moving for Turtle {}
+    assert_eq!(Turtle.name(), "Turtle");
+    assert_eq!(Turtle.speed(), 1.0);
+
+    // 제네릭 타입은 인자를 뺀 이름이다.
+    struct Pack<T>(Vec<T>);
+    impl<T: Moving> Moving for Pack<T> {
+        // 무리는 가장 느린 것에 맞춰 움직인다.
+        fn speed(&self) -> f64 {
+            self.0
+                .iter()
+                .map(|m| m.speed())
+                .fold(f64::INFINITY, f64::min)
+        }
+    }
+    assert_eq!(Pack(vec![Dog, Dog]).name(), "Pack");
+    assert_eq!(Pack(vec![Dog]).speed(), 8.0);
+    assert_eq!(Pack::<Pack<Human>>(Vec::new()).name(), "Pack");
+}
+
+#[test]
+fn move_by_uses_speed() {
+    assert_eq!(Dog.move_by(16.0), Duration::from_secs(2));
+    assert_eq!(Human.move_by(0.0), Duration::ZERO);
+    // 뒤로 가도 걸리는 시간은 같다.
+    assert_eq!(Dog.move_by(-16.0), Duration::from_secs(2));
+
+    struct Rock;
+    impl Moving for Rock {
+        fn speed(&self) -> f64 {
+            0.0
+        }
+    }
+    assert_eq!(Rock.move_by(1.0), Duration::MAX);
+    assert_eq!(Rock.move_by(0.0), Duration::MAX);
+}
+
+#[test]
+fn works_through_trait_object() {
+    let movers: Vec<Box<dyn Moving>> = vec![Box::new(Dog), Box::new(Human)];
+    let names: Vec<&str> = movers.iter().map(|m| m.name()).collect();
+    assert_eq!(names, ["Dog", "Human"]);
+}
@@ -0,0 +1,99 @@
+// main.rs의 find_runner(is_human: bool)는 Human과 Dog 둘 중 하나만 고를 수 있다.
+// 새로운 타입(Cat, Robot 등)을 추가하려면 find_runner의 if 문을 고쳐야 한다.
+//
+// MovingRegistry는 "이름 -> Box<dyn Moving>을 만드는 클로저"를 모아둔 것이다.
+// 클로저 역시 Box<dyn Fn() -> Box<dyn Moving>>으로 저장하므로, 어떤 타입의 클로저라도 넣을 수 있다.
+// 그래서 레지스트리 코드를 고치지 않고도 실행 중에 새로운 종류를 등록할 수 있다. (동적 디스패치)
+
+use crate::moving::{Dog, Human, Moving};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+pub type Factory = Box<dyn Fn() -> Box<dyn Moving>>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    UnknownKind(String),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::UnknownKind(kind) => write!(f, "unknown kind `{}`", kind),
+        }
+    }
+}
+
+impl Error for RegistryError {}
+
+#[derive(Default)]
+pub struct MovingRegistry {
+    // 이름 순서로 목록을 보여주기 위해 BTreeMap을 쓴다.
+    factories: BTreeMap<String, Factory>,
+}
+
+impl MovingRegistry {
+    // 아무것도 등록되지 않은 레지스트리
+    pub fn new() -> MovingRegistry {
+        MovingRegistry::default()
+    }
+
+    // "human", "dog"이 등록된 레지스트리. find_runner와 같은 것을 만들 수 있다.
+    pub fn with_builtins() -> MovingRegistry {
+        let mut registry = MovingRegistry::new();
+        registry.register("human", || Box::new(Human));
+        registry.register("dog", || Box::new(Dog));
+        registry
+    }
+
+    // 같은 이름이 이미 있으면 새 클로저로 바꾸고 true를 돌려준다.
+    pub fn register<F>(&mut self, kind: impl Into<String>, factory: F) -> bool
+    where
+        F: Fn() -> Box<dyn Moving> + 'static,
+    {
+        self.factories
+            .insert(kind.into(), Box::new(factory))
+            .is_some()
+    }
+
+    pub fn unregister(&mut self, kind: &str) -> Result<(), RegistryError> {
+        self.factories
+            .remove(kind)
+            .map(|_| ())
+            .ok_or_else(|| RegistryError::UnknownKind(kind.to_string()))
+    }
+
+    pub fn contains(&self, kind: &str) -> bool {
+        self.factories.contains_key(kind)
+    }
+
+    // 등록된 이름들, 이름 순서
+    pub fn kinds(&self) -> impl Iterator<Item = &str> {
+        self.factories.keys().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.factories.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.factories.is_empty()
+    }
+
+    // 이름으로 새 객체를 만든다. 호출할 때마다 클로저가 실행되어 새 객체가 생긴다.
+    pub fn build(&self, kind: &str) -> Result<Box<dyn Moving>, RegistryError> {
+        self.factories
+            .get(kind)
+            .map(|factory| factory())
+            .ok_or_else(|| RegistryError::UnknownKind(kind.to_string()))
+    }
+}
+
+#[test]
+fn builtins_match_find_runner() {
+    let registry = MovingRegistry::with_builtins();
+    assert_eq!(registry.kinds().collect::<Vec<_>>(), ["dog", "human"]);
+    assert_eq!(registry.build("human").unwrap().name(), "Human");
+    assert_eq!(registry.build("dog").unwrap().name(), "Dog");
+}
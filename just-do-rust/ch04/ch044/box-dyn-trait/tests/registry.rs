@@ -0,0 +1,116 @@
+// MovingRegistry에 이 크레이트 바깥의 타입을 등록해 보는 테스트
+// 크레이트 밖이므로 공개된 것(pub)만 쓸 수 있다.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use box_dyn_trait::moving::Moving;
+use box_dyn_trait::registry::{MovingRegistry, RegistryError};
+
+struct Cat;
+
+impl Moving for Cat {
+    fn speed(&self) -> f64 {
+        12.0
+    }
+}
+
+struct Robot {
+    model: String,
+    speed: f64,
+}
+
+impl Moving for Robot {
+    fn name(&self) -> &str {
+        &self.model
+    }
+
+    fn speed(&self) -> f64 {
+        self.speed
+    }
+}
+
+#[test]
+fn register_custom_kinds() {
+    let mut registry = MovingRegistry::with_builtins();
+    assert!(!registry.register("cat", || Box::new(Cat)));
+
+    // 클로저가 값을 캡처할 수 있으므로, 같은 타입이라도 설정이 다른 종류를 여러 개 등록할 수 있다.
+    for (kind, speed) in [("robot-slow", 0.5), ("robot-fast", 20.0)] {
+        let model = kind.to_uppercase();
+        registry.register(kind, move || {
+            Box::new(Robot {
+                model: model.clone(),
+                speed,
+            })
+        });
+    }
+
+    assert_eq!(
+        registry.kinds().collect::<Vec<_>>(),
+        ["cat", "dog", "human", "robot-fast", "robot-slow"]
+    );
+    assert_eq!(registry.len(), 5);
+
+    let cat = registry.build("cat").unwrap();
+    assert_eq!(cat.name(), "Cat");
+    assert_eq!(cat.move_by(24.0), Duration::from_secs(2));
+
+    let robot = registry.build("robot-slow").unwrap();
+    assert_eq!(robot.name(), "ROBOT-SLOW");
+    assert_eq!(robot.move_by(1.0), Duration::from_secs(2));
+
+    // 이름마다 걸리는 시간을 비교할 수 있다.
+    let fastest = registry
+        .kinds()
+        .min_by_key(|kind| registry.build(kind).unwrap().move_by(100.0))
+        .unwrap();
+    assert_eq!(fastest, "robot-fast");
+}
+
+#[test]
+fn register_replaces_existing_kind() {
+    let mut registry = MovingRegistry::with_builtins();
+    assert!(registry.register("dog", || Box::new(Cat)));
+    assert_eq!(registry.build("dog").unwrap().name(), "Cat");
+    assert_eq!(registry.len(), 2);
+}
+
+#[test]
+fn unknown_kind_errors() {
+    let mut registry = MovingRegistry::new();
+    assert!(registry.is_empty());
+    assert_eq!(
+        registry.build("cat").err(),
+        Some(RegistryError::UnknownKind("cat".to_string()))
+    );
+    assert_eq!(
+        registry.unregister("cat"),
+        Err(RegistryError::UnknownKind("cat".to_string()))
+    );
+    assert_eq!(
+        RegistryError::UnknownKind("cat".to_string()).to_string(),
+        "unknown kind `cat`"
+    );
+
+    registry.register("cat", || Box::new(Cat));
+    assert!(registry.contains("cat"));
+    assert_eq!(registry.unregister("cat"), Ok(()));
+    assert!(registry.build("cat").is_err());
+}
+
+#[test]
+fn each_build_makes_a_new_object() {
+    let count = Rc::new(Cell::new(0));
+    let mut registry = MovingRegistry::new();
+    let c = Rc::clone(&count);
+    registry.register("cat", move || {
+        c.set(c.get() + 1);
+        Box::new(Cat)
+    });
+    for _ in 0..3 {
+        registry.build("cat").unwrap();
+    }
+    assert_eq!(count.get(), 3);
+}
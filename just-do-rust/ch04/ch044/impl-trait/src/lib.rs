@@ -0,0 +1,2 @@
+// main.rs의 Moving 트레잇과, Moving을 구현한 것들을 움직여 보는 시뮬레이션
+pub mod simulation;
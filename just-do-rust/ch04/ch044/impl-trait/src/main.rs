@@ -18,27 +18,34 @@
 // 1. 파라미터 위치에 사용되는 경우
 //
 // 1) Moving이라는 트레잇을 만든다. 이 트레잇에 run이라는 메서드를 정의한다.
+//    시뮬레이션에서도 같은 트레잇을 쓰도록 src/simulation.rs에 두었고, 거기서 name과 speed가 추가되었다.
 // 2) Human과 Dog라는 구조체를 만든다.
 // 3) Human과 Dog 구조체에 대해 각각 Moving 트레잇을 impl 한다.
 // 4) Human 구조체의 한 객체로 tom을, Dog의 한 객체로 hodu를 생성한다.
 
+use impl_trait::simulation::{Finish, Moving, World};
+
 struct Dog;
 struct Human;
 
-trait Moving {
-    fn run(&self);
-}
-
 impl Moving for Dog {
     fn run(&self) {
         println!("This dog is moving!");
     }
+
+    fn speed(&self) -> f64 {
+        8.0
+    }
 }
 
 impl Moving for Human {
     fn run(&self) {
         println!("This Human is moving!");
     }
+
+    fn speed(&self) -> f64 {
+        1.4
+    }
 }
 
 // 이제 '움직일 수 있는 모든 것'은 모두 '달리게'하는 run이라는 함수를 만들어보자.
@@ -65,6 +72,16 @@ fn run(x: impl Moving) {
 fn run_trait_bound<T: Moving>(x: T) {
     x.run();
 }
+
+// 트레잇 바운드로 시뮬레이션을 돌리는 경우. World<T>는 T 하나의 타입만 담으므로,
+// T마다 코드가 따로 만들어지고 speed() 호출도 컴파일 타임에 결정된다. (정적 디스패치)
+fn race_trait_bound<T: Moving>(runners: Vec<T>, seed: u64) -> Vec<Finish> {
+    let mut world = World::new(0.1, seed);
+    for r in runners {
+        world.spawn(r);
+    }
+    world.race(10.0, 10_000)
+}
 // impl Trait 방법: 파라미터의 타입을 해당 Trait를 구현한 객체만으로 하도록 한정하는 것.
 // 트레잇 바운드: 제네릭 타입중에서 지정된 Trait를 구현한 객체만으로 한정하는 것
 
@@ -144,6 +161,25 @@ fn main() {
 
     let w = who_moved_there();
     run(w);
+
+    // 정적 디스패치: who_moved_there()가 리턴하는 impl Moving 타입 하나로만 이루어진 World
+    let mut world = World::new(0.1, 42);
+    world.spawn(who_moved_there());
+    world.spawn(who_moved_there());
+    println!("{:?}", world.race(10.0, 10_000));
+    println!("{:?}", race_trait_bound(vec![Dog, Dog], 42));
+    run_trait_bound(Human);
+
+    // 동적 디스패치: 타입이 다른 것들을 Box<dyn Moving>으로 섞은 World
+    let mut world: World<Box<dyn Moving>> = World::new(0.1, 42);
+    world.spawn(Box::new(Human));
+    world.spawn(Box::new(Dog));
+    for f in world.race(10.0, 10_000) {
+        println!("{} {:?}", f.name, f.time);
+    }
+    for line in world.to_csv().lines().take(3) {
+        println!("{}", line);
+    }
 }
 
 /*
@@ -0,0 +1,418 @@
+// Moving을 구현한 것들을 일정한 시간 간격(dt)으로 움직여 보는 간단한 시뮬레이션
+//
+// - World<M>은 엔티티(위치, 속도, Moving 객체)들을 갖고, step()을 한 번 부를 때마다 dt초만큼 시간을 진행한다.
+// - 매 틱마다 속도는 speed()에 약간의 흔들림(jitter)을 더한 값이다. 흔들림은 시드를 받은 난수 생성기로 만들기 때문에,
+//   같은 시드면 언제 실행해도 같은 결과가 나온다.
+// - M이 Human 같은 구체 타입이면 정적 디스패치, Box<dyn Moving>이면 동적 디스패치로 동작한다.
+//   (아래에서 Box<M>에 대해서도 Moving을 구현해 두었기 때문에 둘 다 같은 World 코드를 쓴다.)
+
+use std::cmp::Ordering;
+use std::fmt::Write;
+
+// main.rs의 Moving 트레잇. 시뮬레이션에 필요한 name과 speed가 추가되었고, 둘 다 기본 구현이 있다.
+pub trait Moving {
+    fn run(&self);
+
+    // 기본값은 타입 이름에서 경로를 뺀 것이다. (impl_trait::Dog -> Dog)
+    fn name(&self) -> &str {
+        let full = std::any::type_name::<Self>();
+        full.rsplit("::").next().unwrap_or(full)
+    }
+
+    // 초당 이동 거리(m/s)
+    fn speed(&self) -> f64 {
+        1.0
+    }
+}
+
+// World<Box<dyn Moving>>에서 쓰려고 Box<dyn Moving>도 Moving이 되도록 한다.
+impl<M: Moving + ?Sized> Moving for Box<M> {
+    fn run(&self) {
+        (**self).run()
+    }
+
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn speed(&self) -> f64 {
+        (**self).speed()
+    }
+}
+
+// 흔들림을 만드는 시드 있는 난수 생성기 (선형 합동법, LCG)
+// 흔들림에만 쓰므로 이 정도 품질이면 충분하다. 밖에서 쓸 일이 없으므로 공개하지 않는다.
+#[derive(Debug, Clone)]
+struct Lcg(u64);
+
+impl Lcg {
+    // [0, 1) 구간의 실수. LCG는 아래쪽 비트의 주기가 짧으므로 위쪽 53비트를 쓴다.
+    fn next_f64(&mut self) -> f64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Entity<M> {
+    pub id: usize,
+    pub mover: M,
+    pub position: f64,
+    pub velocity: f64,
+}
+
+// 한 틱에 한 엔티티가 움직인 기록. CSV의 한 줄이 된다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRow {
+    pub tick: u64,
+    pub time: f64,
+    pub id: usize,
+    pub name: String,
+    pub position: f64,
+    pub velocity: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finish {
+    pub id: usize,
+    pub name: String,
+    // 결승선을 통과한 시각(초). max_ticks 안에 들어오지 못했으면 None
+    pub time: Option<f64>,
+}
+
+pub struct World<M> {
+    dt: f64,
+    tick: u64,
+    // speed에 곱해지는 흔들림의 크기. 0.1이면 매 틱 속도가 speed의 90% ~ 110% 사이가 된다.
+    jitter: f64,
+    rng: Lcg,
+    entities: Vec<Entity<M>>,
+    log: Vec<LogRow>,
+}
+
+impl<M: Moving> World<M> {
+    pub const DEFAULT_JITTER: f64 = 0.2;
+
+    pub fn new(dt: f64, seed: u64) -> World<M> {
+        assert!(dt > 0.0 && dt.is_finite(), "dt must be positive");
+        World {
+            dt,
+            tick: 0,
+            jitter: Self::DEFAULT_JITTER,
+            rng: Lcg(seed),
+            entities: Vec::new(),
+            log: Vec::new(),
+        }
+    }
+
+    // 0이면 흔들림 없이 항상 speed()로 움직인다. 0 ~ 1 사이로 잘린다.
+    pub fn with_jitter(mut self, jitter: f64) -> World<M> {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    // 원점에 엔티티를 추가하고 id를 돌려준다. id는 추가한 순서대로 0부터 매겨진다.
+    pub fn spawn(&mut self, mover: M) -> usize {
+        let id = self.entities.len();
+        self.entities.push(Entity {
+            id,
+            mover,
+            position: 0.0,
+            velocity: 0.0,
+        });
+        id
+    }
+
+    pub fn entities(&self) -> &[Entity<M>] {
+        &self.entities
+    }
+
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn time(&self) -> f64 {
+        self.tick as f64 * self.dt
+    }
+
+    pub fn log(&self) -> &[LogRow] {
+        &self.log
+    }
+
+    // 모든 엔티티를 한 틱만큼 움직인다.
+    pub fn step(&mut self) {
+        let ids: Vec<usize> = (0..self.entities.len()).collect();
+        self.step_only(&ids);
+    }
+
+    // 주어진 엔티티들만 한 틱만큼 움직인다. 난수는 id 순서대로 뽑으므로 결과가 항상 같다.
+    fn step_only(&mut self, ids: &[usize]) {
+        self.tick += 1;
+        let time = self.time();
+        for &id in ids {
+            let noise = 1.0 + self.jitter * (self.rng.next_f64() * 2.0 - 1.0);
+            let e = &mut self.entities[id];
+            e.velocity = e.mover.speed() * noise;
+            e.position += e.velocity * self.dt;
+            self.log.push(LogRow {
+                tick: self.tick,
+                time,
+                id,
+                name: e.mover.name().to_string(),
+                position: e.position,
+                velocity: e.velocity,
+            });
+        }
+    }
+
+    // distance까지 달리기 시합을 한다. 결승선을 넘은 엔티티는 더 이상 움직이지 않는다.
+    // 결과는 도착 시각 순서이고, 시각이 같으면 id 순서, 들어오지 못한 엔티티는 맨 뒤에 id 순서로 온다.
+    // 도착 시각은 결승선을 넘은 틱 안에서 직선으로 보간한 값이다.
+    pub fn race(&mut self, distance: f64, max_ticks: u64) -> Vec<Finish> {
+        let mut times: Vec<Option<f64>> = self
+            .entities
+            .iter()
+            .map(|e| (e.position >= distance).then_some(self.time()))
+            .collect();
+
+        let start = self.tick;
+        while self.tick - start < max_ticks {
+            let running: Vec<usize> = (0..self.entities.len())
+                .filter(|&id| times[id].is_none())
+                .collect();
+            if running.is_empty() {
+                break;
+            }
+            let before: Vec<f64> = running
+                .iter()
+                .map(|&id| self.entities[id].position)
+                .collect();
+            let t0 = self.time();
+            self.step_only(&running);
+            for (&id, prev) in running.iter().zip(before) {
+                let e = &self.entities[id];
+                if e.position >= distance {
+                    times[id] = Some(t0 + (distance - prev) / e.velocity);
+                }
+            }
+        }
+
+        let mut result: Vec<Finish> = self
+            .entities
+            .iter()
+            .map(|e| Finish {
+                id: e.id,
+                name: e.mover.name().to_string(),
+                time: times[e.id],
+            })
+            .collect();
+        result.sort_by(|a, b| match (a.time, b.time) {
+            (Some(x), Some(y)) => x.total_cmp(&y).then(a.id.cmp(&b.id)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => a.id.cmp(&b.id),
+        });
+        result
+    }
+
+    // 지금까지의 기록을 CSV로 만든다. 실수는 소수점 아래 3자리까지 쓴다.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("tick,time,id,name,position,velocity\n");
+        for row in &self.log {
+            writeln!(
+                out,
+                "{},{:.3},{},{},{:.3},{:.3}",
+                row.tick,
+                row.time,
+                row.id,
+                csv_escape(&row.name),
+                row.position,
+                row.velocity
+            )
+            .unwrap();
+        }
+        out
+    }
+}
+
+fn csv_escape(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+struct Runner {
+    name: &'static str,
+    speed: f64,
+}
+
+#[cfg(test)]
+impl Moving for Runner {
+    fn run(&self) {}
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn speed(&self) -> f64 {
+        self.speed
+    }
+}
+
+#[cfg(test)]
+fn mixed_race(seed: u64) -> (Vec<Finish>, String) {
+    let mut world: World<Box<dyn Moving>> = World::new(0.1, seed);
+    world.spawn(Box::new(Runner {
+        name: "Dog",
+        speed: 8.0,
+    }));
+    world.spawn(Box::new(Runner {
+        name: "Human",
+        speed: 1.4,
+    }));
+    world.spawn(Box::new(Runner {
+        name: "Cheetah",
+        speed: 30.0,
+    }));
+    world.spawn(Box::new(Runner {
+        name: "Greyhound",
+        speed: 8.0,
+    }));
+    let finish = world.race(50.0, 10_000);
+    (finish, world.to_csv())
+}
+
+#[test]
+fn same_seed_same_result() {
+    for seed in [0, 1, 42, 2024] {
+        assert_eq!(mixed_race(seed), mixed_race(seed));
+    }
+    // 시드가 다르면 기록이 달라진다.
+    assert_ne!(mixed_race(1).1, mixed_race(2).1);
+}
+
+#[test]
+fn race_order_follows_speed() {
+    let (finish, _) = mixed_race(7);
+    let names: Vec<&str> = finish.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names[0], "Cheetah");
+    assert_eq!(names[3], "Human");
+    assert!(finish.iter().all(|f| f.time.is_some()));
+    assert!(finish.windows(2).all(|w| w[0].time <= w[1].time));
+}
+
+#[test]
+fn no_jitter_gives_exact_times() {
+    let mut world = World::new(0.1, 1).with_jitter(0.0);
+    world.spawn(Runner {
+        name: "a",
+        speed: 10.0,
+    });
+    world.spawn(Runner {
+        name: "b",
+        speed: 4.0,
+    });
+    let finish = world.race(25.0, 1_000);
+    // a는 2.5초, b는 6.25초. 6.25초는 틱(0.1초) 중간이므로 보간된 값이다.
+    assert_eq!(finish[0].name, "a");
+    assert!((finish[0].time.unwrap() - 2.5).abs() < 1e-9);
+    assert_eq!(finish[1].name, "b");
+    assert!((finish[1].time.unwrap() - 6.25).abs() < 1e-9);
+}
+
+#[test]
+fn ties_break_by_id_and_non_finishers_go_last() {
+    let mut world = World::new(0.5, 3).with_jitter(0.0);
+    world.spawn(Runner {
+        name: "still",
+        speed: 0.0,
+    });
+    world.spawn(Runner {
+        name: "x",
+        speed: 2.0,
+    });
+    world.spawn(Runner {
+        name: "y",
+        speed: 2.0,
+    });
+    let finish = world.race(4.0, 100);
+    let ids: Vec<usize> = finish.iter().map(|f| f.id).collect();
+    assert_eq!(ids, [1, 2, 0]);
+    assert_eq!(finish[2].time, None);
+    assert_eq!(world.tick(), 100);
+}
+
+#[test]
+fn static_and_dynamic_dispatch_agree() {
+    let human = || Runner {
+        name: "Human",
+        speed: 1.4,
+    };
+    let mut fixed: World<Runner> = World::new(0.25, 99);
+    fixed.spawn(human());
+    fixed.spawn(human());
+    let mut boxed: World<Box<dyn Moving>> = World::new(0.25, 99);
+    boxed.spawn(Box::new(human()));
+    boxed.spawn(Box::new(human()));
+    assert_eq!(fixed.race(10.0, 1_000), boxed.race(10.0, 1_000));
+    assert_eq!(fixed.to_csv(), boxed.to_csv());
+}
+
+#[test]
+fn csv_export() {
+    let mut world = World::new(1.0, 5).with_jitter(0.0);
+    world.spawn(Runner {
+        name: "Kim, Jr.",
+        speed: 1.5,
+    });
+    world.spawn(Runner {
+        name: "강아지",
+        speed: 2.0,
+    });
+    world.step();
+    world.step();
+    assert_eq!(
+        world.to_csv(),
+        "tick,time,id,name,position,velocity\n\
+         1,1.000,0,\"Kim, Jr.\",1.500,1.500\n\
+         1,1.000,1,강아지,2.000,2.000\n\
+         2,2.000,0,\"Kim, Jr.\",3.000,1.500\n\
+         2,2.000,1,강아지,4.000,2.000\n"
+    );
+}
+
+#[test]
+fn rng_is_deterministic_and_in_range() {
+    let mut a = Lcg(42);
+    let mut b = Lcg(42);
+    for _ in 0..1000 {
+        let x = a.next_f64();
+        assert_eq!(x, b.next_f64());
+        assert!((0.0..1.0).contains(&x));
+    }
+}
+
+#[test]
+fn boxed_delegates_to_inner() {
+    let dog: Box<dyn Moving> = Box::new(Runner {
+        name: "Dog",
+        speed: 8.0,
+    });
+    assert_eq!(dog.name(), "Dog");
+    assert_eq!(dog.speed(), 8.0);
+
+    struct Snail;
+    impl Moving for Snail {
+        fn run(&self) {}
+    }
+    // 기본 구현도 Box를 거쳐서 안쪽 타입의 것이 불린다.
+    let snail: Box<dyn Moving> = Box::new(Snail);
+    assert_eq!(snail.name(), "Snail");
+    assert_eq!(snail.speed(), 1.0);
+}
@@ -0,0 +1,585 @@
+// main.rs의 Car 트레잇은 drive()로 문자열만 출력한다. 여기서는 차량마다 적재량, 연비 같은 고정된 값(상수)과
+// 주행 거리, 남은 연료 같은 상태를 갖게 하고, 여러 대를 Fleet으로 묶어서 관리한다.
+//
+// ExTrait의 CONST_DEFAULT, CONST_NO_DEFAULT처럼 차종마다 다른 값은 트레잇의 연관 상수로 정의한다.
+// 그런데 연관 상수가 있는 트레잇은 dyn Trait(트레잇 객체)로 쓸 수 없다.
+// 상수는 인스턴스가 아니라 타입에 붙어 있어서 vtable에 넣을 수 없기 때문이다.
+// 그래서 트레잇을 두 개로 나눴다.
+//
+// - CarSpec: 차종(Truck, SUV, Sedan)의 연관 상수. 컴파일 타임에 정해지는 값들
+// - Car: 실제 차량이 갖는 메서드. 상수 없이 메서드만 있으므로 Box<dyn Car>로 쓸 수 있다.
+//
+// Vehicle<S: CarSpec>이 둘을 이어준다. Vehicle<Truck>의 capacity_kg()는 Truck::CAPACITY_KG를 돌려주는 식이다.
+
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write;
+use std::marker::PhantomData;
+
+pub trait CarSpec {
+    const KIND: &'static str;
+    const CAPACITY_KG: u32;
+    const KM_PER_LITER: f64;
+    const TANK_LITERS: f64;
+    // 정기 점검 주기. 값을 정하지 않은 차종은 이 기본값을 쓴다.
+    const SERVICE_INTERVAL_KM: f64 = 10_000.0;
+}
+
+pub struct Truck;
+pub struct SUV;
+pub struct Sedan;
+
+impl CarSpec for Truck {
+    const KIND: &'static str = "Truck";
+    const CAPACITY_KG: u32 = 10_000;
+    const KM_PER_LITER: f64 = 4.0;
+    const TANK_LITERS: f64 = 200.0;
+    const SERVICE_INTERVAL_KM: f64 = 20_000.0;
+}
+
+impl CarSpec for SUV {
+    const KIND: &'static str = "SUV";
+    const CAPACITY_KG: u32 = 700;
+    const KM_PER_LITER: f64 = 9.0;
+    const TANK_LITERS: f64 = 70.0;
+}
+
+impl CarSpec for Sedan {
+    const KIND: &'static str = "Sedan";
+    const CAPACITY_KG: u32 = 400;
+    const KM_PER_LITER: f64 = 14.0;
+    const TANK_LITERS: f64 = 50.0;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trip {
+    pub distance_km: f64,
+    pub load_kg: u32,
+    pub fuel_liters: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FleetError {
+    // 거리가 0 이하이거나 숫자가 아닌 경우
+    InvalidDistance(f64),
+    OverCapacity { capacity_kg: u32, load_kg: u32 },
+    NotEnoughFuel { needed: f64, available: f64 },
+    // 적재량과 거리(가득 채운 연료로 갈 수 있는 거리)를 모두 만족하는 차량이 없는 경우
+    NoSuitableVehicle { distance_km: f64, load_kg: u32 },
+    UnknownVehicle(usize),
+}
+
+impl fmt::Display for FleetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FleetError::InvalidDistance(d) => write!(f, "invalid distance {}", d),
+            FleetError::OverCapacity {
+                capacity_kg,
+                load_kg,
+            } => write!(f, "load {}kg exceeds capacity {}kg", load_kg, capacity_kg),
+            FleetError::NotEnoughFuel { needed, available } => write!(
+                f,
+                "not enough fuel: need {:.1}L, have {:.1}L",
+                needed, available
+            ),
+            FleetError::NoSuitableVehicle {
+                distance_km,
+                load_kg,
+            } => write!(
+                f,
+                "no vehicle can carry {}kg for {}km",
+                load_kg, distance_km
+            ),
+            FleetError::UnknownVehicle(index) => write!(f, "no vehicle at index {}", index),
+        }
+    }
+}
+
+impl Error for FleetError {}
+
+pub trait Car {
+    fn drive(&self) {
+        println!("{} drive", self.kind());
+    }
+
+    fn id(&self) -> &str;
+    fn kind(&self) -> &'static str;
+    fn capacity_kg(&self) -> u32;
+    fn km_per_liter(&self) -> f64;
+    fn tank_liters(&self) -> f64;
+    fn service_interval_km(&self) -> f64;
+
+    fn odometer_km(&self) -> f64;
+    fn fuel_liters(&self) -> f64;
+    fn last_service_km(&self) -> f64;
+    fn trips(&self) -> &[Trip];
+
+    // 연료를 쓰고 주행 거리를 늘린다. 실패하면 상태는 그대로다.
+    fn take_trip(&mut self, distance_km: f64, load_kg: u32) -> Result<&Trip, FleetError>;
+    // 연료를 가득 채우고, 넣은 양을 돌려준다.
+    fn refuel(&mut self) -> f64;
+    fn service(&mut self);
+
+    fn fuel_needed(&self, distance_km: f64) -> f64 {
+        distance_km / self.km_per_liter()
+    }
+
+    // 연료를 가득 채웠을 때 갈 수 있는 거리
+    fn range_km(&self) -> f64 {
+        self.tank_liters() * self.km_per_liter()
+    }
+
+    // 다음 점검까지 남은 거리. 음수면 점검 시기가 지났다.
+    fn km_until_service(&self) -> f64 {
+        self.last_service_km() + self.service_interval_km() - self.odometer_km()
+    }
+}
+
+pub struct Vehicle<S> {
+    id: String,
+    odometer_km: f64,
+    fuel_liters: f64,
+    last_service_km: f64,
+    trips: Vec<Trip>,
+    // S는 값으로 갖지 않고 상수만 쓰므로 PhantomData로 표시만 해둔다.
+    spec: PhantomData<S>,
+}
+
+impl<S: CarSpec> Vehicle<S> {
+    // 연료를 가득 채운 새 차량
+    pub fn new(id: impl Into<String>) -> Vehicle<S> {
+        Vehicle {
+            id: id.into(),
+            odometer_km: 0.0,
+            fuel_liters: S::TANK_LITERS,
+            last_service_km: 0.0,
+            trips: Vec::new(),
+            spec: PhantomData,
+        }
+    }
+
+    // Box<dyn Car>로 만들어서 Fleet에 바로 넣을 수 있게 한다.
+    pub fn boxed(id: impl Into<String>) -> Box<dyn Car>
+    where
+        S: 'static,
+    {
+        Box::new(Vehicle::<S>::new(id))
+    }
+}
+
+impl<S: CarSpec> Car for Vehicle<S> {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn kind(&self) -> &'static str {
+        S::KIND
+    }
+
+    fn capacity_kg(&self) -> u32 {
+        S::CAPACITY_KG
+    }
+
+    fn km_per_liter(&self) -> f64 {
+        S::KM_PER_LITER
+    }
+
+    fn tank_liters(&self) -> f64 {
+        S::TANK_LITERS
+    }
+
+    fn service_interval_km(&self) -> f64 {
+        S::SERVICE_INTERVAL_KM
+    }
+
+    fn odometer_km(&self) -> f64 {
+        self.odometer_km
+    }
+
+    fn fuel_liters(&self) -> f64 {
+        self.fuel_liters
+    }
+
+    fn last_service_km(&self) -> f64 {
+        self.last_service_km
+    }
+
+    fn trips(&self) -> &[Trip] {
+        &self.trips
+    }
+
+    fn take_trip(&mut self, distance_km: f64, load_kg: u32) -> Result<&Trip, FleetError> {
+        check_distance(distance_km)?;
+        if load_kg > S::CAPACITY_KG {
+            return Err(FleetError::OverCapacity {
+                capacity_kg: S::CAPACITY_KG,
+                load_kg,
+            });
+        }
+        let needed = self.fuel_needed(distance_km);
+        if needed > self.fuel_liters {
+            return Err(FleetError::NotEnoughFuel {
+                needed,
+                available: self.fuel_liters,
+            });
+        }
+        self.fuel_liters -= needed;
+        self.odometer_km += distance_km;
+        self.trips.push(Trip {
+            distance_km,
+            load_kg,
+            fuel_liters: needed,
+        });
+        Ok(self.trips.last().unwrap())
+    }
+
+    fn refuel(&mut self) -> f64 {
+        let added = S::TANK_LITERS - self.fuel_liters;
+        self.fuel_liters = S::TANK_LITERS;
+        added
+    }
+
+    fn service(&mut self) {
+        self.last_service_km = self.odometer_km;
+    }
+}
+
+fn check_distance(distance_km: f64) -> Result<(), FleetError> {
+    if distance_km > 0.0 && distance_km.is_finite() {
+        Ok(())
+    } else {
+        Err(FleetError::InvalidDistance(distance_km))
+    }
+}
+
+// 점검 일정의 한 줄
+#[derive(Debug, Clone, PartialEq)]
+pub struct Maintenance {
+    pub index: usize,
+    pub id: String,
+    pub km_until_service: f64,
+}
+
+// 차량 한 대의 이용 현황
+#[derive(Debug, Clone, PartialEq)]
+pub struct Utilization {
+    pub index: usize,
+    pub id: String,
+    pub trips: usize,
+    pub distance_km: f64,
+    // 전체 차량의 주행 거리 중 이 차량이 차지하는 비율 (0 ~ 1)
+    pub distance_share: f64,
+    // 운행할 때 적재량 대비 실제 짐의 평균 비율 (0 ~ 1). 운행한 적이 없으면 0
+    pub load_factor: f64,
+}
+
+#[derive(Default)]
+pub struct Fleet {
+    cars: Vec<Box<dyn Car>>,
+}
+
+impl Fleet {
+    pub fn new() -> Fleet {
+        Fleet::default()
+    }
+
+    // 차량을 추가하고 인덱스를 돌려준다.
+    pub fn add(&mut self, car: Box<dyn Car>) -> usize {
+        self.cars.push(car);
+        self.cars.len() - 1
+    }
+
+    pub fn get(&self, index: usize) -> Option<&dyn Car> {
+        self.cars.get(index).map(|c| c.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.cars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cars.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Car> {
+        self.cars.iter().map(|c| c.as_ref())
+    }
+
+    // 거리와 짐을 감당할 수 있는 차량 중 연료를 가장 적게 쓰는 차량의 인덱스
+    // 지금 남은 연료가 아니라 가득 채웠을 때 갈 수 있는 거리로 판단한다. (모자라면 dispatch에서 채운다.)
+    // 연료 사용량이 같으면 먼저 추가된 차량을 고른다.
+    pub fn cheapest_for(&self, distance_km: f64, load_kg: u32) -> Result<usize, FleetError> {
+        check_distance(distance_km)?;
+        self.cars
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| load_kg <= c.capacity_kg() && distance_km <= c.range_km())
+            .min_by(|(i, a), (j, b)| {
+                a.fuel_needed(distance_km)
+                    .total_cmp(&b.fuel_needed(distance_km))
+                    .then(i.cmp(j))
+            })
+            .map(|(i, _)| i)
+            .ok_or(FleetError::NoSuitableVehicle {
+                distance_km,
+                load_kg,
+            })
+    }
+
+    // 가장 싼 차량을 골라서 운행한다. 연료가 모자라면 먼저 가득 채운다.
+    pub fn dispatch(&mut self, distance_km: f64, load_kg: u32) -> Result<usize, FleetError> {
+        let index = self.cheapest_for(distance_km, load_kg)?;
+        let car = &mut self.cars[index];
+        if car.fuel_needed(distance_km) > car.fuel_liters() {
+            car.refuel();
+        }
+        car.take_trip(distance_km, load_kg)?;
+        Ok(index)
+    }
+
+    pub fn service(&mut self, index: usize) -> Result<(), FleetError> {
+        self.cars
+            .get_mut(index)
+            .map(|c| c.service())
+            .ok_or(FleetError::UnknownVehicle(index))
+    }
+
+    // 앞으로 within_km 안에 점검이 필요한 차량들. 급한 순서(남은 거리가 적은 순서)로 정렬된다.
+    // 이미 점검 시기가 지난 차량은 남은 거리가 음수로 맨 앞에 온다.
+    pub fn maintenance_schedule(&self, within_km: f64) -> Vec<Maintenance> {
+        let mut due: Vec<Maintenance> = self
+            .cars
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.km_until_service() <= within_km)
+            .map(|(index, c)| Maintenance {
+                index,
+                id: c.id().to_string(),
+                km_until_service: c.km_until_service(),
+            })
+            .collect();
+        due.sort_by(|a, b| {
+            a.km_until_service
+                .total_cmp(&b.km_until_service)
+                .then(a.index.cmp(&b.index))
+        });
+        due
+    }
+
+    pub fn utilization(&self) -> Vec<Utilization> {
+        let total: f64 = self.cars.iter().map(|c| total_distance(c.trips())).sum();
+        self.cars
+            .iter()
+            .enumerate()
+            .map(|(index, c)| {
+                let trips = c.trips();
+                let distance_km = total_distance(trips);
+                let load_factor = if trips.is_empty() {
+                    0.0
+                } else {
+                    trips
+                        .iter()
+                        .map(|t| t.load_kg as f64 / c.capacity_kg() as f64)
+                        .sum::<f64>()
+                        / trips.len() as f64
+                };
+                Utilization {
+                    index,
+                    id: c.id().to_string(),
+                    trips: trips.len(),
+                    distance_km,
+                    distance_share: if total > 0.0 {
+                        distance_km / total
+                    } else {
+                        0.0
+                    },
+                    load_factor,
+                }
+            })
+            .collect()
+    }
+
+    // utilization을 표 형태의 문자열로 만든다.
+    pub fn utilization_report(&self) -> String {
+        let mut out = String::from("id       kind   trips   distance  share  load\n");
+        for (u, c) in self.utilization().iter().zip(&self.cars) {
+            writeln!(
+                out,
+                "{:<8} {:<6} {:>5} {:>10.1} {:>5.1}% {:>4.0}%",
+                u.id,
+                c.kind(),
+                u.trips,
+                u.distance_km,
+                u.distance_share * 100.0,
+                u.load_factor * 100.0
+            )
+            .unwrap();
+        }
+        out
+    }
+}
+
+fn total_distance(trips: &[Trip]) -> f64 {
+    trips.iter().map(|t| t.distance_km).sum()
+}
+
+#[cfg(test)]
+fn sample_fleet() -> Fleet {
+    let mut fleet = Fleet::new();
+    fleet.add(Vehicle::<Truck>::boxed("T-1"));
+    fleet.add(Vehicle::<SUV>::boxed("S-1"));
+    fleet.add(Vehicle::<Sedan>::boxed("C-1"));
+    fleet.add(Vehicle::<Sedan>::boxed("C-2"));
+    fleet
+}
+
+#[test]
+fn associated_consts_flow_into_trait_objects() {
+    assert_eq!(Truck::SERVICE_INTERVAL_KM, 20_000.0);
+    // 값을 정하지 않은 차종은 트레잇의 기본값
+    assert_eq!(Sedan::SERVICE_INTERVAL_KM, 10_000.0);
+
+    let fleet = sample_fleet();
+    let truck = fleet.get(0).unwrap();
+    assert_eq!(truck.kind(), "Truck");
+    assert_eq!(truck.capacity_kg(), Truck::CAPACITY_KG);
+    assert_eq!(truck.fuel_liters(), Truck::TANK_LITERS);
+    assert_eq!(truck.range_km(), 800.0);
+    assert_eq!(fleet.get(2).unwrap().range_km(), 700.0);
+}
+
+#[test]
+fn trip_updates_odometer_and_fuel() {
+    let mut car = Vehicle::<Sedan>::new("C-1");
+    let trip = car.take_trip(140.0, 300).unwrap().clone();
+    assert_eq!(trip.fuel_liters, 10.0);
+    assert_eq!(car.odometer_km(), 140.0);
+    assert_eq!(car.fuel_liters(), 40.0);
+    assert_eq!(car.refuel(), 10.0);
+    assert_eq!(car.fuel_liters(), 50.0);
+}
+
+#[test]
+fn failed_trip_leaves_state_unchanged() {
+    let mut car = Vehicle::<Sedan>::new("C-1");
+    assert_eq!(
+        car.take_trip(10.0, 401),
+        Err(FleetError::OverCapacity {
+            capacity_kg: 400,
+            load_kg: 401
+        })
+    );
+    assert_eq!(
+        car.take_trip(701.0, 0),
+        Err(FleetError::NotEnoughFuel {
+            needed: 701.0 / 14.0,
+            available: 50.0
+        })
+    );
+    assert_eq!(car.take_trip(0.0, 0), Err(FleetError::InvalidDistance(0.0)));
+    assert!(car.take_trip(f64::NAN, 0).is_err());
+    assert_eq!(car.odometer_km(), 0.0);
+    assert_eq!(car.fuel_liters(), 50.0);
+    assert!(car.trips().is_empty());
+}
+
+#[test]
+fn cheapest_vehicle_for_trip() {
+    let fleet = sample_fleet();
+    // 가벼운 짐은 연비가 가장 좋은 Sedan. 두 대가 같으면 먼저 추가된 것
+    assert_eq!(fleet.cheapest_for(100.0, 100), Ok(2));
+    // Sedan에는 못 싣고 SUV에는 실리는 짐
+    assert_eq!(fleet.cheapest_for(100.0, 500), Ok(1));
+    // Truck만 실을 수 있는 짐
+    assert_eq!(fleet.cheapest_for(100.0, 5_000), Ok(0));
+    // 짐은 가볍지만 Sedan(700km), SUV(630km)는 한 번에 못 가는 거리
+    assert_eq!(fleet.cheapest_for(750.0, 10), Ok(0));
+}
+
+#[test]
+fn load_beyond_every_capacity() {
+    let mut fleet = sample_fleet();
+    let err = FleetError::NoSuitableVehicle {
+        distance_km: 10.0,
+        load_kg: 10_001,
+    };
+    assert_eq!(fleet.cheapest_for(10.0, 10_001), Err(err.clone()));
+    assert_eq!(fleet.dispatch(10.0, 10_001), Err(err));
+    // 너무 먼 거리도 마찬가지
+    assert!(matches!(
+        fleet.cheapest_for(801.0, 0),
+        Err(FleetError::NoSuitableVehicle { .. })
+    ));
+    // 빈 Fleet
+    assert!(Fleet::new().cheapest_for(1.0, 0).is_err());
+    assert_eq!(
+        fleet.cheapest_for(-1.0, 0),
+        Err(FleetError::InvalidDistance(-1.0))
+    );
+}
+
+#[test]
+fn dispatch_refuels_when_needed() {
+    let mut fleet = sample_fleet();
+    assert_eq!(fleet.dispatch(600.0, 100), Ok(2));
+    // C-1은 연료가 모자라서 다시 채운 뒤 떠난다. 연료 사용량은 C-2와 같으므로 여전히 C-1이 뽑힌다.
+    assert_eq!(fleet.dispatch(600.0, 100), Ok(2));
+    let car = fleet.get(2).unwrap();
+    assert_eq!(car.odometer_km(), 1200.0);
+    assert_eq!(car.trips().len(), 2);
+    assert!((car.fuel_liters() - (50.0 - 600.0 / 14.0)).abs() < 1e-9);
+}
+
+#[test]
+fn maintenance_by_mileage() {
+    let mut fleet = sample_fleet();
+    for _ in 0..15 {
+        fleet.dispatch(700.0, 10).unwrap(); // 연비가 가장 좋은 C-1만 운행된다.
+    }
+    for _ in 0..12 {
+        fleet.dispatch(800.0, 5_000).unwrap(); // T-1
+    }
+
+    let schedule = fleet.maintenance_schedule(1_000.0);
+    let ids: Vec<&str> = schedule.iter().map(|m| m.id.as_str()).collect();
+    // C-1은 10,500km를 달려서 500km 지났다. T-1은 9,600km로 아직 10,400km 남았다.
+    assert_eq!(ids, ["C-1"]);
+    assert_eq!(schedule[0].km_until_service, -500.0);
+
+    let all = fleet.maintenance_schedule(f64::INFINITY);
+    let ids: Vec<&str> = all.iter().map(|m| m.id.as_str()).collect();
+    assert_eq!(ids, ["C-1", "S-1", "C-2", "T-1"]);
+
+    fleet.service(2).unwrap();
+    assert!(fleet.maintenance_schedule(1_000.0).is_empty());
+    assert_eq!(fleet.get(2).unwrap().km_until_service(), 10_000.0);
+    assert_eq!(fleet.service(9), Err(FleetError::UnknownVehicle(9)));
+}
+
+#[test]
+fn utilization_report() {
+    let mut fleet = sample_fleet();
+    let empty = fleet.utilization();
+    assert!(
+        empty
+            .iter()
+            .all(|u| u.trips == 0 && u.distance_share == 0.0)
+    );
+
+    fleet.dispatch(100.0, 200).unwrap(); // C-1, 50%
+    fleet.dispatch(100.0, 400).unwrap(); // C-1, 100%
+    fleet.dispatch(200.0, 5_000).unwrap(); // T-1, 50%
+
+    let u = fleet.utilization();
+    assert_eq!(u[0].trips, 1);
+    assert_eq!(u[0].distance_share, 0.5);
+    assert_eq!(u[0].load_factor, 0.5);
+    assert_eq!(u[2].trips, 2);
+    assert_eq!(u[2].distance_km, 200.0);
+    assert_eq!(u[2].load_factor, 0.75);
+    assert_eq!(u[1].load_factor, 0.0);
+
+    let report = fleet.utilization_report();
+    assert_eq!(report.lines().count(), 5);
+    assert!(report.contains("C-1      Sedan      2      200.0  50.0%   75%"));
+}
@@ -0,0 +1,2 @@
+// main.rs의 Car 트레잇에 상수와 상태를 더해서 여러 대의 차량을 관리하는 예제
+pub mod fleet;
@@ -13,6 +13,8 @@ Rust는 구조체(struct)를 Java의 Class와 유사한 객체로 사용할 수
 // 이렇게 명시된 항목들은 이 트레잇을 구현하는 타입들에서 공통으로 사용될 수 있다.
 // 3가지 항목을 트레잇에 명시한 예이다.
 
+use trait_item_concept::fleet::{self, CarSpec, Fleet, Vehicle};
+
 trait ExTrait {
     type TypeNoDefault; //공통으로 사용할 수 있는 타입 정의. 구현측에서 타입지정 해야함
 
@@ -126,4 +128,29 @@ fn main() {
     // 이처럼, 트레잇은 공통으로 가져야할 메서드를 정의만 해놓는 것이고,
     // 이 트레잇을 impl하는 구조체의 구현부에서 해당 메서드의 바디를 실제 구현한다.
     // 나중에, 이 구조체의 인스턴스에서 해당 메서드를 호출하면 구현된 코드가 수행된다.
+
+    // ------------------------------------
+    // 차종마다 다른 적재량, 연비는 연관 상수로, 주행 거리와 연료는 상태로 갖게 한 것이 src/fleet.rs다.
+    // 연관 상수가 있는 트레잇은 dyn으로 쓸 수 없어서, 상수는 CarSpec에 두고 Car는 메서드만 갖도록 나눴다.
+    let mut fleet = Fleet::new();
+    fleet.add(Vehicle::<fleet::Truck>::boxed("T-1"));
+    fleet.add(Vehicle::<fleet::SUV>::boxed("S-1"));
+    fleet.add(Vehicle::<fleet::Sedan>::boxed("C-1"));
+    println!(
+        "Truck: {}kg, {}km/L",
+        <fleet::Truck as CarSpec>::CAPACITY_KG,
+        <fleet::Truck as CarSpec>::KM_PER_LITER
+    );
+
+    for (distance, load) in [(120.0, 300), (300.0, 600), (500.0, 3_000), (50.0, 20_000)] {
+        match fleet.dispatch(distance, load) {
+            Ok(i) => {
+                let car = fleet.get(i).unwrap();
+                car.drive();
+                println!("  {}km, {}kg -> {}", distance, load, car.id());
+            }
+            Err(e) => println!("  {}", e),
+        }
+    }
+    print!("{}", fleet.utilization_report());
 }
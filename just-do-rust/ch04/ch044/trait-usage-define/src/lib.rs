@@ -0,0 +1,3 @@
+// main.rs의 트레잇 정의 예제를 넓힌 모듈
+// - num: MyOp처럼 참조로 연산하는 트레잇을 숫자 타입 전반으로 넓힌 것
+pub mod num;
@@ -1,3 +1,5 @@
+use trait_usage_define::num::{self, One, Zero};
+
 fn main() {
     // 2. 트레잇을 정의해야 하는 경우
 
@@ -9,6 +11,7 @@ fn main() {
 
     // 여기서는, 참조 타입을 쓰는 add sbu 메서드를 만들어보자.
     // 새롭게 MyOp라는 트레잇을 만들고, 거기서 새로운 add sub 메서드를 정의하는 것
+    #[derive(Debug, Clone, PartialEq)]
     struct Point {
         x: i32,
         y: i32,
@@ -44,6 +47,52 @@ fn main() {
         // 따라서, 이제 Point 타입 인스턴스에 대해 add sub를 수행할 때 소유권 이동이 발생하지 않을 것이다.
     }
 
+    // MyOp처럼 참조로 연산하는 방식을 모든 숫자 타입과 Vector<T, N>으로 넓힌 것이 src/num.rs다.
+    // Point도 Zero, One, Num을 구현하면, 트레잇에 대해 한 번만 작성한 sum, mean 같은 알고리즘을 Point에도 쓸 수 있다.
+    // Num의 add, sub는 MyOp와 이름이 겹치므로 Num은 use하지 않고 num::Num으로 부른다. 그래서 p1.add(&p2)는 여전히 MyOp의 add다.
+    impl Zero for Point {
+        fn zero() -> Point {
+            Point { x: 0, y: 0 }
+        }
+
+        fn is_zero(&self) -> bool {
+            self.x == 0 && self.y == 0
+        }
+    }
+
+    impl One for Point {
+        fn one() -> Point {
+            Point { x: 1, y: 1 }
+        }
+    }
+
+    // 덧셈, 뺄셈은 MyOp의 것을 그대로 쓰고, 곱셈과 나눗셈은 x, y 각각에 대해 한다.
+    impl num::Num for Point {
+        type Output = Point;
+
+        fn add(&self, rhs: &Point) -> Point {
+            MyOp::add(self, rhs)
+        }
+
+        fn sub(&self, rhs: &Point) -> Point {
+            MyOp::sub(self, rhs)
+        }
+
+        fn mul(&self, rhs: &Point) -> Point {
+            Point {
+                x: self.x * rhs.x,
+                y: self.y * rhs.y,
+            }
+        }
+
+        fn div(&self, rhs: &Point) -> Point {
+            Point {
+                x: self.x / rhs.x,
+                y: self.y / rhs.y,
+            }
+        }
+    }
+
     let p1 = Point { x: 1, y: 2 };
     let p2 = Point { x: 3, y: 4 };
 
@@ -52,4 +101,15 @@ fn main() {
 
     println!("{} {}", p3.x, p3.y);
     println!("{} {}", p4.x, p4.y);
+
+    println!(
+        "{:?} {:?}",
+        num::Num::mul(&p1, &p2),
+        num::Num::div(&p2, &p1)
+    ); // Point { x: 3, y: 8 } Point { x: 3, y: 2 }
+    println!("{:?}", num::mean(&[p1, p2, Point::one()])); // Some(Point { x: 1, y: 2 })
+    println!("{:?}", num::sum::<Point>(&[]).is_zero()); // true
+    println!("{} {}", num::sum(&[1u8, 2, 3]), num::sum(&[0.5f64, 0.25]));
+    println!("{}", num::poly_eval(&[1, 2, 3], &2)); // 1 + 2*2 + 3*2^2
+    println!("{}", num::norm(&[3.0, 4.0]));
 }
@@ -0,0 +1,331 @@
+// main.rs의 MyOp는 &self, &Self로 받아서 소유권 이동 없이 더하고 빼는 트레잇이었다. 단, Point 하나에만 구현되어 있었다.
+// 여기서는 같은 방식(참조로 받고, 결과 타입은 연관 타입 Output)을 숫자 전반으로 넓혀서 트레잇 계층을 만든다.
+//
+//     Zero, One          덧셈/곱셈의 항등원
+//       └─ Num           add, sub, mul, div (+ 연관 타입 Output)
+//            ├─ Signed   abs, signum, neg (부호 있는 타입)
+//            │    └─ Float   sqrt, powi, epsilon (부동소수점)
+//
+// 모든 정수, 실수 기본 타입과 이 파일의 Vector<T, N>이 구현한다.
+// 점 타입들도 구현한다: main.rs의 MyOp Point, trait-usage-implement의 main.rs에 있는 Point
+// 그리고 sum, mean, dot, poly_eval 같은 알고리즘을 이 트레잇들에 대해 한 번만 작성한다.
+//
+// 정수의 연산은 +, -, *, /와 똑같이 동작한다. 즉, 디버그 빌드에서 오버플로가 나거나 0으로 나누면 패닉이다.
+
+pub trait Zero: Sized {
+    fn zero() -> Self;
+    fn is_zero(&self) -> bool;
+}
+
+pub trait One: Sized {
+    fn one() -> Self;
+}
+
+pub trait Num: Zero + One + Clone + PartialEq {
+    // 대부분의 타입은 Output = Self다. 알고리즘에서는 Num<Output = T>로 바운드해서 쓴다.
+    type Output;
+
+    fn add(&self, rhs: &Self) -> Self::Output;
+    fn sub(&self, rhs: &Self) -> Self::Output;
+    fn mul(&self, rhs: &Self) -> Self::Output;
+    fn div(&self, rhs: &Self) -> Self::Output;
+}
+
+pub trait Signed: Num<Output = Self> {
+    fn abs(&self) -> Self;
+    // 음수면 -1, 0이면 0, 양수면 1 (실수는 std처럼 ±0.0에도 ±1.0)
+    fn signum(&self) -> Self;
+    fn neg(&self) -> Self;
+    fn is_negative(&self) -> bool;
+}
+
+pub trait Float: Signed + PartialOrd + Copy {
+    fn sqrt(&self) -> Self;
+    fn powi(&self, n: i32) -> Self;
+    fn epsilon() -> Self;
+    fn is_nan(&self) -> bool;
+}
+
+// 타입마다 같은 코드를 반복해야 해서 매크로로 만든다. $t 자리에 타입 이름이 들어간다.
+macro_rules! impl_num {
+    ($zero:literal, $one:literal; $($t:ty)*) => {$(
+        impl Zero for $t {
+            fn zero() -> $t {
+                $zero
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == $zero
+            }
+        }
+
+        impl One for $t {
+            fn one() -> $t {
+                $one
+            }
+        }
+
+        impl Num for $t {
+            type Output = $t;
+
+            fn add(&self, rhs: &$t) -> $t {
+                *self + *rhs
+            }
+
+            fn sub(&self, rhs: &$t) -> $t {
+                *self - *rhs
+            }
+
+            fn mul(&self, rhs: &$t) -> $t {
+                *self * *rhs
+            }
+
+            fn div(&self, rhs: &$t) -> $t {
+                *self / *rhs
+            }
+        }
+    )*};
+}
+
+impl_num!(0, 1; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_num!(0.0, 1.0; f32 f64);
+
+macro_rules! impl_signed {
+    ($($t:ty)*) => {$(
+        impl Signed for $t {
+            fn abs(&self) -> $t {
+                <$t>::abs(*self)
+            }
+
+            fn signum(&self) -> $t {
+                <$t>::signum(*self)
+            }
+
+            fn neg(&self) -> $t {
+                -*self
+            }
+
+            fn is_negative(&self) -> bool {
+                *self < <$t as Zero>::zero()
+            }
+        }
+    )*};
+}
+
+impl_signed!(i8 i16 i32 i64 i128 isize f32 f64);
+
+macro_rules! impl_float {
+    ($($t:ident)*) => {$(
+        impl Float for $t {
+            fn sqrt(&self) -> $t {
+                <$t>::sqrt(*self)
+            }
+
+            fn powi(&self, n: i32) -> $t {
+                <$t>::powi(*self, n)
+            }
+
+            fn epsilon() -> $t {
+                $t::EPSILON
+            }
+
+            fn is_nan(&self) -> bool {
+                <$t>::is_nan(*self)
+            }
+        }
+    )*};
+}
+
+impl_float!(f32 f64);
+
+// 길이가 N으로 고정된 벡터. 원소마다 연산한다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector<T, const N: usize>(pub [T; N]);
+
+impl<T: Zero, const N: usize> Zero for Vector<T, N> {
+    fn zero() -> Self {
+        Vector(std::array::from_fn(|_| T::zero()))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(Zero::is_zero)
+    }
+}
+
+impl<T: One, const N: usize> One for Vector<T, N> {
+    fn one() -> Self {
+        Vector(std::array::from_fn(|_| T::one()))
+    }
+}
+
+impl<T: Num<Output = T>, const N: usize> Vector<T, N> {
+    fn zip_with(&self, rhs: &Self, f: impl Fn(&T, &T) -> T) -> Self {
+        Vector(std::array::from_fn(|i| f(&self.0[i], &rhs.0[i])))
+    }
+
+    // 원소끼리 곱한 뒤 모두 더한 값
+    pub fn dot(&self, rhs: &Self) -> T {
+        dot(&self.0, &rhs.0)
+    }
+}
+
+impl<T: Num<Output = T>, const N: usize> Num for Vector<T, N> {
+    type Output = Self;
+
+    fn add(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, T::add)
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, T::sub)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, T::mul)
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, T::div)
+    }
+}
+
+// ------------------------------------------------------------
+// 트레잇에 대해 한 번만 작성한 알고리즘들
+
+pub fn sum<T: Num<Output = T>>(items: &[T]) -> T {
+    items.iter().fold(T::zero(), |acc, x| acc.add(x))
+}
+
+pub fn product<T: Num<Output = T>>(items: &[T]) -> T {
+    items.iter().fold(T::one(), |acc, x| acc.mul(x))
+}
+
+// 평균. 비어 있으면 None
+// 개수 n을 T로 바꾸는 방법이 트레잇에 없으므로, 더할 때마다 one()을 함께 더해서 n을 T 타입으로 만든다.
+// 정수 타입이면 나눗셈도 정수 나눗셈이다.
+pub fn mean<T: Num<Output = T>>(items: &[T]) -> Option<T> {
+    if items.is_empty() {
+        return None;
+    }
+    let one = T::one();
+    let (total, count) = items
+        .iter()
+        .fold((T::zero(), T::zero()), |(s, n), x| (s.add(x), n.add(&one)));
+    Some(total.div(&count))
+}
+
+// 내적. 두 슬라이스의 길이가 다르면 패닉
+pub fn dot<T: Num<Output = T>>(a: &[T], b: &[T]) -> T {
+    assert_eq!(a.len(), b.len(), "dot: slices have different lengths");
+    a.iter()
+        .zip(b)
+        .fold(T::zero(), |acc, (x, y)| acc.add(&x.mul(y)))
+}
+
+// 다항식 계산. coeffs[i]는 x^i의 계수다. [1, 2, 3]이면 1 + 2x + 3x^2
+// 호너(Horner) 방식으로 높은 차수부터 ((3)x + 2)x + 1처럼 계산한다.
+pub fn poly_eval<T: Num<Output = T>>(coeffs: &[T], x: &T) -> T {
+    coeffs
+        .iter()
+        .rev()
+        .fold(T::zero(), |acc, c| acc.mul(x).add(c))
+}
+
+// 원소마다 절댓값을 취해서 더한 값 (L1 노름)
+pub fn abs_sum<T: Signed>(items: &[T]) -> T {
+    items.iter().fold(T::zero(), |acc, x| acc.add(&x.abs()))
+}
+
+// 유클리드 길이 (L2 노름)
+pub fn norm<T: Float>(items: &[T]) -> T {
+    dot(items, items).sqrt()
+}
+
+// 상대 오차가 epsilon의 몇 배(ulps) 이내면 같다고 본다.
+pub fn approx_eq<T: Float>(a: T, b: T, ulps: T) -> bool {
+    if a.is_nan() || b.is_nan() {
+        return false;
+    }
+    let diff = a.sub(&b).abs();
+    let scale = if a.abs() > b.abs() { a.abs() } else { b.abs() };
+    let scale = if scale > T::one() { scale } else { T::one() };
+    diff <= T::epsilon().mul(&ulps).mul(&scale)
+}
+
+#[test]
+fn identities_for_primitives() {
+    assert_eq!(u8::zero(), 0);
+    assert_eq!(i128::one(), 1);
+    assert_eq!(<f32 as Zero>::zero(), 0.0);
+    assert!(0usize.is_zero());
+    assert!(!(-0.5f64).is_zero());
+    assert!((-0.0f64).is_zero());
+}
+
+#[test]
+fn num_ops_match_std_operators() {
+    assert_eq!(Num::add(&7i32, &5), 12);
+    assert_eq!(Num::sub(&7u64, &5), 2);
+    assert_eq!(Num::mul(&7i8, &-5), -35);
+    assert_eq!(Num::div(&7i32, &2), 3);
+    assert_eq!(Num::div(&7.0f64, &2.0), 3.5);
+}
+
+#[test]
+#[should_panic]
+fn integer_division_by_zero_panics_like_std() {
+    let zero = i32::zero();
+    Num::div(&1i32, &zero);
+}
+
+#[test]
+fn signed_and_float() {
+    assert_eq!(Signed::abs(&-3i16), 3);
+    assert_eq!(Signed::signum(&-3i64), -1);
+    assert_eq!(Signed::signum(&0i32), 0);
+    assert!(Signed::is_negative(&-0.1f32));
+    assert_eq!(Signed::neg(&2.5f64), -2.5);
+    assert_eq!(Float::sqrt(&9.0f64), 3.0);
+    assert_eq!(Float::powi(&2.0f32, 10), 1024.0);
+    assert!(approx_eq(0.1 + 0.2, 0.3f64, 2.0));
+    assert!(!approx_eq(0.1, 0.2f64, 2.0));
+    assert!(!approx_eq(f64::NAN, f64::NAN, 2.0));
+}
+
+#[test]
+fn algorithms_on_primitives() {
+    assert_eq!(sum(&[1u32, 2, 3, 4]), 10);
+    assert_eq!(sum::<i32>(&[]), 0);
+    assert_eq!(product(&[1i64, 2, 3, 4]), 24);
+    assert_eq!(mean(&[1.0f64, 2.0, 4.5]), Some(2.5));
+    // 정수 평균은 정수 나눗셈
+    assert_eq!(mean(&[1i32, 2]), Some(1));
+    assert_eq!(mean::<f32>(&[]), None);
+    assert_eq!(dot(&[1i32, 2, 3], &[4, 5, 6]), 32);
+    // 1 + 2x + 3x^2, x = 2
+    assert_eq!(poly_eval(&[1i32, 2, 3], &2), 17);
+    assert_eq!(poly_eval::<i32>(&[], &5), 0);
+    assert_eq!(poly_eval(&[0.5f64, 0.0, 2.0], &-1.5), 5.0);
+    assert_eq!(abs_sum(&[-1i32, 2, -3]), 6);
+    assert_eq!(norm(&[3.0f32, 4.0]), 5.0);
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn dot_length_mismatch() {
+    dot(&[1, 2], &[1]);
+}
+
+#[test]
+fn vector_is_num() {
+    let a = Vector([1.0f64, 2.0, 3.0]);
+    let b = Vector([4.0, 5.0, 6.0]);
+    assert_eq!(a.add(&b), Vector([5.0, 7.0, 9.0]));
+    assert_eq!(b.div(&a), Vector([4.0, 2.5, 2.0]));
+    assert_eq!(a.dot(&b), 32.0);
+    assert_eq!(Vector::<i32, 2>::one(), Vector([1, 1]));
+    assert!(Vector::<u8, 4>::zero().is_zero());
+    assert_eq!(mean(&[a, b]), Some(Vector([2.5, 3.5, 4.5])));
+    // Vector의 슬라이스에 대한 dot은 각 원소(Vector)끼리 원소별 곱을 해서 더한 Vector다.
+    assert_eq!(dot(&[a], &[b]), Vector([4.0, 10.0, 18.0]));
+}
@@ -2,11 +2,14 @@
 // 1. Rust 기본 라이브러리에서 요구되는 트레잇을 구현해야 하는 경우
 // 2. 개인이 트레잇을 정의해야 하는 경우
 
+use trait_usage_define::num::{self, Num, One, Zero};
+
 fn main() {
     // 1. 트레잇을 구현해야 하는 경우
     // 예를 들어 (x,y) 값을 가지는 Point라는 구조체를 정의했고,
     // Point 끼리의 덧셈을 구현하려고 한다고 해보자. Point 끼리의 덧셈이라는 것은 x끼리 더하고 y 끼리 더하는 것
 
+    #[derive(PartialEq)]
     struct Point {
         x: i32,
         y: i32,
@@ -64,4 +67,55 @@ fn main() {
     // 따라서, 그 이후에 해당 인스턴스를 사용하려고 하면 이런 에러가 나는 것
     let p4 = p1 - p2;
     println!("subtract=({},{})", p4.x, p4.y);
+
+    // trait-usage-define의 num 모듈에 있는 Zero, One, Num을 구현하면 num::sum 같은 제네릭 알고리즘에 Point를 넘길 수 있다.
+    // Num은 참조로 연산하므로, 안에서는 clone()한 값으로 위의 Add, Sub를 부른다. (p1.clone() + p2.clone()으로 하던 것)
+    impl Zero for Point {
+        fn zero() -> Point {
+            Point { x: 0, y: 0 }
+        }
+
+        fn is_zero(&self) -> bool {
+            self.x == 0 && self.y == 0
+        }
+    }
+
+    impl One for Point {
+        fn one() -> Point {
+            Point { x: 1, y: 1 }
+        }
+    }
+
+    impl Num for Point {
+        type Output = Point;
+
+        fn add(&self, rhs: &Point) -> Point {
+            self.clone() + rhs.clone()
+        }
+
+        fn sub(&self, rhs: &Point) -> Point {
+            self.clone() - rhs.clone()
+        }
+
+        fn mul(&self, rhs: &Point) -> Point {
+            Point {
+                x: self.x * rhs.x,
+                y: self.y * rhs.y,
+            }
+        }
+
+        fn div(&self, rhs: &Point) -> Point {
+            Point {
+                x: self.x / rhs.x,
+                y: self.y / rhs.y,
+            }
+        }
+    }
+
+    // Num은 참조로 받으므로 clone 없이도 p3, p4를 여러 번 쓸 수 있다.
+    let p5 = p3.mul(&p4);
+    println!("multiply=({},{})", p5.x, p5.y); //multiply=(-12,-16)
+    let points = [p3, p4, Point::one()];
+    let total = num::sum(&points);
+    println!("sum=({},{})", total.x, total.y); //sum=(5,7)
 }
@@ -12,6 +12,8 @@ Rust에서 가장 많이 사용되는 열거형은 Option과 Result다. 둘 다
        Err(E),
     }
 Option의 경우는 1개의 제네릭 타입, Result는 두 개의 제네릭 타입이 사용된다.
+
+위 두 열거형을 메서드까지 포함해서 직접 구현해 본 것은 ch04/ch043/option 크레이트의 src/option.rs, src/result.rs에 있다.
  */
 
 // Option 제네릭
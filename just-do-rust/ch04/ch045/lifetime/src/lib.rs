@@ -0,0 +1,2 @@
+// 여러 문자열 중에서 하나를 고르는 함수들
+pub mod select;
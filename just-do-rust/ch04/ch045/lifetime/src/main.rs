@@ -7,6 +7,8 @@
 //
 // 아래 예를 보자. "라이프타임이 끝난 변수"를 출력하려고 했기에 에러가 뜬다.
 
+use lifetime::select::{self, Tie, Width};
+
 fn main() {
     // let r; // 변수 r은 main 함수가 끝날 때까지 수명을 같이 한다.
 
@@ -48,6 +50,20 @@ fn main() {
     let s = longest(x.as_str(), y.as_str());
     // (2)  String 객체의 참조값을 얻어낼 때는 as_str()을 사용하면 된다.
     println!("longest is : {}", s);
+
+    // 길이가 같으면 longest는 s2를 고르고, len()은 바이트 수라서 "가나"(6바이트)가 "abcd"(4바이트)보다 길다.
+    // 여러 개의 문자열에서 고르는 방법, 길이를 재는 방법, 동점 처리 방법을 정할 수 있는 함수들은 src/select.rs에 있다.
+    let words = ["가나", "abcd", "러스트", "xyz"];
+    println!("bytes: {:?}", select::longest(words, Width::Bytes));
+    println!("chars: {:?}", select::longest(words, Width::Chars));
+    println!(
+        "3글자 동점 모두: {:?}",
+        select::longest_by(["러스트", "xyz", "ab"], |s| s.chars().count(), Tie::All)
+    );
+    println!(
+        "공통 부분: {}",
+        select::longest_common_substring("나는 러스트를 배운다", "러스트가 좋다")
+    );
 }
 
 // fn longest(s1: &str, s2: &str) -> &str {
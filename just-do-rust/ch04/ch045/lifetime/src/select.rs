@@ -0,0 +1,318 @@
+// main.rs의 longest는 두 문자열 중 긴 것을 고르는데, 두 가지 아쉬운 점이 있다.
+//
+// 1) 길이가 같으면 말없이 s2가 선택된다. (if s1.len() > s2.len() { s1 } else { s2 })
+// 2) len()은 바이트 수다. "가나"는 글자 2개지만 len()은 6이라서 "abcd"보다 길다고 판단된다.
+//
+// 이 모듈은 여러 개의 문자열 중에서 하나(혹은 여럿)를 고르는 함수들을 모았다.
+// - 길이를 무엇으로 잴지(Width): 바이트, 글자(char), 사람이 보는 글자(grapheme) 근사치
+// - 동점일 때 무엇을 고를지(Tie): 처음 것, 마지막 것, 전부
+//
+// 모든 함수는 입력 문자열의 일부를 그대로 돌려준다. 그래서 리턴값의 라이프타임은 입력의 라이프타임 'a와 묶여 있다.
+
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Width {
+    // str::len()과 같다. UTF-8 바이트 수
+    Bytes,
+    // chars().count()와 같다. 유니코드 코드 포인트 수
+    Chars,
+    // 결합 문자(악센트, 한글 옛 자모의 중성/종성, 이모지 수식자 등)는 앞 글자에 붙여서 하나로 센다.
+    // 유니코드 표준의 grapheme cluster 규칙을 완전히 구현한 것은 아니고, 자주 쓰이는 경우만 다룬 근사치다.
+    Graphemes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tie {
+    // 동점이면 먼저 나온 것
+    First,
+    // 동점이면 나중에 나온 것 (main.rs의 longest와 같은 동작)
+    Last,
+    // 동점인 것 모두, 나온 순서대로
+    All,
+}
+
+pub fn width(s: &str, w: Width) -> usize {
+    match w {
+        Width::Bytes => s.len(),
+        Width::Chars => s.chars().count(),
+        Width::Graphemes => {
+            let mut count = 0;
+            let mut after_zwj = false;
+            for c in s.chars() {
+                if after_zwj {
+                    // ZWJ(zero width joiner) 다음 글자는 앞 글자와 합쳐진다. 👨‍👩‍👧 같은 이모지
+                    after_zwj = false;
+                    continue;
+                }
+                if c == '\u{200D}' {
+                    after_zwj = true;
+                    continue;
+                }
+                if !is_extend(c) || count == 0 {
+                    count += 1;
+                }
+            }
+            count
+        }
+    }
+}
+
+// 앞 글자에 붙어서 하나의 글자로 보이는 문자인가
+fn is_extend(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}'     // 결합 분음 부호 (é = e + ́)
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{FE20}'..='\u{FE2F}'
+        | '\u{FE00}'..='\u{FE0F}'   // 이체자 선택자 (❤️ = ❤ + VS16)
+        | '\u{1F3FB}'..='\u{1F3FF}' // 이모지 피부색 수식자
+        | '\u{1160}'..='\u{11FF}'   // 한글 자모의 중성, 종성 (ᄒ + ᅡ + ᆫ = 한)
+        | '\u{D7B0}'..='\u{D7FF}'
+    )
+}
+
+// key 값이 가장 큰 문자열을 고른다. key가 같은 것들 사이에서는 tie 정책을 따른다.
+// 입력이 비어 있으면 빈 Vec, First/Last면 원소가 최대 1개, All이면 동점인 것 모두를 돌려준다.
+pub fn longest_by<'a, I, K, F>(items: I, key: F, tie: Tie) -> Vec<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+    K: Ord,
+    F: FnMut(&str) -> K,
+{
+    select_by(items, key, tie, Ordering::Greater)
+}
+
+// key 값이 가장 작은 문자열을 고른다.
+pub fn shortest_by<'a, I, K, F>(items: I, key: F, tie: Tie) -> Vec<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+    K: Ord,
+    F: FnMut(&str) -> K,
+{
+    select_by(items, key, tie, Ordering::Less)
+}
+
+// 가장 긴 문자열 하나. 동점이면 먼저 나온 것
+pub fn longest<'a, I>(items: I, w: Width) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    longest_by(items, |s| width(s, w), Tie::First).pop()
+}
+
+// 가장 짧은 문자열 하나. 동점이면 먼저 나온 것
+pub fn shortest<'a, I>(items: I, w: Width) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    shortest_by(items, |s| width(s, w), Tie::First).pop()
+}
+
+// better: 새 후보가 지금까지의 최선보다 key 비교 결과가 이 값이면 교체한다.
+fn select_by<'a, I, K, F>(items: I, mut key: F, tie: Tie, better: Ordering) -> Vec<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+    K: Ord,
+    F: FnMut(&str) -> K,
+{
+    let mut best: Option<K> = None;
+    let mut picked: Vec<&'a str> = Vec::new();
+    for s in items {
+        let k = key(s);
+        let ord = match &best {
+            None => better,
+            Some(b) => k.cmp(b),
+        };
+        if ord == better {
+            best = Some(k);
+            picked.clear();
+            picked.push(s);
+        } else if ord == Ordering::Equal {
+            match tie {
+                Tie::First => {}
+                Tie::Last => picked[0] = s,
+                Tie::All => picked.push(s),
+            }
+        }
+    }
+    picked
+}
+
+// 모든 문자열의 가장 긴 공통 접두사. 첫 번째 문자열의 일부를 돌려준다.
+// 글자 단위로 비교하므로, 멀티바이트 글자의 중간에서 잘리는 일이 없다.
+pub fn longest_common_prefix<'a>(items: &[&'a str]) -> &'a str {
+    let Some((first, rest)) = items.split_first() else {
+        return "";
+    };
+    let mut end = first.len();
+    for other in rest {
+        let common: usize = first
+            .chars()
+            .zip(other.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+        end = end.min(common);
+    }
+    &first[..end]
+}
+
+// 모든 문자열의 가장 긴 공통 접미사. 첫 번째 문자열의 일부를 돌려준다.
+pub fn longest_common_suffix<'a>(items: &[&'a str]) -> &'a str {
+    let Some((first, rest)) = items.split_first() else {
+        return "";
+    };
+    let mut len = first.len();
+    for other in rest {
+        let common: usize = first
+            .chars()
+            .rev()
+            .zip(other.chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+        len = len.min(common);
+    }
+    &first[first.len() - len..]
+}
+
+// 두 문자열의 가장 긴 공통 부분 문자열. a의 일부를 돌려주므로 라이프타임은 a와만 묶인다.
+// (lifetime-notation의 first_str처럼 b는 리턴값과 관계가 없다.)
+// 길이가 같은 후보가 여럿이면 a에서 먼저 나오는 것을 돌려준다. 글자 단위 동적 계획법으로 O(n*m)이다.
+pub fn longest_common_substring<'a>(a: &'a str, b: &str) -> &'a str {
+    let a_chars: Vec<(usize, char)> = a.char_indices().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    // prev[j]: a의 i-1번째 글자와 b의 j-1번째 글자에서 끝나는 공통 부분의 길이(글자 수)
+    let mut prev = vec![0usize; b_chars.len() + 1];
+    let mut cur = vec![0usize; b_chars.len() + 1];
+    let mut best_len = 0;
+    let mut best_end = 0; // a_chars에서 공통 부분이 끝나는 위치 (그 다음 글자의 인덱스)
+    for i in 1..=a_chars.len() {
+        for j in 1..=b_chars.len() {
+            cur[j] = if a_chars[i - 1].1 == b_chars[j - 1] {
+                prev[j - 1] + 1
+            } else {
+                0
+            };
+            if cur[j] > best_len {
+                best_len = cur[j];
+                best_end = i;
+            }
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    if best_len == 0 {
+        return "";
+    }
+    let start = a_chars[best_end - best_len].0;
+    let end = a_chars
+        .get(best_end)
+        .map(|(idx, _)| *idx)
+        .unwrap_or(a.len());
+    &a[start..end]
+}
+
+#[test]
+fn width_kinds() {
+    assert_eq!(width("hello", Width::Bytes), 5);
+    assert_eq!(width("안녕하세요", Width::Bytes), 15);
+    assert_eq!(width("안녕하세요", Width::Chars), 5);
+    assert_eq!(width("안녕하세요", Width::Graphemes), 5);
+
+    // 옛 한글처럼 자모를 이어 쓴 "한": 코드 포인트 3개, 보이는 글자 1개
+    let jamo = "\u{1112}\u{1161}\u{11AB}";
+    assert_eq!(width(jamo, Width::Chars), 3);
+    assert_eq!(width(jamo, Width::Graphemes), 1);
+
+    // e + 결합 악센트
+    assert_eq!(width("e\u{0301}", Width::Graphemes), 1);
+    // 가족 이모지: 사람 3명을 ZWJ로 이었다.
+    let family = "👨\u{200D}👩\u{200D}👧";
+    assert_eq!(width(family, Width::Chars), 5);
+    assert_eq!(width(family, Width::Graphemes), 1);
+    assert_eq!(width("👍🏽", Width::Graphemes), 1);
+    assert_eq!(width("", Width::Graphemes), 0);
+}
+
+#[test]
+fn longest_depends_on_width() {
+    let words = ["가나", "abcd"];
+    // 바이트로는 "가나"(6) > "abcd"(4)
+    assert_eq!(longest(words, Width::Bytes), Some("가나"));
+    // 글자 수로는 "abcd"(4) > "가나"(2)
+    assert_eq!(longest(words, Width::Chars), Some("abcd"));
+    assert_eq!(shortest(words, Width::Chars), Some("가나"));
+    assert_eq!(longest([], Width::Chars), None);
+}
+
+#[test]
+fn tie_policies() {
+    let words = ["사과", "배", "포도", "귤", "수박"];
+    let by_chars = |s: &str| s.chars().count();
+    assert_eq!(longest_by(words, by_chars, Tie::First), vec!["사과"]);
+    assert_eq!(longest_by(words, by_chars, Tie::Last), vec!["수박"]);
+    assert_eq!(
+        longest_by(words, by_chars, Tie::All),
+        vec!["사과", "포도", "수박"]
+    );
+    assert_eq!(shortest_by(words, by_chars, Tie::All), vec!["배", "귤"]);
+    assert_eq!(shortest_by(words, by_chars, Tie::Last), vec!["귤"]);
+
+    // main.rs의 longest(s1, s2)는 바이트 길이에 Tie::Last를 쓴 것과 같다.
+    assert_eq!(longest_by(["123", "456"], str::len, Tie::Last), vec!["456"]);
+    assert_eq!(longest_by(["가", "abc"], str::len, Tie::Last), vec!["abc"]);
+}
+
+#[test]
+fn custom_keys() {
+    // 받침 있는 글자가 가장 많은 단어 (한글 음절에서 종성 유무는 (코드 - 0xAC00) % 28 != 0)
+    let batchim = |s: &str| {
+        s.chars()
+            .filter(|c| ('가'..='힣').contains(c) && !(*c as u32 - 0xAC00).is_multiple_of(28))
+            .count()
+    };
+    assert_eq!(
+        longest_by(["바나나", "수박", "딸기", "참외"], batchim, Tie::All),
+        vec!["수박", "딸기", "참외"]
+    );
+}
+
+#[test]
+fn common_prefix_and_suffix() {
+    assert_eq!(longest_common_prefix(&["러스트", "러스티", "러시아"]), "러");
+    assert_eq!(longest_common_prefix(&["프로그램", "프로그래머"]), "프로그");
+    assert_eq!(longest_common_prefix(&["abc", "xyz"]), "");
+    assert_eq!(longest_common_prefix(&["혼자"]), "혼자");
+    assert_eq!(longest_common_prefix(&[]), "");
+
+    assert_eq!(longest_common_suffix(&["학생들", "선생들", "들"]), "들");
+    assert_eq!(longest_common_suffix(&["먹었습니다", "갔습니다"]), "습니다");
+    assert_eq!(longest_common_suffix(&["가", "나"]), "");
+}
+
+#[test]
+fn common_substring() {
+    assert_eq!(
+        longest_common_substring("나는 러스트를 배운다", "러스트가 좋다"),
+        "러스트"
+    );
+    assert_eq!(longest_common_substring("abcdef", "zcdez"), "cde");
+    assert_eq!(longest_common_substring("가나다", "라마바"), "");
+    assert_eq!(longest_common_substring("", "abc"), "");
+    // 같은 길이의 후보가 여럿이면 a에서 먼저 나오는 것
+    assert_eq!(longest_common_substring("ab-cd", "cd-ab"), "ab");
+}
+
+#[test]
+fn result_lives_as_long_as_first_input() {
+    let a = String::from("라이프타임");
+    let s;
+    {
+        let b = String::from("타임머신");
+        // 리턴값은 a에서만 빌려오므로 b가 먼저 사라져도 괜찮다.
+        s = longest_common_substring(&a, &b);
+    }
+    assert_eq!(s, "타임");
+}
@@ -0,0 +1,73 @@
+// 빌려오는 파서(Records::parse)와 String으로 복사하는 파서(parse_owned)의 속도 비교
+// 외부 크레이트 없이 재기 위해 Cargo.toml에서 harness = false로 두고 main에서 직접 시간을 잰다.
+//
+//   cargo bench -p structure-lifetime
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use structure_lifetime::records::{Records, parse_owned};
+
+// 이름 열 개 중 하나는 따옴표 안에 쉼표가 있고, 하나는 ""로 이스케이프된 입력
+fn make_input(lines: usize) -> String {
+    let names = [
+        "Jeff",
+        "Alice",
+        "\"Kim, Jr.\"",
+        "홍길동",
+        "Bob",
+        "\"The \"\"Rock\"\"\"",
+        "Carol",
+        "Dave",
+        "Eve",
+        "Mallory",
+    ];
+    let mut s = String::new();
+    for i in 0..lines {
+        s.push_str(names[i % names.len()]);
+        s.push(',');
+        s.push_str(&i.to_string());
+        s.push('\n');
+    }
+    s
+}
+
+fn measure<F: FnMut() -> usize>(name: &str, rounds: u32, mut f: F) -> Duration {
+    // 한 번 미리 돌려서 캐시를 데운다.
+    black_box(f());
+    let start = Instant::now();
+    for _ in 0..rounds {
+        black_box(f());
+    }
+    let per_round = start.elapsed() / rounds;
+    println!(
+        "{:<10} {:>10.3} ms/round",
+        name,
+        per_round.as_secs_f64() * 1000.0
+    );
+    per_round
+}
+
+fn main() {
+    let input = make_input(200_000);
+    let rounds = 20;
+    println!("{} bytes, {} rounds", input.len(), rounds);
+
+    let borrowed = measure("borrowed", rounds, || {
+        Records::parse(black_box(&input))
+            .filter_map(Result::ok)
+            .map(|p| p.name.len() + p.num as usize)
+            .sum()
+    });
+    let owned = measure("owned", rounds, || {
+        parse_owned(black_box(&input))
+            .into_iter()
+            .filter_map(Result::ok)
+            .map(|p| p.name.len() + p.num as usize)
+            .sum()
+    });
+    println!(
+        "owned / borrowed = {:.2}x",
+        owned.as_secs_f64() / borrowed.as_secs_f64()
+    );
+}
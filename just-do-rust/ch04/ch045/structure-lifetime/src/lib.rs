@@ -0,0 +1,2 @@
+// Person<'a>처럼 입력을 빌려오는 구조체를 실제 텍스트에서 채우는 파서 모듈
+pub mod records;
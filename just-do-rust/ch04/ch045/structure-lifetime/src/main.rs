@@ -1,3 +1,5 @@
+use structure_lifetime::records::Records;
+
 // 구조체에서는 그 구성원으로 참조자를 가지고 있는 경우 무조건 해당 구조체에 대해 라이프타임을 표기해줘야 한다.
 // 아래는 그 예다.
 struct Person<'a> {
@@ -67,6 +69,19 @@ fn main() {
     let b = Borrowed(&x);
 
     println!("borrowed: {}", b.0);
+
+    // 실제 데이터로 Person을 채워보기
+    // records 모듈의 Person<'a>는 위의 Person과 같은 모양인데, name이 &'a str 대신 Cow<'a, str>이다.
+    // 이름은 input에서 빌려온 것이기에, people을 쓰는 동안에는 input이 살아있어야 한다.
+    let input = String::from("Jeff,10\n\"Kim, Jr.\",20\nAlice,x\n");
+    let people = Records::parse(&input);
+    for p in people {
+        match p {
+            Ok(p) => println!("name: {}, num: {}", p.name, p.num),
+            Err(e) => println!("error: {}", e),
+        }
+    }
+    // drop(input);을 위 for문 앞에 넣으면, people이 input을 빌리고 있기에 컴파일 에러가 난다.
 }
 
 /*
@@ -0,0 +1,328 @@
+// main.rs의 Person<'a>는 name을 &'a str로 빌려오는 구조체였다.
+// 여기서는 실제 텍스트(한 줄에 "이름,숫자" 하나)를 읽어서 Person<'a>를 만드는데,
+// 이름을 새 String으로 복사하지 않고 입력 문자열의 일부를 그대로 빌려온다. (zero-copy)
+//
+//     Jeff,10
+//     "Kim, Jr.",20        <- 따옴표로 감싸면 이름 안에 쉼표를 쓸 수 있다.
+//     "The ""Rock""",30    <- 따옴표 안에서 따옴표는 두 번 쓴다.
+//
+// 마지막 경우처럼 ""를 "로 바꿔야 할 때는 입력을 그대로 빌려올 수 없으므로 그때만 새 String을 만든다.
+// 이렇게 "보통은 빌리고, 필요할 때만 소유한다"를 표현하는 타입이 Cow<'a, str>(Clone on Write)다.
+//   - Cow::Borrowed(&'a str): 입력에서 빌려온 경우
+//   - Cow::Owned(String): 새로 만든 경우
+//
+// 반환되는 Person<'a>의 라이프타임 'a는 입력 문자열의 라이프타임과 같다.
+// 즉, Person들을 쓰는 동안에는 입력 문자열을 없앨 수 없고, 컴파일러가 이를 검사해준다.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+const DELIMITER: u8 = b',';
+const QUOTE: u8 = b'"';
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Person<'a> {
+    pub name: Cow<'a, str>,
+    pub num: i32,
+}
+
+impl Person<'_> {
+    // 입력에서 빌려오지 않은 소유 버전으로 바꾼다. 입력 문자열보다 오래 살아야 할 때 쓴다.
+    pub fn into_owned(self) -> OwnedPerson {
+        OwnedPerson {
+            name: self.name.into_owned(),
+            num: self.num,
+        }
+    }
+}
+
+// 비교용으로 만든, 이름을 항상 String으로 복사해서 갖는 버전
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedPerson {
+    pub name: String,
+    pub num: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    MissingField(&'static str),
+    TooManyFields,
+    InvalidNumber(String),
+    UnterminatedQuote,
+    // 따옴표로 감싼 필드가 끝난 뒤에 구분자가 아닌 글자가 온 경우, 혹은 따옴표 없는 필드 중간에 따옴표가 온 경우
+    UnexpectedQuote,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    // 1부터 세는 줄 번호
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ParseErrorKind::MissingField(name) => write!(f, "missing field `{}`", name),
+            ParseErrorKind::TooManyFields => write!(f, "too many fields"),
+            ParseErrorKind::InvalidNumber(s) => write!(f, "invalid number {:?}", s),
+            ParseErrorKind::UnterminatedQuote => write!(f, "unterminated quoted field"),
+            ParseErrorKind::UnexpectedQuote => write!(f, "unexpected quote"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// 입력 문자열을 한 줄씩 Person으로 바꾸는 반복자
+// 빈 줄과 #으로 시작하는 주석 줄은 건너뛴다.
+pub struct Records<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+}
+
+impl<'a> Records<'a> {
+    pub fn parse(input: &'a str) -> Records<'a> {
+        Records {
+            lines: input.lines().enumerate(),
+        }
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Result<Person<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (i, line) in self.lines.by_ref() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            return Some(parse_line(line).map_err(|kind| ParseError { line: i + 1, kind }));
+        }
+        None
+    }
+}
+
+fn parse_line(line: &str) -> Result<Person<'_>, ParseErrorKind> {
+    let mut fields = Fields { rest: Some(line) };
+    let name = fields
+        .next()
+        .ok_or(ParseErrorKind::MissingField("name"))??;
+    let num_field = fields.next().ok_or(ParseErrorKind::MissingField("num"))??;
+    // 남은 필드를 끝까지 읽어서, 닫히지 않은 따옴표 같은 오류를 필드 개수보다 먼저 알린다. (parse_owned와 같은 순서)
+    let extra: Vec<_> = fields.collect::<Result<_, _>>()?;
+    if !extra.is_empty() {
+        return Err(ParseErrorKind::TooManyFields);
+    }
+    let num = num_field
+        .parse::<i32>()
+        .map_err(|_| ParseErrorKind::InvalidNumber(num_field.into_owned()))?;
+    Ok(Person { name, num })
+}
+
+// 한 줄을 구분자로 잘라서 필드를 하나씩 돌려주는 반복자
+// rest가 None이면 더 이상 필드가 없다는 뜻이다. (빈 문자열 Some("")은 "빈 필드 하나"를 뜻한다.)
+struct Fields<'a> {
+    rest: Option<&'a str>,
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = Result<Cow<'a, str>, ParseErrorKind>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.rest?;
+        let start = s.len() - s.trim_start().len();
+        let bytes = s.as_bytes();
+
+        if bytes.get(start) != Some(&QUOTE) {
+            // 따옴표가 없는 필드: 다음 구분자까지 그대로 빌려온다.
+            let end = bytes
+                .iter()
+                .position(|b| *b == DELIMITER)
+                .unwrap_or(s.len());
+            let field = &s[..end];
+            if field.as_bytes().contains(&QUOTE) {
+                self.rest = None;
+                return Some(Err(ParseErrorKind::UnexpectedQuote));
+            }
+            self.rest = if end < s.len() {
+                Some(&s[end + 1..])
+            } else {
+                None
+            };
+            return Some(Ok(Cow::Borrowed(field.trim())));
+        }
+
+        // 따옴표로 감싼 필드: 닫는 따옴표를 찾는다. ""는 따옴표 글자 하나다.
+        let body = start + 1;
+        let mut i = body;
+        let mut escaped = false;
+        let close = loop {
+            match bytes.get(i) {
+                None => {
+                    self.rest = None;
+                    return Some(Err(ParseErrorKind::UnterminatedQuote));
+                }
+                Some(&QUOTE) if bytes.get(i + 1) == Some(&QUOTE) => {
+                    escaped = true;
+                    i += 2;
+                }
+                Some(&QUOTE) => break i,
+                Some(_) => i += 1,
+            }
+        };
+
+        // 닫는 따옴표 뒤에는 공백 다음에 구분자나 줄 끝만 올 수 있다.
+        let after = &s[close + 1..];
+        let after_trimmed = after.trim_start();
+        self.rest = match after_trimmed.as_bytes().first() {
+            None => None,
+            Some(&DELIMITER) => Some(&after_trimmed[1..]),
+            Some(_) => {
+                self.rest = None;
+                return Some(Err(ParseErrorKind::UnexpectedQuote));
+            }
+        };
+
+        let raw = &s[body..close];
+        if escaped {
+            Some(Ok(Cow::Owned(raw.replace("\"\"", "\""))))
+        } else {
+            Some(Ok(Cow::Borrowed(raw)))
+        }
+    }
+}
+
+// 벤치마크 비교용: 같은 형식을 읽지만 이름을 항상 String으로 복사한다.
+pub fn parse_owned(input: &str) -> Vec<Result<OwnedPerson, ParseError>> {
+    let mut out = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let fields: Result<Vec<String>, ParseErrorKind> = Fields { rest: Some(line) }
+            .map(|f| f.map(|c| c.to_string()))
+            .collect();
+        let result = fields.and_then(|fields| match fields.as_slice() {
+            [] => Err(ParseErrorKind::MissingField("name")),
+            [_] => Err(ParseErrorKind::MissingField("num")),
+            [name, num] => num
+                .parse::<i32>()
+                .map(|num| OwnedPerson {
+                    name: name.clone(),
+                    num,
+                })
+                .map_err(|_| ParseErrorKind::InvalidNumber(num.clone())),
+            _ => Err(ParseErrorKind::TooManyFields),
+        });
+        out.push(result.map_err(|kind| ParseError { line: i + 1, kind }));
+    }
+    out
+}
+
+// 빌려온 문자열이 정말로 입력 버퍼 안을 가리키는지 주소로 확인한다.
+#[cfg(test)]
+fn points_into(input: &str, s: &str) -> bool {
+    let start = input.as_ptr() as usize;
+    let p = s.as_ptr() as usize;
+    p >= start && p + s.len() <= start + input.len()
+}
+
+#[test]
+fn parses_plain_records_without_copying() {
+    let input = "Jeff,10\nAlice, 20\n  Bob ,-3\n";
+    let people: Vec<Person> = Records::parse(input).map(|r| r.unwrap()).collect();
+    assert_eq!(people.len(), 3);
+    assert_eq!(people[0].name, "Jeff");
+    assert_eq!(people[1].num, 20);
+    assert_eq!(people[2].name, "Bob");
+    assert_eq!(people[2].num, -3);
+    for p in &people {
+        match &p.name {
+            Cow::Borrowed(s) => assert!(points_into(input, s)),
+            Cow::Owned(_) => panic!("{} should be borrowed", p.name),
+        }
+    }
+}
+
+#[test]
+fn quoted_fields_borrow_unless_unescaping() {
+    let input = "\"Kim, Jr.\",20\n\"The \"\"Rock\"\"\",30\n\"\",0\n";
+    let people: Vec<Person> = Records::parse(input).map(|r| r.unwrap()).collect();
+
+    assert_eq!(people[0].name, "Kim, Jr.");
+    assert!(matches!(&people[0].name, Cow::Borrowed(s) if points_into(input, s)));
+
+    assert_eq!(people[1].name, "The \"Rock\"");
+    assert!(matches!(people[1].name, Cow::Owned(_)));
+
+    assert_eq!(people[2].name, "");
+    assert_eq!(people[2].num, 0);
+}
+
+#[test]
+fn multibyte_names() {
+    let input = "홍길동,1\n\"김, 철수\",2\n";
+    let people: Vec<Person> = Records::parse(input).map(|r| r.unwrap()).collect();
+    assert_eq!(people[0].name, "홍길동");
+    assert_eq!(people[1].name, "김, 철수");
+}
+
+#[test]
+fn skips_blank_and_comment_lines_but_keeps_line_numbers() {
+    let input = "# people\n\nJeff,10\n\nAlice,x\n";
+    let results: Vec<_> = Records::parse(input).collect();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].as_ref().unwrap().name, "Jeff");
+    assert_eq!(
+        results[1],
+        Err(ParseError {
+            line: 5,
+            kind: ParseErrorKind::InvalidNumber("x".to_string())
+        })
+    );
+}
+
+#[test]
+fn error_kinds() {
+    let kind = |line: &str| Records::parse(line).next().unwrap().unwrap_err().kind;
+    assert_eq!(kind("Jeff"), ParseErrorKind::MissingField("num"));
+    assert_eq!(kind("Jeff,1,2"), ParseErrorKind::TooManyFields);
+    assert_eq!(kind("\"Jeff,1"), ParseErrorKind::UnterminatedQuote);
+    assert_eq!(kind("\"Jeff\"x,1"), ParseErrorKind::UnexpectedQuote);
+    assert_eq!(kind("Je\"ff,1"), ParseErrorKind::UnexpectedQuote);
+    assert_eq!(kind("Jeff,"), ParseErrorKind::InvalidNumber(String::new()));
+    assert_eq!(
+        Records::parse("a,1\nb,2,3")
+            .nth(1)
+            .unwrap()
+            .unwrap_err()
+            .to_string(),
+        "line 2: too many fields"
+    );
+}
+
+#[test]
+fn owned_parser_agrees_with_borrowed() {
+    let input = "Jeff,10\n\"Kim, Jr.\",20\n\"The \"\"Rock\"\"\",30\nbad\n# c\nAlice,1,2\n\
+                 a,1,\"x\na,1,2,\"x\na,1,x\"\n";
+    let borrowed: Vec<_> = Records::parse(input)
+        .map(|r| r.map(Person::into_owned))
+        .collect();
+    assert_eq!(borrowed, parse_owned(input));
+    assert_eq!(
+        borrowed[4..]
+            .iter()
+            .map(|r| &r.as_ref().unwrap_err().kind)
+            .collect::<Vec<_>>(),
+        [
+            &ParseErrorKind::TooManyFields,
+            &ParseErrorKind::UnterminatedQuote,
+            &ParseErrorKind::UnterminatedQuote,
+            &ParseErrorKind::UnexpectedQuote,
+        ]
+    );
+}
@@ -0,0 +1,336 @@
+// ?로 에러를 위임하면 최상위 함수에는 io::Error 같은 맨 밑바닥 에러만 올라온다.
+// "No such file or directory"라는 메시지만으로는 어떤 작업을 하다가 실패했는지 알 수가 없다.
+//
+// 그래서 에러가 위로 올라가는 길목마다 "지금 무엇을 하던 중이었는지"를 덧붙여 주는 방법을 쓴다.
+//   File::open("name.txt").context("opening name.txt")?;
+// 이렇게 하면 에러가 "opening name.txt: No such file or directory"처럼 원인의 사슬(chain)을 갖게 된다.
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error as StdError;
+use std::fmt;
+
+// 문맥(context) 메시지들과 맨 밑바닥 원인(root)을 함께 담는 에러 타입
+// contexts는 바깥쪽(나중에 붙인 것)이 앞에 오도록 저장한다.
+pub struct Error {
+    contexts: Vec<String>,
+    root: Option<Box<dyn StdError + Send + Sync + 'static>>,
+    backtrace: Backtrace,
+}
+
+impl Error {
+    // 원인 에러 없이 메시지만으로 에러를 만든다. Option의 None에 문맥을 붙일 때 사용
+    pub fn msg<C: fmt::Display>(context: C) -> Error {
+        Error {
+            contexts: vec![context.to_string()],
+            root: None,
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    // 이미 만들어진 에러에 바깥쪽 문맥을 하나 더 붙인다.
+    // 새 Error로 감싸지 않고 contexts 맨 앞에 끼워 넣기에, 사슬이 중첩되지 않고 한 줄로 유지된다.
+    pub fn context<C: fmt::Display>(mut self, context: C) -> Error {
+        self.contexts.insert(0, context.to_string());
+        self
+    }
+
+    // 바깥쪽 문맥부터 맨 밑바닥 원인까지, 각 단계의 메시지를 순서대로 돌려준다.
+    // root 에러가 source()를 갖고 있으면 그것도 따라 내려간다.
+    pub fn chain(&self) -> Vec<String> {
+        let mut v = self.contexts.clone();
+        let mut cause: Option<&(dyn StdError + 'static)> = match &self.root {
+            Some(e) => Some(e.as_ref()),
+            None => None,
+        };
+        while let Some(e) = cause {
+            v.push(e.to_string());
+            cause = e.source();
+        }
+        v
+    }
+
+    // 맨 밑바닥 원인 에러. downcast_ref로 원래 타입(io::Error 등)을 꺼내볼 수 있다.
+    pub fn root_cause(&self) -> Option<&(dyn StdError + Send + Sync + 'static)> {
+        self.root.as_deref()
+    }
+
+    // RUST_BACKTRACE=1 혹은 RUST_LIB_BACKTRACE=1 일 때만 백트레이스가 실제로 수집된다.
+    // 수집되지 않았으면 None을 리턴한다.
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        match self.backtrace.status() {
+            BacktraceStatus::Captured => Some(&self.backtrace),
+            _ => None,
+        }
+    }
+}
+
+// {}로 출력하면 사슬 전체를 ": "로 이어서 한 줄로 보여준다.
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.chain().join(": "))
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Error")
+            .field("chain", &self.chain())
+            .finish()
+    }
+}
+
+// Error 자체는 std::error::Error를 구현하지 않는다.
+// 구현하게 되면 아래의 From<E: StdError>와 From<T> for T가 겹쳐서 컴파일이 안된다.
+// 덕분에 ?만 써도 io::Error 등이 자동으로 Error로 바뀐다.
+impl<E> From<E> for Error
+where
+    E: StdError + Send + Sync + 'static,
+{
+    fn from(e: E) -> Error {
+        Error {
+            contexts: Vec::new(),
+            root: Some(Box::new(e)),
+            backtrace: Backtrace::capture(),
+        }
+    }
+}
+
+// Result와 Option에 .context(...), .with_context(|| ...)를 붙일 수 있게 해주는 확장 트레잇
+pub trait Context<T> {
+    // 문맥 메시지를 바로 넘긴다. 메시지를 만드는 비용이 싸면 이쪽을 쓴다.
+    fn context<C: fmt::Display>(self, context: C) -> Result<T, Error>;
+
+    // 에러가 났을 때만 클로저를 실행해서 메시지를 만든다. format!처럼 비용이 드는 경우에 쓴다.
+    fn with_context<C, F>(self, f: F) -> Result<T, Error>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C;
+}
+
+impl<T, E> Context<T> for Result<T, E>
+where
+    E: StdError + Send + Sync + 'static,
+{
+    fn context<C: fmt::Display>(self, context: C) -> Result<T, Error> {
+        self.map_err(|e| Error::from(e).context(context))
+    }
+
+    fn with_context<C, F>(self, f: F) -> Result<T, Error>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|e| Error::from(e).context(f()))
+    }
+}
+
+// 이미 Error인 경우는 새로 감싸지 않고 문맥만 추가한다.
+impl<T> Context<T> for Result<T, Error> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T, Error> {
+        self.map_err(|e| e.context(context))
+    }
+
+    fn with_context<C, F>(self, f: F) -> Result<T, Error>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|e| e.context(f()))
+    }
+}
+
+// None은 원인 에러가 없으니, 문맥 메시지 자체가 에러가 된다.
+impl<T> Context<T> for Option<T> {
+    fn context<C: fmt::Display>(self, context: C) -> Result<T, Error> {
+        self.ok_or_else(|| Error::msg(context))
+    }
+
+    fn with_context<C, F>(self, f: F) -> Result<T, Error>
+    where
+        C: fmt::Display,
+        F: FnOnce() -> C,
+    {
+        self.ok_or_else(|| Error::msg(f()))
+    }
+}
+
+// main에서 에러를 보기 좋게 출력하기 위한 타입
+// fn main() -> Result<(), Report> 로 선언하면, 에러로 끝날 때 Debug 형식으로 출력되기에
+// Debug도 Display와 똑같이 여러 줄 형식으로 출력하게 했다.
+//
+// Error: reading user name
+//
+// Caused by:
+//     0: opening name.txt
+//     1: No such file or directory (os error 2)
+pub struct Report(Error);
+
+impl Report {
+    pub fn error(&self) -> &Error {
+        &self.0
+    }
+}
+
+impl From<Error> for Report {
+    fn from(e: Error) -> Report {
+        Report(e)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chain = self.0.chain();
+        write!(f, "Error: {}", chain[0])?;
+        if chain.len() > 1 {
+            write!(f, "\n\nCaused by:")?;
+            for (i, msg) in chain[1..].iter().enumerate() {
+                write!(f, "\n    {}: {}", i, msg)?;
+            }
+        }
+        if let Some(bt) = self.0.backtrace() {
+            write!(f, "\n\nStack backtrace:\n{}", bt)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+// 테스트에서 source()를 갖는 중첩 에러를 흉내내기 위한 타입
+#[cfg(test)]
+#[derive(Debug)]
+struct ParseConfigError {
+    line: usize,
+    source: std::num::ParseIntError,
+}
+
+#[cfg(test)]
+impl fmt::Display for ParseConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid number at line {}", self.line)
+    }
+}
+
+#[cfg(test)]
+impl StdError for ParseConfigError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[test]
+fn context_on_io_error() {
+    fn open() -> Result<std::fs::File, Error> {
+        std::fs::File::open("this-file-does-not-exist.txt").context("opening name.txt")
+    }
+    let e = open().unwrap_err();
+    let chain = e.chain();
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain[0], "opening name.txt");
+    assert!(e.to_string().starts_with("opening name.txt: "));
+    let io = e
+        .root_cause()
+        .unwrap()
+        .downcast_ref::<std::io::Error>()
+        .unwrap();
+    assert_eq!(io.kind(), std::io::ErrorKind::NotFound);
+}
+
+#[test]
+fn nested_context_renders_full_chain() {
+    fn parse(s: &str) -> Result<i32, Error> {
+        Ok(s.parse::<i32>()?)
+    }
+    fn load(s: &str) -> Result<i32, Error> {
+        parse(s).context("parsing age field")
+    }
+    fn run(s: &str) -> Result<i32, Error> {
+        load(s).with_context(|| format!("loading user {}", 7))
+    }
+
+    let e = run("abc").unwrap_err();
+    assert_eq!(
+        e.chain(),
+        vec![
+            "loading user 7".to_string(),
+            "parsing age field".to_string(),
+            "invalid digit found in string".to_string(),
+        ]
+    );
+    assert_eq!(
+        e.to_string(),
+        "loading user 7: parsing age field: invalid digit found in string"
+    );
+    assert_eq!(run("42").unwrap(), 42);
+}
+
+#[test]
+fn chain_follows_source_of_root() {
+    let r: Result<(), ParseConfigError> = Err(ParseConfigError {
+        line: 3,
+        source: "x".parse::<u8>().unwrap_err(),
+    });
+    let e = r.context("reading config").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "reading config: invalid number at line 3: invalid digit found in string"
+    );
+}
+
+#[test]
+fn option_context() {
+    let v = [1, 2, 3];
+    assert_eq!(*v.first().context("empty list").unwrap(), 1);
+
+    let e = v.get(10).context("index 10 out of range").unwrap_err();
+    assert_eq!(e.chain(), vec!["index 10 out of range".to_string()]);
+    assert!(e.root_cause().is_none());
+
+    let e = v
+        .get(10)
+        .context("index 10 out of range")
+        .context("looking up item")
+        .unwrap_err();
+    assert_eq!(e.to_string(), "looking up item: index 10 out of range");
+}
+
+#[test]
+fn with_context_is_lazy() {
+    let mut called = 0;
+    let r: Result<i32, std::num::ParseIntError> = "5".parse::<i32>();
+    let v = r
+        .with_context(|| {
+            called += 1;
+            "never built"
+        })
+        .unwrap();
+    assert_eq!(v, 5);
+    assert_eq!(called, 0);
+}
+
+#[test]
+fn report_format() {
+    let e = "abc"
+        .parse::<i32>()
+        .context("parsing age field")
+        .context("loading user")
+        .unwrap_err();
+    let report = Report::from(e);
+    let text = format!("{}", report);
+    let expected = "Error: loading user\n\
+                    \n\
+                    Caused by:\n    \
+                    0: parsing age field\n    \
+                    1: invalid digit found in string";
+    // 백트레이스가 수집된 환경(RUST_BACKTRACE=1)에서는 뒤에 스택이 더 붙는다.
+    assert!(text.starts_with(expected), "{}", text);
+    assert_eq!(format!("{:?}", report), text);
+
+    let single = Report::from(Error::msg("nothing to do"));
+    assert!(format!("{}", single).starts_with("Error: nothing to do"));
+    assert!(!format!("{}", single).contains("Caused by"));
+}
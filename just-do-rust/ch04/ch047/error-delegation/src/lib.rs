@@ -0,0 +1,3 @@
+// ?로 위임되는 에러에 문맥(context)을 붙이는 모듈
+// main.rs에서는 error_delegation::context::... 로 가져다 쓴다.
+pub mod context;
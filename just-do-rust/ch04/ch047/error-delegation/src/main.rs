@@ -19,9 +19,11 @@
 // 에러를 상위로 전파하는 키워드는 ?다. 아래 예제는 ?를 이용해서 에러를 상위로 위임하는 예제
 
 use std::fs::File;
-use std::io::{Error, Read};
+use std::io::Read;
 
-fn read_name() -> Result<String, Error> {
+use error_delegation::context::{Context, Error, Report};
+
+fn read_name() -> Result<String, std::io::Error> {
     // read_name 함수의 리턴 타입을 Result로 했다.
     let mut name = String::new();
     File::open("name.txt")?.read_to_string(&mut name)?;
@@ -32,6 +34,26 @@ fn read_name() -> Result<String, Error> {
     Ok(name)
 }
 
+// 위 read_name은 실패해도 io::Error만 올라오기 때문에, main에서는 무엇을 하다가 실패했는지 알 수 없다.
+// context 모듈의 Context 트레잇을 쓰면 ?로 위임하면서 각 단계에 문맥을 붙일 수 있다.
+fn read_name_with_context() -> Result<String, Error> {
+    let mut name = String::new();
+    File::open("name.txt")
+        .context("opening name.txt")?
+        .read_to_string(&mut name)
+        .context("reading name.txt")?;
+    Ok(name)
+}
+
+fn greeting() -> Result<String, Error> {
+    let name = read_name_with_context().context("loading user name")?;
+    let first = name
+        .split_whitespace()
+        .next()
+        .with_context(|| format!("name.txt has no name in {:?}", name))?;
+    Ok(format!("Hello, {}!", first))
+}
+
 fn main() {
     let result = match read_name() {
         Ok(n) => n,
@@ -41,4 +63,11 @@ fn main() {
         }
     };
     println!("{}", result);
+
+    // Report로 감싸서 출력하면 문맥부터 원인까지 사슬 전체가 보인다. 에러이므로 stderr로 보낸다.
+    // fn main() -> Result<(), Report>로 선언해서 ?로 올려 보내도 같은 형식으로 stderr에 출력된다.
+    match greeting() {
+        Ok(s) => println!("{}", s),
+        Err(e) => eprintln!("{}", Report::from(e)),
+    }
 }
@@ -24,6 +24,8 @@ try~catch 구문에서 에러가 발생하면 Exception을 캐치해서 에러
 
 use std::{fs::File, io::Write};
 
+use error_delegation::context::{Context, Error, Report};
+
 fn main() {
     // let file = File::open("hello.txt");
     //
@@ -58,6 +60,31 @@ fn main() {
     // 위에서의 예는 모두 Result와 Option으로 리턴될 때 match를 이용해서 정상으로 리턴되었는지
     // 에러 혹은 None으로 리턴되었는지를 판별하고, 판별된 것에 따른 루틴을 실행하는 방식을 사용했다.
     // 실제 코드를 짜다보면 이 방법보다는 unwrap, expect등 Result와 Option에서 제공되는 메서드들을 많이 이용
+
+    // 5. 에러를 위로 보내면서 문맥 붙이기 (error-delegation의 context 모듈)
+    // 2번의 write_file은 그 자리에서 출력하고 끝내기 때문에, 부른 쪽은 실패했는지조차 알 수 없다.
+    // Result를 리턴하고 ?로 위임하되, 무엇을 하던 중이었는지를 붙여서 올려 보낸다.
+    if let Err(e) = write_file_with_context("no-such-dir/foo.txt") {
+        eprintln!("{}", Report::from(e));
+    }
+    // 3번의 to_digit처럼 Option의 None에도 문맥을 붙여서 에러로 바꿀 수 있다.
+    match first_digit("abc") {
+        Ok(n) => println!("first digit: {}", n),
+        Err(e) => eprintln!("{}", Report::from(e)),
+    }
+}
+
+fn write_file_with_context(f_name: &str) -> Result<(), Error> {
+    let mut f = File::create(f_name).with_context(|| format!("creating {}", f_name))?;
+    f.write_all(b"Hello, world!")
+        .with_context(|| format!("writing {}", f_name))?;
+    Ok(())
+}
+
+fn first_digit(s: &str) -> Result<u32, Error> {
+    s.chars()
+        .find_map(|c| c.to_digit(10))
+        .with_context(|| format!("no digit in {:?}", s))
 }
 
 fn write_file(f_name: &str) {
@@ -71,3 +98,17 @@ fn write_file(f_name: &str) {
     };
     let _ = f.write_all(b"Hello, world!");
 }
+
+#[test]
+fn errors_carry_context() {
+    let e = write_file_with_context("no-such-dir/foo.txt").unwrap_err();
+    let chain = e.chain();
+    assert_eq!(chain[0], "creating no-such-dir/foo.txt");
+    assert_eq!(chain.len(), 2);
+
+    assert_eq!(first_digit("010-1234-5678").unwrap(), 0);
+    assert_eq!(
+        first_digit("abc").unwrap_err().to_string(),
+        "no digit in \"abc\""
+    );
+}
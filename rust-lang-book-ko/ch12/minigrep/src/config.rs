@@ -0,0 +1,267 @@
+// 커맨드 라인 인수를 Config로 만든다. (ch12.md 4절, just-do-rust ch05/ch051/input-cmd의 ArgsConfigs)
+//
+//   minigrep [옵션] 검색어 경로...
+//
+//   -i, --ignore-case     대소문자 무시 (환경 변수 IGNORE_CASE가 있어도 켜진다)
+//   -n, --line-number     줄 번호를 붙인다
+//   -C N, --context N     맞는 줄의 앞뒤 N줄도 보여 준다
+//   -c, --count           줄 대신 파일마다 맞는 줄의 개수만
+//   -v, --invert-match    맞지 않는 줄을 보여 준다
+//   -r, --recursive       경로가 디렉터리면 그 안의 파일을 모두 찾는다
+//   -W, --wildcard        검색어의 *와 ?를 와일드카드로 쓴다
+//
+// 짧은 옵션은 -in 처럼 붙여 쓸 수 있고, -C2 / --context=2 처럼 값을 붙여 써도 된다.
+// -- 뒤의 인수는 -로 시작해도 옵션이 아니다. (minigrep -- -v file.txt)
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::matcher::Matcher;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Config {
+    pub query: String,
+    pub paths: Vec<PathBuf>,
+    pub ignore_case: bool,
+    pub line_numbers: bool,
+    pub context: usize,
+    pub count_only: bool,
+    pub invert: bool,
+    pub recursive: bool,
+    pub wildcard: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    MissingQuery,
+    MissingPath,
+    UnknownOption(String),
+    // 값이 필요한 옵션(-C) 뒤에 값이 없다
+    MissingValue(String),
+    InvalidNumber { option: String, value: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::MissingQuery => write!(f, "not enough arguments: missing query"),
+            ConfigError::MissingPath => write!(f, "not enough arguments: missing file path"),
+            ConfigError::UnknownOption(opt) => write!(f, "unknown option {}", opt),
+            ConfigError::MissingValue(opt) => write!(f, "option {} needs a value", opt),
+            ConfigError::InvalidNumber { option, value } => {
+                write!(f, "invalid number {:?} for {}", value, option)
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+pub const USAGE: &str = "usage: minigrep [-i] [-n] [-C N] [-c] [-v] [-r] [-W] QUERY PATH...";
+
+fn parse_context(option: &str, value: Option<String>) -> Result<usize, ConfigError> {
+    let value = value.ok_or_else(|| ConfigError::MissingValue(option.to_string()))?;
+    value.parse().map_err(|_| ConfigError::InvalidNumber {
+        option: option.to_string(),
+        value,
+    })
+}
+
+impl Config {
+    // 책의 Config::build처럼 env::args()를 그대로 받는다. 첫 인수(프로그램 이름)는 건너뛴다.
+    pub fn build(args: impl IntoIterator<Item = String>) -> Result<Config, ConfigError> {
+        Config::parse(args, env::var("IGNORE_CASE").is_ok())
+    }
+
+    // build에서 환경 변수를 읽는 부분만 뺀 것. ignore_case는 -i가 없을 때의 기본값
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+        ignore_case: bool,
+    ) -> Result<Config, ConfigError> {
+        let mut config = Config {
+            ignore_case,
+            ..Config::default()
+        };
+        let mut positional = Vec::new();
+        let mut args = args.into_iter().skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                positional.extend(args.by_ref());
+                break;
+            }
+            if let Some(long) = arg.strip_prefix("--") {
+                let (name, value) = match long.split_once('=') {
+                    Some((name, value)) => (name, Some(value.to_string())),
+                    None => (long, None),
+                };
+                match name {
+                    "ignore-case" => config.ignore_case = true,
+                    "line-number" => config.line_numbers = true,
+                    "count" => config.count_only = true,
+                    "invert-match" => config.invert = true,
+                    "recursive" => config.recursive = true,
+                    "wildcard" => config.wildcard = true,
+                    "context" => {
+                        config.context = parse_context("--context", value.or_else(|| args.next()))?
+                    }
+                    _ => return Err(ConfigError::UnknownOption(arg)),
+                }
+                continue;
+            }
+            // "-"만 있는 것은 옵션이 아니라 경로로 본다.
+            let Some(flags) = arg.strip_prefix('-').filter(|f| !f.is_empty()) else {
+                positional.push(arg);
+                continue;
+            };
+            for (i, flag) in flags.char_indices() {
+                match flag {
+                    'i' => config.ignore_case = true,
+                    'n' => config.line_numbers = true,
+                    'c' => config.count_only = true,
+                    'v' => config.invert = true,
+                    'r' => config.recursive = true,
+                    'W' => config.wildcard = true,
+                    'C' => {
+                        // -C2 처럼 붙은 값, 없으면 다음 인수
+                        let rest = &flags[i + 1..];
+                        let value = if rest.is_empty() {
+                            args.next()
+                        } else {
+                            Some(rest.to_string())
+                        };
+                        config.context = parse_context("-C", value)?;
+                        break;
+                    }
+                    _ => return Err(ConfigError::UnknownOption(format!("-{}", flag))),
+                }
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        config.query = positional.next().ok_or(ConfigError::MissingQuery)?;
+        config.paths = positional.map(PathBuf::from).collect();
+        if config.paths.is_empty() {
+            return Err(ConfigError::MissingPath);
+        }
+        Ok(config)
+    }
+
+    pub fn matcher(&self) -> Matcher {
+        if self.wildcard {
+            Matcher::wildcard(&self.query, self.ignore_case)
+        } else {
+            Matcher::fixed(&self.query, self.ignore_case)
+        }
+    }
+}
+
+#[cfg(test)]
+fn args(line: &str) -> Vec<String> {
+    std::iter::once("minigrep")
+        .chain(line.split_whitespace())
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn book_style_arguments() {
+    let config = Config::parse(args("to poem.txt"), false).unwrap();
+    assert_eq!(config.query, "to");
+    assert_eq!(config.paths, [PathBuf::from("poem.txt")]);
+    assert!(!config.ignore_case && !config.line_numbers && !config.recursive);
+    assert_eq!(config.context, 0);
+
+    // IGNORE_CASE 환경 변수가 있을 때
+    assert!(
+        Config::parse(args("to poem.txt"), true)
+            .unwrap()
+            .ignore_case
+    );
+
+    assert_eq!(
+        Config::parse(args(""), false),
+        Err(ConfigError::MissingQuery)
+    );
+    assert_eq!(
+        Config::parse(args("to"), false),
+        Err(ConfigError::MissingPath)
+    );
+    assert_eq!(
+        ConfigError::MissingPath.to_string(),
+        "not enough arguments: missing file path"
+    );
+}
+
+#[test]
+fn options() {
+    let config = Config::parse(args("-rn -C 2 --invert-match fn src lib.rs"), false).unwrap();
+    assert!(config.recursive && config.line_numbers && config.invert);
+    assert!(!config.count_only && !config.wildcard);
+    assert_eq!(config.context, 2);
+    assert_eq!(config.query, "fn");
+    assert_eq!(
+        config.paths,
+        [PathBuf::from("src"), PathBuf::from("lib.rs")]
+    );
+
+    let config = Config::parse(args("-icWC3 a*b x"), false).unwrap();
+    assert!(config.ignore_case && config.count_only && config.wildcard);
+    assert_eq!(config.context, 3);
+    let config = Config::parse(args("--context=4 --count --wildcard q f"), false).unwrap();
+    assert_eq!(
+        (config.context, config.count_only, config.wildcard),
+        (4, true, true)
+    );
+    let config = Config::parse(args("q --ignore-case --recursive --line-number f"), false).unwrap();
+    assert!(config.ignore_case && config.recursive && config.line_numbers);
+
+    // -- 뒤와 - 하나는 옵션이 아니다
+    let config = Config::parse(args("-n -- -v -"), false).unwrap();
+    assert_eq!(config.query, "-v");
+    assert_eq!(config.paths, [PathBuf::from("-")]);
+    assert!(!config.invert);
+}
+
+#[test]
+fn option_errors() {
+    let table = [
+        ("-x q f", ConfigError::UnknownOption("-x".to_string())),
+        ("-nx q f", ConfigError::UnknownOption("-x".to_string())),
+        (
+            "--colour q f",
+            ConfigError::UnknownOption("--colour".to_string()),
+        ),
+        ("q f -C", ConfigError::MissingValue("-C".to_string())),
+        (
+            "q f --context",
+            ConfigError::MissingValue("--context".to_string()),
+        ),
+        (
+            "-C two q f",
+            ConfigError::InvalidNumber {
+                option: "-C".to_string(),
+                value: "two".to_string(),
+            },
+        ),
+        (
+            "--context=-1 q f",
+            ConfigError::InvalidNumber {
+                option: "--context".to_string(),
+                value: "-1".to_string(),
+            },
+        ),
+    ];
+    for (line, expected) in table {
+        assert_eq!(Config::parse(args(line), false), Err(expected), "{}", line);
+    }
+    assert_eq!(
+        ConfigError::InvalidNumber {
+            option: "-C".to_string(),
+            value: "two".to_string()
+        }
+        .to_string(),
+        "invalid number \"two\" for -C"
+    );
+}
@@ -0,0 +1,7 @@
+// ch12.md의 minigrep을 넓힌 것
+// - config: 커맨드 라인 인수 (-i -n -C -c -v -r -W)를 Config로 만든다
+// - matcher: 고정 문자열과 *, ? 와일드카드로 한 줄이 맞는지 본다 (정규식은 쓰지 않는다)
+// - search: 책의 search 함수들과, 디렉터리를 돌며 바이너리 파일을 건너뛰고 grep처럼 출력하는 run
+pub mod config;
+pub mod matcher;
+pub mod search;
@@ -0,0 +1,32 @@
+// ch12.md의 minigrep
+//
+//   cargo run -- to poem.txt
+//   IGNORE_CASE=1 cargo run -- to poem.txt
+//   cargo run -- -rn -C 1 -W "fn *(" src
+//
+// 인수 오류와 파일 오류는 eprintln!으로 stderr에 쓴다. (cargo run -- to poem.txt > output.txt 해도 화면에 보인다)
+// 끝내는 코드는 grep과 같다: 찾은 줄이 있으면 0, 없으면 1, 오류가 있었으면 2
+
+use std::env;
+use std::process;
+
+use minigrep::config::{Config, USAGE};
+use minigrep::search;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {}", err);
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    });
+
+    match search::run(&config) {
+        Ok(summary) if summary.errors > 0 => process::exit(2),
+        Ok(summary) if summary.lines_matched == 0 => process::exit(1),
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Application error: {}", e);
+            process::exit(2);
+        }
+    }
+}
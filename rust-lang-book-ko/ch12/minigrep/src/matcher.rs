@@ -0,0 +1,229 @@
+// 한 줄이 검색어와 맞는지 판단한다. 정규식 없이 두 가지 방식만 쓴다.
+//
+// - 고정 문자열(기본): 책의 search처럼 line.contains(query)
+// - 와일드카드(-W): *는 아무 글자 0개 이상, ?는 아무 글자 1개. 줄의 어느 부분과 맞아도 된다.
+//   "fn *(" 은 "pub fn main() {" 과 맞는다. \*, \?, \\ 로 글자 그대로의 *, ?, \ 를 쓸 수 있다.
+//
+// 대소문자 무시(-i, IGNORE_CASE)는 책의 search_case_insensitive처럼 양쪽을 to_lowercase해서 비교한다.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Char(char),
+    // ?
+    Any,
+    // *
+    Star,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Pattern {
+    Fixed(String),
+    Wildcard(Vec<Token>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matcher {
+    pattern: Pattern,
+    ignore_case: bool,
+}
+
+fn tokenize(pattern: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '*' => Token::Star,
+            '?' => Token::Any,
+            // 끝에 홀로 남은 \는 글자 그대로
+            '\\' => Token::Char(chars.next().unwrap_or('\\')),
+            c => Token::Char(c),
+        };
+        // 연달아 나온 *는 하나와 같다.
+        if !(token == Token::Star && tokens.last() == Some(&Token::Star)) {
+            tokens.push(token);
+        }
+    }
+    tokens
+}
+
+// tokens가 text 전체와 맞는지. *를 만나면 그 자리를 기억해 두고, 뒤에서 어긋나면
+// *가 한 글자 더 먹은 것으로 하고 다시 해 본다. (되돌아가는 곳은 마지막 * 하나뿐이라 O(n * m))
+fn wildcard_match(tokens: &[Token], text: &[char]) -> bool {
+    let (mut t, mut p) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match tokens.get(p) {
+            Some(Token::Star) => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(Token::Any) => {
+                t += 1;
+                p += 1;
+            }
+            Some(Token::Char(c)) if *c == text[t] => {
+                t += 1;
+                p += 1;
+            }
+            _ => match backtrack {
+                Some((star, start)) => {
+                    p = star + 1;
+                    t = start + 1;
+                    backtrack = Some((star, start + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    tokens[p..].iter().all(|tok| *tok == Token::Star)
+}
+
+impl Matcher {
+    pub fn fixed(query: &str, ignore_case: bool) -> Matcher {
+        let query = if ignore_case {
+            query.to_lowercase()
+        } else {
+            query.to_string()
+        };
+        Matcher {
+            pattern: Pattern::Fixed(query),
+            ignore_case,
+        }
+    }
+
+    pub fn wildcard(pattern: &str, ignore_case: bool) -> Matcher {
+        let pattern = if ignore_case {
+            pattern.to_lowercase()
+        } else {
+            pattern.to_string()
+        };
+        // 줄의 어느 부분과 맞아도 되므로 앞뒤에 *를 붙여서 줄 전체와 맞춰 본다.
+        let mut tokens = vec![Token::Star];
+        tokens.extend(tokenize(&pattern));
+        if tokens.last() != Some(&Token::Star) {
+            tokens.push(Token::Star);
+        }
+        if tokens.get(1) == Some(&Token::Star) {
+            tokens.remove(0);
+        }
+        Matcher {
+            pattern: Pattern::Wildcard(tokens),
+            ignore_case,
+        }
+    }
+
+    pub fn is_match(&self, line: &str) -> bool {
+        let lowered;
+        let line = if self.ignore_case {
+            lowered = line.to_lowercase();
+            lowered.as_str()
+        } else {
+            line
+        };
+        match &self.pattern {
+            Pattern::Fixed(query) => line.contains(query.as_str()),
+            Pattern::Wildcard(tokens) => {
+                let text: Vec<char> = line.chars().collect();
+                wildcard_match(tokens, &text)
+            }
+        }
+    }
+}
+
+#[test]
+fn fixed_strings() {
+    let m = Matcher::fixed("duct", false);
+    assert!(m.is_match("safe, fast, productive."));
+    assert!(!m.is_match("Duct tape."));
+    // *와 ?는 고정 문자열에서는 글자 그대로
+    let m = Matcher::fixed("a*b", false);
+    assert!(m.is_match("x a*b y"));
+    assert!(!m.is_match("axxb"));
+    assert!(Matcher::fixed("", false).is_match("anything"));
+    assert!(Matcher::fixed("", false).is_match(""));
+}
+
+#[test]
+fn ignore_case() {
+    let m = Matcher::fixed("rUsT", true);
+    assert!(m.is_match("Rust:"));
+    assert!(m.is_match("Trust me."));
+    assert!(!m.is_match("rus t"));
+    let m = Matcher::wildcard("R?ST", true);
+    assert!(m.is_match("trust"));
+    // 한글과 같이 대소문자가 없는 글자도 그대로 된다
+    assert!(Matcher::fixed("러스트", true).is_match("안녕 러스트"));
+}
+
+#[test]
+fn wildcards() {
+    let cases = [
+        ("fn *(", "pub fn main() {", true),
+        ("fn *(", "fn(", false),
+        ("fn*(", "fn(", true),
+        ("a?c", "xxabcxx", true),
+        ("a?c", "ac", false),
+        ("a*c", "ac", true),
+        ("a*b*c", "a---b---c", true),
+        ("a*b*c", "a---c---b", false),
+        ("*", "", true),
+        ("?", "", false),
+        ("?", "한", true),
+        ("ab*ab*ab", "abababab", true),
+        ("ab*ab*ab", "ababa", false),
+        ("a**b", "ab", true),
+        ("\\*", "2 * 3", true),
+        ("\\*", "2 x 3", false),
+        ("\\?\\\\", "why?\\", true),
+        ("end\\", "the end\\", true),
+    ];
+    for (pattern, line, expected) in cases {
+        assert_eq!(
+            Matcher::wildcard(pattern, false).is_match(line),
+            expected,
+            "{:?} in {:?}",
+            pattern,
+            line
+        );
+    }
+}
+
+#[test]
+fn wildcard_matches_brute_force() {
+    // 작은 알파벳에서 모든 경우를 재귀로 확인한 것과 같은지
+    fn brute(p: &[Token], t: &[char]) -> bool {
+        match p.split_first() {
+            None => t.is_empty(),
+            Some((Token::Star, rest)) => (0..=t.len()).any(|i| brute(rest, &t[i..])),
+            Some((Token::Any, rest)) => !t.is_empty() && brute(rest, &t[1..]),
+            Some((Token::Char(c), rest)) => t.first() == Some(c) && brute(rest, &t[1..]),
+        }
+    }
+    // 길이가 max_len 이하인, alphabet으로 만들 수 있는 모든 문자열
+    fn all_strings(alphabet: &[char], max_len: usize) -> Vec<String> {
+        let mut out = vec![String::new()];
+        let mut last = vec![String::new()];
+        for _ in 0..max_len {
+            last = last
+                .iter()
+                .flat_map(|s| alphabet.iter().map(move |c| format!("{}{}", s, c)))
+                .collect();
+            out.extend(last.iter().cloned());
+        }
+        out
+    }
+    let texts = all_strings(&['a', 'b'], 5);
+    for pattern in all_strings(&['a', 'b', '*', '?'], 4) {
+        let tokens = tokenize(&pattern);
+        for text in &texts {
+            let text: Vec<char> = text.chars().collect();
+            assert_eq!(
+                wildcard_match(&tokens, &text),
+                brute(&tokens, &text),
+                "{:?} vs {:?}",
+                pattern,
+                text
+            );
+        }
+    }
+}
@@ -0,0 +1,491 @@
+// 파일을 읽어서 맞는 줄을 찾고 출력한다. (ch12.md 3절 ~ 7절)
+//
+// 책의 search / search_case_insensitive는 그대로 두고, run은 여기에 더해서
+// - 경로가 디렉터리면 -r일 때 그 안을 이름 순서로 모두 찾는다. (디렉터리 심볼릭 링크는 따라가지 않는다)
+// - 앞쪽 8000바이트 안에 0바이트가 있으면 바이너리 파일로 보고 건너뛴다. (git이 쓰는 방법과 같다)
+// - UTF-8이 아닌 바이트는 �로 바꿔서 읽는다.
+// - 출력은 grep과 같은 모양이다. 파일이 여럿이거나 -r이면 "경로:" 를, -n이면 "줄번호:" 를 앞에 붙이고,
+//   -C로 보여 주는 앞뒤 줄은 : 대신 - 를 쓴다. 떨어져 있는 묶음 사이에는 "--" 줄을 넣는다.
+// - 파일을 못 읽는 것 같은 오류는 stderr에 쓰고 다음 파일로 넘어간다.
+
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::matcher::Matcher;
+
+// 책의 search: query가 들어 있는 줄들
+pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let mut results = Vec::new();
+    for line in contents.lines() {
+        if line.contains(query) {
+            results.push(line);
+        }
+    }
+    results
+}
+
+// 책의 search_case_insensitive
+pub fn search_case_insensitive<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let query = query.to_lowercase();
+    let mut results = Vec::new();
+    for line in contents.lines() {
+        if line.to_lowercase().contains(&query) {
+            results.push(line);
+        }
+    }
+    results
+}
+
+// 이 크기 안에서 0바이트를 찾는다.
+const BINARY_PROBE: usize = 8000;
+
+pub fn is_binary(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(BINARY_PROBE)].contains(&0)
+}
+
+// 고른 줄들의 번호(0부터). invert면 맞지 않는 줄들
+pub fn selected_lines(matcher: &Matcher, lines: &[&str], invert: bool) -> Vec<usize> {
+    (0..lines.len())
+        .filter(|&i| matcher.is_match(lines[i]) != invert)
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub files_searched: usize,
+    // 고른 줄이 하나라도 있는 파일 수
+    pub files_matched: usize,
+    pub lines_matched: usize,
+    pub binary_skipped: usize,
+    pub errors: usize,
+}
+
+// 표준 출력과 표준 에러에 쓴다.
+pub fn run(config: &Config) -> Result<Summary, Box<dyn Error>> {
+    let stdout = io::stdout();
+    let stderr = io::stderr();
+    let summary = run_to(config, &mut stdout.lock(), &mut stderr.lock())?;
+    Ok(summary)
+}
+
+// 결과는 out에, 파일 오류는 err에 쓴다. 돌려주는 오류는 out/err에 쓰지 못했을 때뿐이다.
+pub fn run_to<W: Write, E: Write>(
+    config: &Config,
+    out: &mut W,
+    err: &mut E,
+) -> io::Result<Summary> {
+    let mut search = Search {
+        config,
+        matcher: config.matcher(),
+        with_names: config.recursive || config.paths.len() > 1,
+        printed_group: false,
+        out,
+        err,
+        summary: Summary::default(),
+    };
+    for path in &config.paths {
+        search.path(path)?;
+    }
+    Ok(search.summary)
+}
+
+struct Search<'a, W, E> {
+    config: &'a Config,
+    matcher: Matcher,
+    with_names: bool,
+    // -C일 때, 앞에서 이미 묶음을 출력했으면 다음 묶음 앞에 "--"를 넣는다.
+    printed_group: bool,
+    out: &'a mut W,
+    err: &'a mut E,
+    summary: Summary,
+}
+
+impl<W: Write, E: Write> Search<'_, W, E> {
+    fn report(&mut self, path: &Path, message: &dyn std::fmt::Display) -> io::Result<()> {
+        self.summary.errors += 1;
+        writeln!(self.err, "minigrep: {}: {}", path.display(), message)
+    }
+
+    fn path(&mut self, path: &Path) -> io::Result<()> {
+        match fs::metadata(path) {
+            Err(e) => self.report(path, &e),
+            Ok(meta) if meta.is_dir() => {
+                if self.config.recursive {
+                    self.dir(path)
+                } else {
+                    self.report(path, &"is a directory (use -r to search it)")
+                }
+            }
+            Ok(_) => self.file(path),
+        }
+    }
+
+    fn dir(&mut self, dir: &Path) -> io::Result<()> {
+        let entries = match fs::read_dir(dir).and_then(|rd| rd.collect::<io::Result<Vec<_>>>()) {
+            Ok(entries) => entries,
+            Err(e) => return self.report(dir, &e),
+        };
+        let mut entries: Vec<(PathBuf, fs::FileType)> = entries
+            .into_iter()
+            .filter_map(|e| Some((e.path(), e.file_type().ok()?)))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        for (path, file_type) in entries {
+            if file_type.is_dir() {
+                self.dir(&path)?;
+            } else if file_type.is_file() || (file_type.is_symlink() && path.is_file()) {
+                self.file(&path)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn file(&mut self, path: &Path) -> io::Result<()> {
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) => return self.report(path, &e),
+        };
+        if is_binary(&bytes) {
+            self.summary.binary_skipped += 1;
+            return Ok(());
+        }
+        self.summary.files_searched += 1;
+        let contents = String::from_utf8_lossy(&bytes);
+        let lines: Vec<&str> = contents.lines().collect();
+        let selected = selected_lines(&self.matcher, &lines, self.config.invert);
+        if !selected.is_empty() {
+            self.summary.files_matched += 1;
+            self.summary.lines_matched += selected.len();
+        }
+
+        let name = self.with_names.then(|| path.display().to_string());
+        if self.config.count_only {
+            return match name {
+                Some(name) => writeln!(self.out, "{}:{}", name, selected.len()),
+                None => writeln!(self.out, "{}", selected.len()),
+            };
+        }
+        self.print_lines(name.as_deref(), &lines, &selected)
+    }
+
+    fn print_line(
+        &mut self,
+        name: Option<&str>,
+        number: usize,
+        line: &str,
+        sep: char,
+    ) -> io::Result<()> {
+        if let Some(name) = name {
+            write!(self.out, "{}{}", name, sep)?;
+        }
+        if self.config.line_numbers {
+            write!(self.out, "{}{}", number, sep)?;
+        }
+        writeln!(self.out, "{}", line)
+    }
+
+    fn print_lines(
+        &mut self,
+        name: Option<&str>,
+        lines: &[&str],
+        selected: &[usize],
+    ) -> io::Result<()> {
+        let context = self.config.context;
+        let mut is_selected = vec![false; lines.len()];
+        for &i in selected {
+            is_selected[i] = true;
+        }
+        // 이 파일에서 마지막으로 출력한 줄
+        let mut last: Option<usize> = None;
+        for &i in selected {
+            let start = i.saturating_sub(context).max(last.map_or(0, |l| l + 1));
+            let end = (i + context).min(lines.len() - 1);
+            if start > end {
+                continue;
+            }
+            let new_group = last.is_none_or(|l| start > l + 1);
+            if context > 0 && new_group && self.printed_group {
+                writeln!(self.out, "--")?;
+            }
+            for (j, line) in lines.iter().enumerate().take(end + 1).skip(start) {
+                let sep = if is_selected[j] { ':' } else { '-' };
+                self.print_line(name, j + 1, line, sep)?;
+            }
+            self.printed_group = true;
+            last = Some(end);
+        }
+        Ok(())
+    }
+}
+
+// 테스트용 임시 디렉터리. 테스트가 끝나면(Drop) 지운다.
+#[cfg(test)]
+struct Fixture {
+    root: PathBuf,
+}
+
+#[cfg(test)]
+impl Fixture {
+    fn new(name: &str) -> Fixture {
+        let root = std::env::temp_dir().join(format!("{}-minigrep-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        Fixture { root }
+    }
+
+    fn file(&self, rel: &str, contents: impl AsRef<[u8]>) -> PathBuf {
+        let path = self.root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn path(&self, rel: &str) -> String {
+        self.root.join(rel).display().to_string()
+    }
+}
+
+#[cfg(test)]
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+// config로 run_to를 돌려서 (stdout, stderr, 요약)을 돌려준다.
+#[cfg(test)]
+fn grep(options: &[&str], query: &str, paths: &[&Path]) -> (String, String, Summary) {
+    let mut args = vec!["minigrep".to_string()];
+    args.extend(options.iter().map(|s| s.to_string()));
+    args.push("--".to_string());
+    args.push(query.to_string());
+    args.extend(paths.iter().map(|p| p.display().to_string()));
+    let config = Config::parse(args, false).unwrap();
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    let summary = run_to(&config, &mut out, &mut err).unwrap();
+    (
+        String::from_utf8(out).unwrap(),
+        String::from_utf8(err).unwrap(),
+        summary,
+    )
+}
+
+#[cfg(test)]
+const POEM: &str = "\
+I'm nobody! Who are you?
+Are you nobody, too?
+Then there's a pair of us - don't tell!
+They'd banish us, you know.
+
+How dreary to be somebody!
+How public, like a frog
+To tell your name the livelong day
+To an admiring bog!
+";
+
+#[test]
+fn book_search_functions() {
+    let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Duct tape.
+Trust me.";
+    assert_eq!(search("duct", contents), ["safe, fast, productive."]);
+    assert_eq!(
+        search_case_insensitive("rUsT", contents),
+        ["Rust:", "Trust me."]
+    );
+    assert!(search("monomorphization", contents).is_empty());
+}
+
+#[test]
+fn binary_detection() {
+    assert!(!is_binary(b"plain text\n"));
+    assert!(!is_binary("한글도 텍스트".as_bytes()));
+    assert!(is_binary(b"ELF\x00\x01"));
+    let mut late = vec![b'a'; BINARY_PROBE];
+    late.push(0);
+    // 앞쪽만 보므로 뒤늦게 나온 0바이트는 모른다
+    assert!(!is_binary(&late));
+    assert!(!is_binary(b""));
+}
+
+#[test]
+fn single_file_like_the_book() {
+    let fx = Fixture::new("single");
+    let poem = fx.file("poem.txt", POEM);
+    let (out, err, summary) = grep(&[], "to", &[&poem]);
+    assert_eq!(out, "Are you nobody, too?\nHow dreary to be somebody!\n");
+    assert_eq!(err, "");
+    assert_eq!(summary.lines_matched, 2);
+
+    let (out, _, _) = grep(&["-i"], "to", &[&poem]);
+    assert_eq!(out.lines().count(), 4);
+    assert!(out.contains("To tell your name") && out.contains("To an admiring bog!"));
+}
+
+#[test]
+fn line_numbers_context_and_separators() {
+    let fx = Fixture::new("context");
+    let poem = fx.file("poem.txt", POEM);
+    let (out, _, _) = grep(&["-n"], "nobody", &[&poem]);
+    assert_eq!(out, "1:I'm nobody! Who are you?\n2:Are you nobody, too?\n");
+
+    // 앞뒤 줄은 : 대신 -
+    let (out, _, _) = grep(&["-n", "-C", "1"], "frog", &[&poem]);
+    assert_eq!(
+        out,
+        "6-How dreary to be somebody!\n7:How public, like a frog\n8-To tell your name the livelong day\n"
+    );
+    let (out, _, _) = grep(&["-nC1"], "too", &[&poem]);
+    assert_eq!(
+        out,
+        "1-I'm nobody! Who are you?\n2:Are you nobody, too?\n3-Then there's a pair of us - don't tell!\n"
+    );
+
+    let (out, _, _) = grep(&["-n", "-C1"], "us", &[&poem]);
+    // 3, 4번 줄이 맞으면 앞뒤 줄이 겹치므로 한 묶음으로 합친다.
+    assert_eq!(
+        out,
+        "2-Are you nobody, too?\n3:Then there's a pair of us - don't tell!\n4:They'd banish us, you know.\n5-\n"
+    );
+
+    let (out, _, _) = grep(&["-n", "-C1"], "!", &[&poem]);
+    assert_eq!(
+        out,
+        "\
+1:I'm nobody! Who are you?
+2-Are you nobody, too?
+3:Then there's a pair of us - don't tell!
+4-They'd banish us, you know.
+5-
+6:How dreary to be somebody!
+7-How public, like a frog
+8-To tell your name the livelong day
+9:To an admiring bog!
+"
+    );
+
+    let (out, _, _) = grep(&["-C", "0", "-n"], "How", &[&poem]);
+    assert_eq!(
+        out,
+        "6:How dreary to be somebody!\n7:How public, like a frog\n"
+    );
+
+    let (out, _, _) = grep(&["-n", "-C", "1"], "I'm", &[&poem]);
+    assert_eq!(out, "1:I'm nobody! Who are you?\n2-Are you nobody, too?\n");
+    let (out, _, _) = grep(&["-C", "1"], "bog", &[&poem]);
+    assert_eq!(
+        out,
+        "To tell your name the livelong day\nTo an admiring bog!\n"
+    );
+
+    // 떨어져 있는 묶음 사이에는 --
+    let letters = fx.file("letters.txt", "a\nx\nb\nc\nd\nx\ne\n");
+    let (out, _, _) = grep(&["-n", "-C", "1"], "x", &[&letters]);
+    assert_eq!(out, "1-a\n2:x\n3-b\n--\n5-d\n6:x\n7-e\n");
+}
+
+#[test]
+fn invert_and_count() {
+    let fx = Fixture::new("invert");
+    let poem = fx.file("poem.txt", POEM);
+    let (out, _, summary) = grep(&["-v", "-n"], "o", &[&poem]);
+    // o가 없는 줄은 빈 줄뿐
+    assert_eq!(out, "5:\n");
+    assert_eq!(summary.lines_matched, 1);
+
+    let (out, _, _) = grep(&["-c"], "you", &[&poem]);
+    // "your"도 센다
+    assert_eq!(out, "4\n");
+    let (out, _, _) = grep(&["-c", "-v"], "you", &[&poem]);
+    assert_eq!(out, "5\n");
+    let (out, _, summary) = grep(&["-c"], "zebra", &[&poem]);
+    assert_eq!(out, "0\n");
+    assert_eq!((summary.files_searched, summary.files_matched), (1, 0));
+}
+
+#[test]
+fn recursive_search_skips_binary_files() {
+    let fx = Fixture::new("tree");
+    fx.file("b.txt", "needle in b\nhay\n");
+    fx.file("a.txt", "hay\nneedle in a\n");
+    fx.file("sub/c.md", "no match here\n");
+    fx.file("sub/deeper/d.txt", "a NEEDLE\r\nneedle again\r\n");
+    fx.file("sub/image.bin", b"needle\x00\x01\x02");
+    fx.file("sub/latin1.txt", b"caf\xe9 needle\n");
+
+    let (out, err, summary) = grep(&["-r"], "needle", &[&fx.root]);
+    assert_eq!(err, "");
+    let expected = [
+        format!("{}:needle in a", fx.path("a.txt")),
+        format!("{}:needle in b", fx.path("b.txt")),
+        format!("{}:needle again", fx.path("sub/deeper/d.txt")),
+        format!("{}:caf\u{FFFD} needle", fx.path("sub/latin1.txt")),
+    ];
+    assert_eq!(out.lines().collect::<Vec<_>>(), expected);
+    assert_eq!(
+        summary,
+        Summary {
+            files_searched: 5,
+            files_matched: 4,
+            lines_matched: 4,
+            binary_skipped: 1,
+            errors: 0,
+        }
+    );
+
+    let (out, _, _) = grep(&["-r", "-c", "-i"], "needle", &[&fx.root.join("sub")]);
+    assert_eq!(
+        out.lines().collect::<Vec<_>>(),
+        [
+            format!("{}:0", fx.path("sub/c.md")),
+            format!("{}:2", fx.path("sub/deeper/d.txt")),
+            format!("{}:1", fx.path("sub/latin1.txt")),
+        ]
+    );
+}
+
+#[test]
+fn errors_go_to_stderr_and_search_continues() {
+    let fx = Fixture::new("errors");
+    let a = fx.file("a.txt", "hit\n");
+    fx.file("dir/b.txt", "hit\n");
+    let missing = fx.root.join("missing.txt");
+    let dir = fx.root.join("dir");
+
+    let (out, err, summary) = grep(&[], "hit", &[&missing, &dir, &a]);
+    assert_eq!(out, format!("{}:hit\n", a.display()));
+    let err_lines: Vec<&str> = err.lines().collect();
+    assert_eq!(err_lines.len(), 2);
+    assert!(err_lines[0].starts_with(&format!("minigrep: {}: ", missing.display())));
+    assert_eq!(
+        err_lines[1],
+        format!(
+            "minigrep: {}: is a directory (use -r to search it)",
+            dir.display()
+        )
+    );
+    assert_eq!(summary.errors, 2);
+    assert_eq!(summary.files_matched, 1);
+}
+
+#[test]
+fn wildcard_search() {
+    let fx = Fixture::new("wildcard");
+    let src = fx.file(
+        "lib.rs",
+        "pub fn search() {}\nfn helper(x: i32) {}\nlet f = fn_ptr;\n// fn? maybe\n",
+    );
+    let (out, _, _) = grep(&["-W", "-n"], "fn *(", &[&src]);
+    assert_eq!(out, "1:pub fn search() {}\n2:fn helper(x: i32) {}\n");
+    let (out, _, _) = grep(&["-W"], "fn\\?", &[&src]);
+    assert_eq!(out, "// fn? maybe\n");
+    // -W가 없으면 *는 글자 그대로
+    let (out, _, summary) = grep(&[], "fn *(", &[&src]);
+    assert_eq!((out.as_str(), summary.lines_matched), ("", 0));
+}